@@ -1,10 +1,30 @@
 #![allow(unused)]
 
 use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
-use crossword_generator::{generator::{CrosswordGenerationRequest, CrosswordGenerator, CrosswordGeneratorSettings}, word::Word};
+use crossword_generator::{crossword::{Crossword, CrosswordConstraint, CrosswordSettings}, generator::{CrosswordGenerationRequest, CrosswordGenerator, CrosswordGeneratorSettings, PlacementOrder}, word::Word};
+#[cfg(feature = "multi-thread")]
+use crossword_generator::generator::CrosswordStream;
+use futures::SinkExt;
 use tokio::runtime::Runtime;
 use tokio_stream::StreamExt;
 
+// the words of the most generation benchmarks
+const WORDS: [&str; 7] = ["Hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt"];
+
+// the generator of a benchmark, with the settings and the words
+fn bench_generator(settings: CrosswordGeneratorSettings<u8>, words: &[&str]) -> CrosswordGenerator<u8, Vec<u8>>
+{
+    CrosswordGenerator { settings, words: words.iter().map(|s| Word::new(s.to_lowercase().into_bytes(), None)).collect(), ..Default::default() }
+}
+
+// requests all the crosswords of the stream and waits for them
+#[cfg(feature = "multi-thread")]
+async fn generate_all(mut str: CrosswordStream<u8, Vec<u8>>)
+{
+    str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+    while str.next().await.is_some() {}
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("crossword");
 
@@ -15,16 +35,39 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         let rt = Runtime::new().unwrap();
         b.iter(||
         {
-            let mut generator = CrosswordGenerator::<u8, Vec<u8>>::default();
-            generator.settings = CrosswordGeneratorSettings::default();
-            generator.words = vec!["Hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt"].into_iter().map(|s| Word::new(<String as AsRef<[u8]>>::as_ref(&s.to_lowercase()).to_owned(), None)).collect();
-            
+            let generator = bench_generator(CrosswordGeneratorSettings::default(), &WORDS);
+            rt.block_on(generate_all(generator.crossword_stream_randomized(ToOwned::to_owned)));
+        });
+    });
 
+    #[cfg(feature = "multi-thread")]
+    for tasks in [1, 4, 16]
+    {
+        group.bench_function(BenchmarkId::new("randomized_tasks", tasks),
+        |b|
+        {
+            let rt = Runtime::new().unwrap();
+            b.iter(||
+            {
+                let generator = bench_generator(CrosswordGeneratorSettings { max_concurrent_tasks: tasks, ..Default::default() }, &WORDS);
+                rt.block_on(generate_all(generator.crossword_stream_randomized(ToOwned::to_owned)));
+            });
+        });
+    }
+
+    #[cfg(feature = "multi-thread")]
+    group.bench_function(BenchmarkId::new("randomized_first_10", ""),
+    |b|
+    {
+        let rt = Runtime::new().unwrap();
+        b.iter(||
+        {
+            let generator = bench_generator(CrosswordGeneratorSettings::default(), &["Hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt", "lemon", "dose"]);
             rt.block_on(async move
             {
                 let mut str = generator.crossword_stream_randomized(ToOwned::to_owned);
-                str.request_crossword(CrosswordGenerationRequest::All).await;
-                while let Some(_) = str.next().await {}
+                str.request_crossword(CrosswordGenerationRequest::Count(10)).await.unwrap();
+                let _ = (&mut str).take(10).collect::<Vec<_>>().await;
             });
         });
     });
@@ -36,20 +79,123 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         let rt = Runtime::new().unwrap();
         b.iter(||
         {
-            let mut generator = CrosswordGenerator::<u8, Vec<u8>>::default();
-            generator.settings = CrosswordGeneratorSettings::default();
-            generator.words = vec!["Hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt"].into_iter().map(|s| Word::new(<String as AsRef<[u8]>>::as_ref(&s.to_lowercase()).to_owned(), None)).collect();
-            
+            let generator = bench_generator(CrosswordGeneratorSettings::default(), &WORDS);
+            rt.block_on(generate_all(generator.crossword_stream_sorted(ToOwned::to_owned)));
+        });
+    });
+
+    #[cfg(feature = "multi-thread")]
+    group.bench_function(BenchmarkId::new("sorted_prune_containing_crosswords", ""),
+    |b|
+    {
+        let rt = Runtime::new().unwrap();
+        b.iter(||
+        {
+            let generator = bench_generator(CrosswordGeneratorSettings { prune_containing_crosswords: true, ..Default::default() }, &WORDS);
+            rt.block_on(generate_all(generator.crossword_stream_sorted(ToOwned::to_owned)));
+        });
+    });
 
+    #[cfg(feature = "multi-thread")]
+    group.bench_function(BenchmarkId::new("sorted_min_fill_ratio", ""),
+    |b|
+    {
+        let rt = Runtime::new().unwrap();
+        b.iter(||
+        {
+            let generator = bench_generator(CrosswordGeneratorSettings { crossword_settings: CrosswordSettings { constraints: vec![CrosswordConstraint::MinFillRatio(0.3)] }, ..Default::default() }, &WORDS);
+            rt.block_on(generate_all(generator.crossword_stream_sorted(ToOwned::to_owned)));
+        });
+    });
+
+    #[cfg(feature = "multi-thread")]
+    for (name, order) in [("sorted_shuffled_placements", PlacementOrder::Shuffled(0)), ("sorted_scored_placements", PlacementOrder::Scored)]
+    {
+        group.bench_function(BenchmarkId::new(name, ""),
+        |b|
+        {
+            let rt = Runtime::new().unwrap();
+            b.iter(||
+            {
+                let generator = bench_generator(CrosswordGeneratorSettings { placement_order: order.clone(), ..Default::default() }, &WORDS);
+                rt.block_on(generate_all(generator.crossword_stream_sorted(ToOwned::to_owned)));
+            });
+        });
+    }
+
+    #[cfg(feature = "multi-thread")]
+    for batch_size in [1, 16, 256]
+    {
+        group.bench_function(BenchmarkId::new("sorted_emit_batch_size", batch_size),
+        |b|
+        {
+            let rt = Runtime::new().unwrap();
+            b.iter(||
+            {
+                let generator = bench_generator(CrosswordGeneratorSettings { emit_batch_size: batch_size, ..Default::default() }, &["hello", "world", "local", "cat", "toy", "lord", "low"]);
+                rt.block_on(generate_all(generator.crossword_stream_sorted(ToOwned::to_owned)));
+            });
+        });
+    }
+
+    #[cfg(feature = "multi-thread")]
+    for batch_size in [1, 16, 256]
+    {
+        group.bench_function(BenchmarkId::new("randomized_emit_batch_size", batch_size),
+        |b|
+        {
+            let rt = Runtime::new().unwrap();
+            b.iter(||
+            {
+                let generator = bench_generator(CrosswordGeneratorSettings { emit_batch_size: batch_size, ..Default::default() }, &WORDS);
+                rt.block_on(generate_all(generator.crossword_stream_randomized(ToOwned::to_owned)));
+            });
+        });
+    }
+
+    // a stream with a custom generation function, that sends 10000 empty crosswords
+    #[cfg(feature = "multi-thread")]
+    group.bench_function(BenchmarkId::new("custom_stream", ""),
+    |b|
+    {
+        let rt = Runtime::new().unwrap();
+        b.iter(||
+        {
             rt.block_on(async move
             {
-                let mut str = generator.crossword_stream_sorted(ToOwned::to_owned);
-                str.request_crossword(CrosswordGenerationRequest::All).await;
-                while let Some(_) = str.next().await {}
+                let mut str = CrosswordStream::<u8, Vec<u8>>::new(|_requests, mut crosswords| async move
+                {
+                    for _ in 0..10_000
+                    {
+                        if crosswords.send(Crossword::default()).await.is_err() { break; }
+                    }
+                });
+                while str.next().await.is_some() {}
             });
         });
     });
 
+    // the placements of words on crosswords with 10 and 30 words (placed greedily)
+    for size in [10, 30]
+    {
+        group.bench_function(BenchmarkId::new("possible_placements", size),
+        |b|
+        {
+            let mut crossword = Crossword::<u8, Vec<u8>>::default();
+            for word in ["hello", "world", "local", "cat", "toy", "lord", "low", "dog", "gold", "owl", "tall", "road", "lake", "dark", "rock", "cold", "door", "oat", "lot", "hat", "ear", "coat", "halt", "told",
+                         "table", "chair", "water", "stone", "river", "music", "green", "light", "night", "storm", "heart", "plant", "bread", "cloud", "house", "train", "apple", "lemon", "tiger", "horse", "mouse", "snake", "eagle", "shark", "whale", "zebra"]
+            {
+                if crossword.len() == size { break; }
+                let placement = crossword.calculate_possible_ways_to_add_word(&Word::new(word.as_bytes().to_owned(), None)).into_iter().next();
+                if let Some(placement) = placement { crossword.add_word(placement).unwrap(); }
+            }
+            assert_eq!(crossword.len(), size);
+            let words = ["world", "hollow", "decal", "tool", "ark"].map(|s| Word::new(s.as_bytes().to_owned(), None));
+
+            b.iter(|| words.iter().map(|word| crossword.calculate_possible_ways_to_add_word(word).len()).sum::<usize>());
+        });
+    }
+
     group.finish();
 
 }
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Represents a word value as unicode characters, for [crosswords](crate::crossword::Crossword) with [char] characters.
+///
+/// With u8 characters a non ascii character takes more than one cell (its utf8 bytes), with [CharString] every character takes one cell.
+/// Serialized as a string.
+///
+/// # Example
+///
+/// ```
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::Crossword;
+/// # use crossword_generator::char_string::CharString;
+/// let mut cw = Crossword::<char, CharString>::default();
+/// cw.add_word(PlacedWord::new("héllo".into(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+/// cw.add_word(PlacedWord::new("éclat".into(), Position { x: 1, y: 0 }, Direction::Down)).unwrap();
+///
+/// assert_eq!(cw.get_size(), (5, 5));
+/// assert_eq!(serde_json::to_string(&CharString::from("héllo")).unwrap(), "\"héllo\"");
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Hash)]
+pub struct CharString(pub Vec<char>);
+
+impl AsRef<[char]> for CharString
+{
+    fn as_ref(&self) -> &[char]
+    {
+        &self.0
+    }
+}
+
+impl From<&str> for CharString
+{
+    fn from(value: &str) -> Self
+    {
+        CharString(value.chars().collect())
+    }
+}
+
+impl From<String> for CharString
+{
+    fn from(value: String) -> Self
+    {
+        CharString::from(value.as_str())
+    }
+}
+
+impl From<&[char]> for CharString
+{
+    fn from(value: &[char]) -> Self
+    {
+        CharString(value.to_vec())
+    }
+}
+
+impl From<Vec<char>> for CharString
+{
+    fn from(value: Vec<char>) -> Self
+    {
+        CharString(value)
+    }
+}
+
+impl Display for CharString
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.iter().try_for_each(|c| write!(f, "{c}"))
+    }
+}
+
+impl Serialize for CharString
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CharString
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        String::deserialize(deserializer).map(CharString::from)
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{crossword::Crossword, placed_word::PlacedWord, word::{Direction, Position, Word}};
+
+    use super::*;
+
+    #[test]
+    fn test_char_string_conversions()
+    {
+        let value = CharString::from("բարև");
+        assert_eq!(value.as_ref(), &['բ', 'ա', 'ր', 'և']);
+        assert_eq!(value.to_string(), "բարև");
+        assert_eq!(CharString::from(value.as_ref()), value);
+        assert!(CharString::from("ա") < CharString::from("բ"));
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "\"բարև\"");
+        assert_eq!(serde_json::from_str::<CharString>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_char_string_crossword()
+    {
+        // the words cross on 'р', which is two bytes in utf8
+        let mut cw = Crossword::<char, CharString>::default();
+        cw.add_word(PlacedWord::new("мир".into(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::new("рука".into(), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        assert_eq!(cw.get_size(), (3, 4));
+        assert_eq!(cw.to_string(),
+" ----- 
+|м и р|
+|    у|
+|    к|
+|    а|
+ ----- ");
+
+        let word = Word::from_str_chars("рука");
+        assert_eq!(word.value, CharString::from("рука"));
+        assert_eq!(word.to_string(), "рука [any]");
+
+        let json = serde_json::to_string(&cw).unwrap();
+        assert!(json.contains("\"мир\""));
+        assert_eq!(serde_json::from_str::<Crossword<char, CharString>>(&json).unwrap(), cw);
+    }
+}
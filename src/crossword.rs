@@ -1,7 +1,8 @@
-use std::collections::BTreeSet;
+use std::{cell::OnceCell, cmp::Ordering, collections::{BTreeMap, BTreeSet, HashMap}, fmt::{Debug, Display}, hash::{BuildHasherDefault, Hash, Hasher}, ops::Index, sync::Arc};
+use itertools::{iproduct, Itertools};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use crate::{placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}, word::{Direction, Position, Word}};
+use crate::{placed_word::{PlacedWord, WordBoundingBox, WordRelation}, template::{GridTemplate, Slot}, traits::{CrosswordChar, CrosswordString, RenderChar}, word::{Direction, DirectionFlags, Position, Word}};
 
 
 /// Represents a constraint on a [crossword](Crossword).
@@ -19,38 +20,193 @@ use crate::{placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}, w
 /// //              | |      l        |
 /// //              v |      y        |
 /// //                 ---------------
+/// //
+/// //ExactSize(8, 6) satisfied
 /// ```
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
-pub enum CrosswordConstraint
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum CrosswordConstraint<CharT: CrosswordChar>
+{
+    None,
+    MaxLength(u16),
+    MaxHeight(u16),
+    MaxArea(u32),
+    /// The crossword must have exactly the specified length and height.
+    ExactSize(u16, u16),
+    /// All words of the crossword must be connected with each other through intersections (check [Crossword::is_connected]).
+    Connected,
+    /// Every word of the crossword must intersect at least the specified number of other words (check [Crossword::intersection_count]).
+    MinIntersectionsPerWord(u8),
+    /// The fraction of filled cells in the minimum rectangle containing the crossword must be at least the specified value (check [Crossword::filled_cell_count]).
+    MinFillRatio(f32),
+    /// The crossword must contain a word with the specified value.
+    /// 
+    /// The value is stored as a vector of characters, so the same constraint can be used for crosswords with any string type.
+    ContainsWord(Vec<CharT>),
+    /// At least the specified number of words of the crossword must have the tag (check [WordMetadata::has_tag](crate::word::WordMetadata::has_tag)).
+    MinWordsWithTag(String, usize),
+    /// At most the specified number of words of the crossword can have the tag (check [WordMetadata::has_tag](crate::word::WordMetadata::has_tag)).
+    MaxWordsWithTag(String, usize),
+    /// The pattern of the filled cells must be the same after rotating the crossword by 180 degrees, like the grids of newspaper crosswords (check [Crossword::is_rotationally_symmetric]).
+    RotationalSymmetry,
+    /// A user defined constraint (check [CrosswordConstraintFn]).
+    /// 
+    /// Custom constraints are compared by their addresses and are not serialized.
+    #[serde(skip)]
+    Custom(Arc<dyn CrosswordConstraintFn<CharT>>)
+}
+
+/// Trait for user defined [constraints](CrosswordConstraint::Custom) on a [crossword](Crossword).
+/// 
+/// The crossword is passed with words of type &\[CharT\], so the same constraint can be used for crosswords with any string type.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::sync::Arc;
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::{Crossword, CrosswordConstraint, CrosswordConstraintFn, CrosswordSettings};
+/// // rejects crosswords that have the specified letter in the top left cell
+/// #[derive(Debug)]
+/// struct NoLetterInCorner(u8);
+/// 
+/// impl CrosswordConstraintFn<u8> for NoLetterInCorner
+/// {
+///     fn check(&self, crossword: &Crossword<u8, &[u8]>) -> bool
+///     {
+///         crossword.generate_char_table().first().and_then(|row| row.first()) != Some(&self.0)
+///     }
+/// }
+/// 
+/// let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::Custom(Arc::new(NoLetterInCorner(b'h')))] };
+/// 
+/// let mut cw = Crossword::default();
+/// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+/// assert!(!settings.check_nonrecoverables_constraints(&cw));
+/// 
+/// cw.add_word(PlacedWord::<u8, &str>::new("world", Position{x: 4, y: -1}, Direction::Down)).unwrap();
+/// assert!(settings.check_nonrecoverables_constraints(&cw));
+/// ```
+pub trait CrosswordConstraintFn<CharT: CrosswordChar>: Debug + Send + Sync
+{
+    /// Returns true if the crossword satisfies the constraint, the crossword is always normalized (check [Crossword]).
+    fn check(&self, crossword: &Crossword<CharT, &[CharT]>) -> bool;
+
+    /// A constraint is recoverable if adding a new word to a crossword that doesn't meet the requirement can make the crossword to meet the requirement.
+    /// 
+    /// Recoverable constraints are only checked on finished crosswords.
+    fn recoverable(&self) -> bool { false }
+}
+
+// the normalized crossword with slices for the custom constraints, converted when the first custom constraint is checked, 
+// so all the checks of the same crossword share one copy of it
+pub(crate) type ConstraintView<'b, CharT> = OnceCell<Crossword<CharT, &'b [CharT]>>;
+
+/// Used to compare constraints, because custom constraints can only be compared by their addresses, and floats are compared by their bits.
+#[derive(Eq, PartialEq, PartialOrd, Ord, Hash)]
+enum ConstraintKey<'a, CharT: CrosswordChar>
 {
     None,
     MaxLength(u16),
     MaxHeight(u16),
-    MaxArea(u32)
+    MaxArea(u32),
+    ExactSize(u16, u16),
+    Connected,
+    MinIntersectionsPerWord(u8),
+    MinFillRatio(u32),
+    ContainsWord(&'a [CharT]),
+    MinWordsWithTag(&'a str, usize),
+    MaxWordsWithTag(&'a str, usize),
+    RotationalSymmetry,
+    Custom(*const ()),
 }
 
-impl CrosswordConstraint
+impl<CharT: CrosswordChar> CrosswordConstraint<CharT>
 {
-    fn check<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> bool
+    fn key(&self) -> ConstraintKey<'_, CharT>
     {
-        match *self
+        match self
+        {
+            CrosswordConstraint::None => ConstraintKey::None,
+            CrosswordConstraint::MaxLength(length) => ConstraintKey::MaxLength(*length),
+            CrosswordConstraint::MaxHeight(height) => ConstraintKey::MaxHeight(*height),
+            CrosswordConstraint::MaxArea(area) => ConstraintKey::MaxArea(*area),
+            CrosswordConstraint::ExactSize(length, height) => ConstraintKey::ExactSize(*length, *height),
+            CrosswordConstraint::Connected => ConstraintKey::Connected,
+            CrosswordConstraint::MinIntersectionsPerWord(count) => ConstraintKey::MinIntersectionsPerWord(*count),
+            CrosswordConstraint::MinFillRatio(ratio) => ConstraintKey::MinFillRatio(ratio.to_bits()),
+            CrosswordConstraint::ContainsWord(value) => ConstraintKey::ContainsWord(value),
+            CrosswordConstraint::MinWordsWithTag(tag, count) => ConstraintKey::MinWordsWithTag(tag, *count),
+            CrosswordConstraint::MaxWordsWithTag(tag, count) => ConstraintKey::MaxWordsWithTag(tag, *count),
+            CrosswordConstraint::RotationalSymmetry => ConstraintKey::RotationalSymmetry,
+            CrosswordConstraint::Custom(constr) => ConstraintKey::Custom(Arc::as_ptr(constr) as *const ()),
+        }
+    }
+
+    fn check<'b, StrT: CrosswordString<CharT>>(&self, crossword: &'b Crossword<CharT, StrT>, view: &ConstraintView<'b, CharT>) -> bool
+    {
+        match self
         {
             CrosswordConstraint::None => true,
             CrosswordConstraint::MaxLength(length) => 
             {
                 let size = crossword.get_size();
-                size.0 <= length
+                size.0 <= *length
             }
             CrosswordConstraint::MaxHeight(height) => 
             {
                 let size = crossword.get_size();
-                size.1 <= height
+                size.1 <= *height
             }
             CrosswordConstraint::MaxArea(area) => 
             {
                 let size = crossword.get_size();
-                size.0 as u32 * size.1 as u32 <= area
+                size.0 as u32 * size.1 as u32 <= *area
+            }
+            CrosswordConstraint::ExactSize(length, height) =>
+            {
+                crossword.get_size() == (*length, *height)
+            }
+            CrosswordConstraint::Connected => crossword.is_connected(),
+            CrosswordConstraint::MinIntersectionsPerWord(count) => crossword.words.iter().all(|w| crossword.intersection_count(w) >= *count as usize),
+            CrosswordConstraint::MinFillRatio(ratio) =>
+            {
+                let size = crossword.get_size();
+                let area = size.0 as u32 * size.1 as u32;
+                area == 0 || crossword.filled_cell_count() as f32 / area as f32 >= *ratio
+            }
+            CrosswordConstraint::ContainsWord(value) => crossword.words.iter().any(|w| w.value.as_ref() == value.as_slice()),
+            CrosswordConstraint::MinWordsWithTag(tag, count) => crossword.words.iter().filter(|w| w.meta.has_tag(tag)).count() >= *count,
+            CrosswordConstraint::MaxWordsWithTag(tag, count) => crossword.words.iter().filter(|w| w.meta.has_tag(tag)).count() <= *count,
+            CrosswordConstraint::RotationalSymmetry => crossword.is_rotationally_symmetric(),
+            CrosswordConstraint::Custom(constr) => constr.check(view.get_or_init(|| 
+            {
+                let mut crossword = crossword.as_slices();
+                crossword.normalize();
+                crossword
+            })),
+        }
+    }
+
+    /// Checks only the part of the constraint that can't be fixed by adding new words to the crossword.
+    /// 
+    /// For nonrecoverable constraints it's the same as the full check, for recoverable ones it can be used to prune crosswords early
+    /// (for example a crossword that is already bigger than [CrosswordConstraint::ExactSize] will never satisfy it).
+    fn check_nonrecoverable_part<'b, StrT: CrosswordString<CharT>>(&self, crossword: &'b Crossword<CharT, StrT>, view: &ConstraintView<'b, CharT>) -> bool
+    {
+        match *self
+        {
+            CrosswordConstraint::ExactSize(length, height) =>
+            {
+                let size = crossword.get_size();
+                size.0 <= length && size.1 <= height
             }
+            // the rotated cells of the filled cells can be filled later, the pattern with them is symmetric and has the same size (so it meets the size constraints too), 
+            // except in a template, where a filled cell can't be rotated to a blocked one
+            CrosswordConstraint::RotationalSymmetry => crossword.grid_template.as_ref().is_none_or(|template| crossword.cells()
+                .all(|(pos, _)| template.mirrored(&pos, true, true).is_some_and(|pos| !template.is_blocked(&pos)))),
+            _ if self.recoverable() => true,
+            _ => self.check(crossword, view)
         }
     }
 
@@ -59,33 +215,130 @@ impl CrosswordConstraint
     /// For example a requirement on minimum word count is recoverable
     fn recoverable(&self) -> bool
     {
-        match *self
+        match self
         {
             CrosswordConstraint::None => false,
             CrosswordConstraint::MaxLength(_) => false,
             CrosswordConstraint::MaxHeight(_) => false,
             CrosswordConstraint::MaxArea(_) => false,
+            CrosswordConstraint::ExactSize(_, _) => true,
+            CrosswordConstraint::Connected => true,
+            CrosswordConstraint::MinIntersectionsPerWord(_) => true,
+            CrosswordConstraint::MinFillRatio(_) => true,
+            CrosswordConstraint::ContainsWord(_) => true,
+            CrosswordConstraint::MinWordsWithTag(_, _) => true,
+            CrosswordConstraint::MaxWordsWithTag(_, _) => false,
+            CrosswordConstraint::RotationalSymmetry => true,
+            CrosswordConstraint::Custom(constr) => constr.recoverable(),
         }
     }
 }
 
+impl<CharT: CrosswordChar> PartialEq for CrosswordConstraint<CharT>
+{
+    fn eq(&self, other: &Self) -> bool 
+    {
+        self.key() == other.key()
+    }
+}
+
+impl<CharT: CrosswordChar> Eq for CrosswordConstraint<CharT> {}
+
+impl<CharT: CrosswordChar> PartialOrd for CrosswordConstraint<CharT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar> Ord for CrosswordConstraint<CharT>
+{
+    fn cmp(&self, other: &Self) -> Ordering 
+    {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<CharT: CrosswordChar> Hash for CrosswordConstraint<CharT>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) 
+    {
+        self.key().hash(state)
+    }
+}
+
 /// Represents all settigns for a [crossword](Crossword).
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
-pub struct CrosswordSettings
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct CrosswordSettings<CharT: CrosswordChar>
+{
+    pub constraints: Vec<CrosswordConstraint<CharT>>
+}
+
+// without the constraints, implemented manually so the characters don't need Default
+impl<CharT: CrosswordChar> Default for CrosswordSettings<CharT>
 {
-    pub constraints: Vec<CrosswordConstraint>
+    fn default() -> Self 
+    {
+        CrosswordSettings { constraints: vec![] }
+    }
 }
 
-impl CrosswordSettings
+impl<CharT: CrosswordChar> CrosswordSettings<CharT>
 {
-    pub fn check_recoverable_constraints<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> bool
+    pub fn check_recoverable_constraints<StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> bool
+    {
+        self.failed_recoverable_constraint(crossword).is_none()
+    }
+
+    pub fn check_nonrecoverables_constraints<StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> bool
+    {
+        self.failed_nonrecoverable_constraint(crossword).is_none()
+    }
+
+    /// Returns false if some [CrosswordConstraint::ContainsWord] constraint can't be satisfied anymore, 
+    /// because the required word is neither in the crossword nor in the words that can still be added to it.
+    pub fn check_required_words<'b, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>, remaining_words: impl Iterator<Item = &'b [CharT]> + Clone) -> bool
+    where
+        CharT: 'b
+    {
+        self.failed_required_word(crossword, remaining_words).is_none()
+    }
+
+    /// Returns the first recoverable constraint the crossword doesn't meet (check [check_recoverable_constraints](CrosswordSettings::check_recoverable_constraints)).
+    pub fn failed_recoverable_constraint<StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> Option<&CrosswordConstraint<CharT>>
+    {
+        self.failed_recoverable_constraint_in(crossword, &ConstraintView::new())
+    }
+
+    // same as failed_recoverable_constraint, but the custom constraints use the view, so the generator converts the crossword once for all its checks
+    pub(crate) fn failed_recoverable_constraint_in<'b, StrT: CrosswordString<CharT>>(&self, crossword: &'b Crossword<CharT, StrT>, view: &ConstraintView<'b, CharT>) -> Option<&CrosswordConstraint<CharT>>
+    {
+        self.constraints.iter().filter(|constr| constr.recoverable()).find(|constr| !constr.check(crossword, view))
+    }
+
+    /// Returns the first constraint the crossword can't meet anymore (check [check_nonrecoverables_constraints](CrosswordSettings::check_nonrecoverables_constraints)).
+    pub fn failed_nonrecoverable_constraint<StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> Option<&CrosswordConstraint<CharT>>
+    {
+        self.failed_nonrecoverable_constraint_in(crossword, &ConstraintView::new())
+    }
+
+    // same as failed_nonrecoverable_constraint, but with the view of the crossword (check failed_recoverable_constraint_in)
+    pub(crate) fn failed_nonrecoverable_constraint_in<'b, StrT: CrosswordString<CharT>>(&self, crossword: &'b Crossword<CharT, StrT>, view: &ConstraintView<'b, CharT>) -> Option<&CrosswordConstraint<CharT>>
     {
-        self.constraints.iter().filter(|constr| constr.recoverable()).all(|constr| constr.check(crossword))
+        self.constraints.iter().find(|constr| !constr.check_nonrecoverable_part(crossword, view))
     }
 
-    pub fn check_nonrecoverables_constraints<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> bool
+    /// Returns the first [CrosswordConstraint::ContainsWord] constraint that can't be satisfied anymore (check [check_required_words](CrosswordSettings::check_required_words)).
+    pub fn failed_required_word<'b, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>, remaining_words: impl Iterator<Item = &'b [CharT]> + Clone) -> Option<&CrosswordConstraint<CharT>>
+    where
+        CharT: 'b
     {
-        self.constraints.iter().filter(|constr| !constr.recoverable()).all(|constr| constr.check(crossword))
+        self.constraints.iter().find(|constr| match constr
+        {
+            CrosswordConstraint::ContainsWord(value) => !constr.check(crossword, &ConstraintView::new()) && !remaining_words.clone().any(|w| w == value.as_slice()),
+            _ => false
+        })
     }
 }
 
@@ -142,76 +395,269 @@ pub enum WordCompatibilityError
 /// true == allowed
 /// false == not allowed
 /// ```
+/// 
+/// side_by_side and head_by_head are about two words with the same direction, so they can be set separately for horizontal and vertical words (check [DirectionFlags]).
+/// ```
+/// # use crossword_generator::crossword::WordCompatibilitySettings;
+/// # use crossword_generator::word::DirectionFlags;
+/// // allows horizontal words to be side by side, but not vertical ones
+/// let wcs = WordCompatibilitySettings::<u8> { side_by_side: DirectionFlags { horizontal: true, vertical: false }, ..Default::default() };
+/// 
+/// // allows both
+/// let wcs = WordCompatibilitySettings::<u8> { side_by_side: true.into(), ..Default::default() };
+/// ```
 #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
-pub struct WordCompatibilitySettings
+// the skipped validator is None by default, so the characters don't need Default
+#[serde(bound(deserialize = "CharT: Deserialize<'de>"))]
+pub struct WordCompatibilitySettings<CharT: CrosswordChar>
 {
-    pub side_by_side: bool,
-    pub head_by_head: bool,
+    pub side_by_side: DirectionFlags,
+    pub head_by_head: DirectionFlags,
     pub side_by_head: bool,
-    pub corner_by_corner: bool
+    pub corner_by_corner: bool,
+    /// If set, a word can only be added to a non empty [crossword](Crossword) if it intersects some other word, 
+    /// so crosswords built with [Crossword::add_word] are always [connected](Crossword::is_connected).
+    /// 
+    /// If not set, words can also be placed without intersections, if they are compatible with other words by other settings.
+    pub require_intersection: bool,
+    /// If set, a word can only be added to a non empty [crossword](Crossword) if it covers at least this many cells of the other words (every intersection is one shared cell), 
+    /// for example 2 makes every added word cross at least two words, not set by default.
+    /// 
+    /// It's checked only when adding the words, not by [Crossword::validate], because the first word of a crossword doesn't share any cells.
+    #[serde(default)]
+    pub min_shared_cells: Option<usize>,
+    /// Validates the sequences of characters that are created when a word is placed next to other words (check [AdjacentSequenceValidator]).
+    #[serde(skip)]
+    pub adjacent_sequence_validator: Option<AdjacentSequenceValidator<CharT>>,
+    /// The directions of the words that are proposed by [Crossword::calculate_possible_ways_to_add_word] (so the directions of words in generated crosswords), 
+    /// [Direction::Right] and [Direction::Down] by default.
+    /// 
+    /// Words in other directions can still be added to a crossword directly.
+    #[serde(default = "default_allowed_directions")]
+    pub allowed_directions: BTreeSet<Direction>,
+}
+
+fn default_allowed_directions() -> BTreeSet<Direction>
+{
+    BTreeSet::from([Direction::Right, Direction::Down])
+}
+
+/// A function that validates the sequences of characters that are created when a [word](PlacedWord) is placed next to other words.
+/// 
+/// When two words are placed side by side (or side by head), the adjacent characters form sequences in the perpendicular direction, that are not words of the crossword,
+/// before adding a word to a [crossword](Crossword) the function is called with every such sequence (of length 2 or more) that goes through the characters of the word,
+/// and if the function returns false the word can't be added.
+/// 
+/// All validators are considered equal, so [settings](WordCompatibilitySettings) only compare the presence of the validator.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::sync::Arc;
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::{AdjacentSequenceValidator, Crossword, WordCompatibilitySettings};
+/// let wcs = WordCompatibilitySettings 
+/// { 
+///     side_by_side: true.into(), 
+///     adjacent_sequence_validator: Some(AdjacentSequenceValidator(Arc::new(|seq: &[u8]| seq == b"la" || seq == b"ok"))),
+///     ..Default::default() 
+/// };
+/// 
+/// let mut cw = Crossword::new(wcs);                                                                   //     ---------
+///                                                                                                     //    |h e l l o|
+/// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |    o a k|
+/// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    c    |
+///                                                                                                     //    |    a    |
+///                                                                                                     //    |    l    |
+///                                                                                                     //     ---------
+/// // creates sequences "la" and "ok"
+/// assert!(cw.issue_when_adding_word(&PlacedWord::new("oak", Position{x: 2, y: 1}, Direction::Right)).is_none());
+/// // creates sequences "la" and "ot"
+/// assert!(cw.issue_when_adding_word(&PlacedWord::new("oat", Position{x: 2, y: 1}, Direction::Right)).is_some());
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct AdjacentSequenceValidator<CharT: CrosswordChar>(pub Arc<dyn Fn(&[CharT]) -> bool + Send + Sync>);
+
+impl<CharT: CrosswordChar> Debug for AdjacentSequenceValidator<CharT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        f.write_str("AdjacentSequenceValidator")
+    }
+}
+
+impl<CharT: CrosswordChar> PartialEq for AdjacentSequenceValidator<CharT>
+{
+    fn eq(&self, _other: &Self) -> bool 
+    {
+        true
+    }
+}
+
+impl<CharT: CrosswordChar> Eq for AdjacentSequenceValidator<CharT> {}
+
+impl<CharT: CrosswordChar> PartialOrd for AdjacentSequenceValidator<CharT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar> Ord for AdjacentSequenceValidator<CharT>
+{
+    fn cmp(&self, _other: &Self) -> Ordering 
+    {
+        Ordering::Equal
+    }
+}
+
+impl<CharT: CrosswordChar> Hash for AdjacentSequenceValidator<CharT>
+{
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
 }
 
-impl WordCompatibilitySettings 
+impl<CharT: CrosswordChar> WordCompatibilitySettings<CharT> 
 {
     /// Returns [None] if two [words](PlacedWord) are compatible.
     /// 
     /// Returns Some([WordCompatibilityError]) if the words are not compatible according to settings.
-    pub fn word_compatibility_issue<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, first: &PlacedWord<CharT, StrT>, second: &PlacedWord<CharT, StrT>) -> Option<WordCompatibilityError>
+    pub fn word_compatibility_issue<StrT: CrosswordString<CharT>>(&self, first: &PlacedWord<CharT, StrT>, second: &PlacedWord<CharT, StrT>) -> Option<WordCompatibilityError>
     {
-        if first.corners_touch(second) && !self.corner_by_corner { return Some(WordCompatibilityError::CornerByCorner); }
-
-        if first.direction == second.direction
-        {
-            if first.head_touches_head(second) && !self.head_by_head { return Some(WordCompatibilityError::HeadByHead); }
-            if first.side_touches_side(second) && !self.side_by_side { return Some(WordCompatibilityError::SideBySide); }
-            if first.intersects(second) { return Some(WordCompatibilityError::InvalidIntersection); }
-
-            None
-        }
-        else
+        match first.relation_to(second)
         {
-            if first.side_touches_head(second) && !self.side_by_head {  return Some(WordCompatibilityError::SideByHead); }
-            if first.intersects(second)
-            {
-                let (first_ind, second_ind) = first.get_intersection_indices(second).unwrap();
-                let first_char = first.value.as_ref().iter().nth(first_ind as usize);
-                let second_char = second.value.as_ref().iter().nth(second_ind as usize);
-        
-                return (first_char.is_none() || second_char.is_none() || (first_char != second_char)).then_some(WordCompatibilityError::InvalidIntersection);
-            }
-
-            None
+            WordRelation::Disjoint => None,
+            WordRelation::CornerTouch => (!self.corner_by_corner).then_some(WordCompatibilityError::CornerByCorner),
+            WordRelation::SideBySide => (!self.side_by_side.get(&first.direction)).then_some(WordCompatibilityError::SideBySide),
+            WordRelation::HeadByHead => (!self.head_by_head.get(&first.direction)).then_some(WordCompatibilityError::HeadByHead),
+            WordRelation::SideByHead => (!self.side_by_head).then_some(WordCompatibilityError::SideByHead),
+            WordRelation::Intersecting { matching, .. } => (!matching).then_some(WordCompatibilityError::InvalidIntersection),
+            WordRelation::Overlapping => Some(WordCompatibilityError::InvalidIntersection),
         }
     }
 }
 
-impl Default for WordCompatibilitySettings 
+impl<CharT: CrosswordChar> Default for WordCompatibilitySettings<CharT> 
 {
     fn default() -> Self 
     {
         WordCompatibilitySettings 
         {
-            side_by_side: false,
-            head_by_head: false,
+            side_by_side: false.into(),
+            head_by_head: false.into(),
             side_by_head: false,
-            corner_by_corner: true
+            corner_by_corner: true,
+            require_intersection: true,
+            min_shared_cells: None,
+            adjacent_sequence_validator: None,
+            allowed_directions: default_allowed_directions(),
         }    
     }
 }
 
 
 /// Error type for possible errors when working with [crosswords](Crossword)
+/// 
+/// Implements [Display] if the characters implement [RenderChar], the words are shown like "hello @(2,0) Down" (check [PlacedWord]).
 #[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
 pub enum CrosswordError<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
-    #[error("The word is already in the crossword. Word: {0}")]
     WordAlreadyExists(PlacedWord<CharT, StrT>),
-    #[error("The word is not connected to the rest of crossword.")]
     WordNotConnected,
-    #[error("The word is not compatible with another word. CompatibilityError: {0}, Word: {1}")]
     WordCompatibilityError(WordCompatibilityError, PlacedWord<CharT, StrT>),
+    InvalidAdjacentSequence(Vec<CharT>),
+    BatchWordError(usize, Box<CrosswordError<CharT, StrT>>),
+    WordNotFound,
+    IsolatedCharacter(Position),
+    WordDoesNotFitTemplate(PlacedWord<CharT, StrT>),
+    CoordinateOverflow,
+    NotEnoughSharedCells(usize),
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Display for CrosswordError<CharT, StrT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        match self
+        {
+            CrosswordError::WordAlreadyExists(word) => write!(f, "The word is already in the crossword. Word: {word}"),
+            CrosswordError::WordNotConnected => write!(f, "The word is not connected to the rest of crossword."),
+            CrosswordError::WordCompatibilityError(err, word) => write!(f, "The word is not compatible with another word. CompatibilityError: {err}, Word: {word}"),
+            CrosswordError::InvalidAdjacentSequence(sequence) => write!(f, "The word creates a sequence of characters with adjacent words, that is rejected by the validator. Sequence: {}", crate::word::render_value(sequence)),
+            CrosswordError::BatchWordError(index, err) => write!(f, "Can't add the word with index {index} in the batch. Error: {err}"),
+            CrosswordError::WordNotFound => write!(f, "The word is not found in the crossword."),
+            CrosswordError::IsolatedCharacter(pos) => write!(f, "The character is not part of any word. Position: ({},{})", pos.x, pos.y),
+            CrosswordError::WordDoesNotFitTemplate(word) => write!(f, "The word leaves the template of the crossword or covers a blocked cell. Word: {word}"),
+            CrosswordError::CoordinateOverflow => write!(f, "The coordinates of a word don't fit in i16."),
+            CrosswordError::NotEnoughSharedCells(count) => write!(f, "The word shares fewer cells with the other words than required. Shared cells: {count}"),
+        }
+    }
+}
+
+
+/// Error type for an invalid [crossword](Crossword), contains all the errors found by [Crossword::validate].
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+#[error("The crossword is invalid. Errors: {0:?}")]
+pub struct CrosswordValidationError<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(pub Vec<CrosswordError<CharT, StrT>>);
+
+
+/// Represents options for matching a [crossword](Crossword) inside another crossword (check [Crossword::contains_crossword_with]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct MatchOptions
+{
+    /// The crossword can be found transposed.
+    pub allow_transpose: bool,
+    /// The crossword can be found mirrored horizontally, vertically or both.
+    pub allow_mirror: bool,
+}
+
+/// Represents a numbered entry of a [crossword](Crossword) (check [Crossword::numbered_entries]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct NumberedEntry<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    pub number: u32,
+    pub direction: Direction,
+    pub position: Position,
+    pub word: PlacedWord<CharT, StrT>,
 }
 
+/// Represents the statistics of a [crossword](Crossword) (check [Crossword::stats]).
+/// 
+/// The [Display] implementation writes a small human-readable report.
+#[derive(Clone, PartialEq, PartialOrd, Default, Debug, Serialize, Deserialize)]
+pub struct CrosswordStats
+{
+    pub word_count: usize,
+    pub width: u16,
+    pub height: u16,
+    pub filled_cells: usize,
+    /// The part of the cells of the [size](Crossword::get_size) rectangle that are filled, from 0 to 1.
+    pub fill_ratio: f64,
+    /// Check [Crossword::total_intersections].
+    pub intersections: usize,
+    /// The average number of words that a word intersects.
+    pub average_intersections: f64,
+    /// The length of the longest word, 0 for an empty crossword.
+    pub longest_word: usize,
+    /// The length of the shortest word, 0 for an empty crossword.
+    pub shortest_word: usize,
+    pub across_words: usize,
+    pub down_words: usize,
+}
+
+impl Display for CrosswordStats
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        writeln!(f, "Words: {} ({} across, {} down)", self.word_count, self.across_words, self.down_words)?;
+        writeln!(f, "Size: {}x{}", self.width, self.height)?;
+        writeln!(f, "Filled cells: {} ({:.1}%)", self.filled_cells, self.fill_ratio * 100.0)?;
+        writeln!(f, "Intersections: {} ({:.2} per word)", self.intersections, self.average_intersections)?;
+        write!(f, "Word lengths: {} to {}", self.shortest_word, self.longest_word)
+    }
+}
 
 /// # Represents a crossword
 /// 
@@ -220,6 +666,17 @@ pub enum CrosswordError<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 /// A crossword is always normalized, meaning all possible coordinates of words are positive, and the minimums are 0
 /// 
 /// Normalization means shifting coordinates of all words in a way, that ensures that the minimum x and y values in all words will be 0s
+/// 
+/// The only exception are the crosswords edited with [Crossword::add_word_unnormalized] and [Crossword::remove_word_unnormalized], 
+/// that keep the coordinates of words stable until [Crossword::normalize] is called. 
+/// Querying methods (like [Crossword::get_size] or [Crossword::generate_char_table]) work the same for not normalized crosswords.
+/// 
+/// A crossword with a [template](GridTemplate) (check [Crossword::with_template]) is never normalized, the positions of its words are the absolute positions in the template,
+/// the words can occupy only the open cells of the template, and the crossword has the size of the template.
+/// 
+/// Equality, ordering and hashing are consistent with each other and take into account the words, the template and the [settings](WordCompatibilitySettings) 
+/// (where all [validators](AdjacentSequenceValidator) are considered equal), so crosswords with the same words and settings are equal and have the same hash,
+/// no matter in which order the words were added. To compare only the words of crosswords use [Crossword::same_layout].
 /// # Example
 /// 
 /// ```
@@ -243,431 +700,3503 @@ pub enum CrosswordError<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 /// 
 /// assert_eq!(cw1, cw2)
 /// ```
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+/// 
+/// Deserialization validates the words like [Crossword::from_placed_words] with default [settings](WordCompatibilitySettings) (the settings are not serialized), 
+/// so an inconsistent set of words is rejected. The template is serialized only if there is one.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize, Hash)]
+// the skipped fields are created from the words, so the characters don't need Default
+#[serde(try_from = "CrosswordData<CharT, StrT>", bound(deserialize = "CharT: Deserialize<'de>, StrT: Deserialize<'de>"))]
 pub struct Crossword<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
     words: BTreeSet<PlacedWord<CharT, StrT>>,
+    /// The template of the crossword, changing it doesn't check the words of the crossword again (use [Crossword::validate] for that).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid_template: Option<GridTemplate>,
+    #[serde(skip)]
+    pub word_compatibility_settings: WordCompatibilitySettings<CharT>,
+    // the occupied cells of the words, updated with every change of the words
     #[serde(skip)]
-    pub word_compatibility_settings: WordCompatibilitySettings
+    cells: CellMap<CharT>,
 }
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+/// The serialized form of a [crossword](Crossword), that is validated when deserializing.
+#[derive(Deserialize)]
+struct CrosswordData<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
-    fn normalize(&mut self)
-    {
-        let mut min_corner = (i16::MAX, i16::MAX);
-        let mut new_set = BTreeSet::new();
+    words: BTreeSet<PlacedWord<CharT, StrT>>,
+    #[serde(default)]
+    grid_template: Option<GridTemplate>,
+}
 
-        for word in self.words.iter()
-        {
-            min_corner.0 = min_corner.0.min(word.position.x);
-            min_corner.1 = min_corner.1.min(word.position.y);
-        }
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> TryFrom<CrosswordData<CharT, StrT>> for Crossword<CharT, StrT>
+{
+    type Error = CrosswordValidationError<CharT, StrT>;
 
-        for word in self.words.iter()
-        {
-            let mut new_word = word.clone();
-            new_word.position = Position { x: word.position.x - min_corner.0, y: word.position.y - min_corner.1};
-            new_set.insert(new_word);
-        }
+    fn try_from(data: CrosswordData<CharT, StrT>) -> Result<Self, Self::Error> 
+    {
+        Crossword::from_parts(data.words, data.grid_template, WordCompatibilitySettings::default())
+            .validated()
+            .map_err(CrosswordValidationError)
+    }
+}
 
-        self.words = new_set;
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Debug for Crossword<CharT, StrT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        f.debug_struct("Crossword")
+            .field("words", &self.words)
+            .field("grid_template", &self.grid_template)
+            .field("word_compatibility_settings", &self.word_compatibility_settings)
+            .finish()
     }
+}
 
-    /// Creates a new empty crossword with provided [settings](WordCompatibilitySettings)
-    pub fn new(word_compatibility_settings: WordCompatibilitySettings) -> Crossword<CharT, StrT>
+// the orientations of the words that cover a cell, and whether the cell is the first or the last cell of those words (the one with the smaller or the bigger coordinate), 
+// a valid crossword has at most one word of every orientation on a cell
+#[derive(Clone, Copy, Eq, PartialEq, Default, Debug)]
+struct DirectionMask(u8);
+
+impl DirectionMask
+{
+    const COVERED: u8 = 0b001;
+    const FIRST: u8 = 0b010;
+    const LAST: u8 = 0b100;
+
+    fn shift(horizontal: bool) -> u8
     {
-        Crossword{ word_compatibility_settings, ..Default::default() }
+        if horizontal { 0 } else { 3 }
     }
 
-    /// Checks if a [word](PlacedWord) can or can't be added to the crossword
-    /// 
-    /// Returns [None] if the word can be added to the crossword
-    /// 
-    /// Returns Some([CrosswordError]) if the word can't be added to the crossword
-    /// 
-    /// # Example
-    /// 
-    /// ```
-    /// # use crossword_generator::word::{Direction, Position};
-    /// # use crossword_generator::placed_word::PlacedWord;
-    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};                                         
-    /// let mut cw = Crossword::new(WordCompatibilitySettings::default());                                  //     ---------
-    ///                                                                                                     //    |h e l l o|
-    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
-    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
-    ///                                                                                                     //    |    a    |
-    ///                                                                                                     //    |    l    |
-    ///                                                                                                     //     ---------
-    ///                                                                                             
-    /// assert!(cw.issue_when_adding_word(&PlacedWord::new("halo", Position { x: 0, y: 0 }, Direction::Down)).is_none());
-    /// ```
-    /// 
-    /// Note that for example word halo on position (3, -2) and direction down is not allowed by a setting in word compatibility settings that forbids two words with same direction to be side to side
-    pub fn issue_when_adding_word(&self, word: &PlacedWord<CharT, StrT>) -> Option<CrosswordError<CharT, StrT>>
+    fn has(self, horizontal: bool, flag: u8) -> bool
     {
-        if let Some(w) = self.find_word(&word.value) { Some(CrosswordError::WordAlreadyExists(w.clone())) }
-        else 
-        {
-            let err = self.words.iter()
-                .flat_map(|w| self.word_compatibility_settings.word_compatibility_issue(w, word).map(|err| CrosswordError::WordCompatibilityError(err, w.clone())))
-                .next();
+        self.0 & (flag << DirectionMask::shift(horizontal)) != 0
+    }
+}
 
-            if let None = err
-            {
-                (!self.words.is_empty() && self.words.iter().all(|w| !w.intersects(word))).then_some(CrosswordError::WordNotConnected)
-            }
-            else { err }
-        }
+// a hasher for the coordinates of the cells, faster than the default one for the small keys (that are not chosen by an attacker)
+#[derive(Default)]
+struct CellHasher(u64);
+
+impl Hasher for CellHasher
+{
+    fn finish(&self) -> u64
+    {
+        self.0
     }
 
-    /// Finds the [word](PlacedWord) given its string value.
-    pub fn find_word(&self, word: &StrT) -> Option<&PlacedWord<CharT, StrT>>
+    fn write(&mut self, bytes: &[u8])
     {
-        self.words.iter().find(|w| w.value == *word)
+        bytes.iter().for_each(|b| self.write_u32(*b as u32));
     }
 
-    fn add_word_unnormalized(&mut self, word: PlacedWord<CharT, StrT>) -> Result<(), CrosswordError<CharT, StrT>>
+    fn write_u32(&mut self, i: u32)
     {
-        match self.issue_when_adding_word(&word)
-        {
-            None => { self.words.insert(word); Ok(()) },
-            Some(err) => Err(err),
-        }
+        self.0 = (self.0.rotate_left(32) ^ i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
     }
 
-    /// Adds the [word](PlacedWord) to the crossword.
-    /// Normalizes the crossword after adding the word.
-    /// 
-    /// # Errors
-    /// 
-    /// [CrosswordError::WordNotConnected] - The word is isolated from the crossword.
-    /// 
-    /// [CrosswordError::WordAlreadyExists] - A word with same value already exists in the crossword.
-    /// 
-    /// [CrosswordError::WordCompatibilityError] - Word can't be added because it's violates the [word compatilibity settings](WordCompatibilitySettings) or has conflict with some other word.
-    pub fn add_word(&mut self, word: PlacedWord<CharT, StrT>) -> Result<(), CrosswordError<CharT, StrT>>
+    fn write_i32(&mut self, i: i32)
     {
-        self.add_word_unnormalized(word)?;
-        self.normalize();
-        Ok(())
-    }  
+        self.write_u32(i as u32);
+    }
+}
 
-    /// Adds the [words](PlacedWord) to the crossword.
-    /// Only normalizes the crossword after adding all the words. 
-    /// Note, that it's different from calling [Crossword::add_word] in a loop.
-    /// 
-    /// 
-    /// # Errors
-    /// 
-    /// [CrosswordError::CantAddWord] - Word can't be added because it's violates the [word compatilibity settings](WordCompatibilitySettings) or has conflict with some other word.
-    /// 
-    /// [CrosswordError::WordAlreadyExists] - A word with same value already exists in the crossword.
-    pub fn add_words(&mut self, mut words: impl Iterator<Item = PlacedWord<CharT, StrT>>) -> Result<(), CrosswordError<CharT, StrT>>
+// the key of a cell in a CellMap
+type CellKey = (i32, i32);
+
+// the minimum and the maximum keys of the cells of a CellMap, None without cells
+type CellBounds = Option<(CellKey, CellKey)>;
+
+// the characters of the occupied cells of a crossword, so the checks of a new word look only at the cells around it, 
+// and the cells of every character, so the placements of a new word are found only from the cells with its characters, 
+// it's derived from the words, so all maps are equal
+struct CellMap<CharT: CrosswordChar>
+{
+    // the keys are the coordinates minus the offset, so shifting all the words only changes the offset
+    cells: HashMap<CellKey, (CharT, DirectionMask), BuildHasherDefault<CellHasher>>,
+    // the keys of the cells with the character for every word that covers them, with the direction of the word
+    letters: BTreeMap<CharT, Vec<(CellKey, Direction)>>,
+    // the bounding box of the cells, recomputed from all of them only when a cell on the border is removed
+    bounds: CellBounds,
+    // the bounds before the words were added, with the first cells, the directions and the lengths of the words, 
+    // so removing the last added word (like when backtracking) restores the bounds without recomputing them, 
+    // it has at most one entry for every word of the map, only for the words added after the map was built or after a word was removed out of order
+    added: Vec<(CellKey, Direction, usize, CellBounds)>,
+    offset: CellKey,
+}
+
+impl<CharT: CrosswordChar> Default for CellMap<CharT>
+{
+    fn default() -> Self 
     {
-        let res = words.try_for_each(|w| self.add_word_unnormalized(w));
-        self.normalize();
-        res
+        CellMap { cells: HashMap::default(), letters: BTreeMap::new(), bounds: None, added: vec![], offset: CellKey::default() }
     }
+}
 
-    /// Removes the [word](PlacedWord) from the crossword if finded.
-    /// 
-    /// Returns true if the word was succesfully removed.
-    /// 
-    /// Returns false if a word with provaded value was not found.
-    /// 
-    /// (normalizes the crossword after removing the word)
-    pub fn remove_word(&mut self, word: &StrT) -> bool
+// the bounds with the key in them
+fn extended_bounds(bounds: CellBounds, key: CellKey) -> CellBounds
+{
+    let (min, max) = bounds.unwrap_or((key, key));
+    Some(((min.0.min(key.0), min.1.min(key.1)), (max.0.max(key.0), max.1.max(key.1))))
+}
+
+impl<CharT: CrosswordChar> CellMap<CharT>
+{
+    fn new<'a, StrT: CrosswordString<CharT> + 'a>(words: impl IntoIterator<Item = &'a PlacedWord<CharT, StrT>>) -> CellMap<CharT> where CharT: 'a
     {
-        if let Some(word) = self.find_word(word).cloned()
-        {
-            self.words.remove(&word);
+        let mut cells = CellMap::default();
+        for word in words { cells.add(word); }
+        // the words of a built map are not removed in the order of the set
+        cells.added.clear();
+        cells
+    }
 
-            self.normalize();
+    fn key(&self, pos: &Position) -> CellKey
+    {
+        (pos.x as i32 - self.offset.0, pos.y as i32 - self.offset.1)
+    }
 
-            true
+    fn add<StrT: CrosswordString<CharT>>(&mut self, word: &PlacedWord<CharT, StrT>)
+    {
+        // an empty word (only a deserialized or an unchecked one) doesn't cover any cell
+        let Some(last) = word.value.as_ref().len().checked_sub(1) else { return; };
+        self.added.push((self.key(&word.position), word.direction.clone(), word.value.as_ref().len(), self.bounds));
+        let shift = DirectionMask::shift(word.direction.is_horizontal());
+        // the words to the left or up start from the cell with the bigger coordinate
+        let forward = matches!(word.direction, Direction::Right | Direction::Down);
+        for (ind, (pos, c)) in word.cells().enumerate()
+        {
+            let along = if forward { ind } else { last - ind };
+            let mut flags = DirectionMask::COVERED;
+            if along == 0 { flags |= DirectionMask::FIRST; }
+            if along == last { flags |= DirectionMask::LAST; }
+
+            let key = self.key(&pos);
+            let (_, mask) = self.cells.entry(key).or_insert_with(|| (c.clone(), DirectionMask::default()));
+            mask.0 |= flags << shift;
+            self.bounds = extended_bounds(self.bounds, key);
+            self.letters.entry(c.clone()).or_default().push((key, word.direction.clone()));
         }
-        else { false }
     }
 
-    /// Checks if another crossword is found inside this crossword.
-    /// 
-    /// # Example
-    /// 
-    /// ```
-    /// # use crossword_generator::word::{Direction, Position};
-    /// # use crossword_generator::placed_word::PlacedWord;
-    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};  
-    /// // allowing two words to be side by side
-    /// let wcs = WordCompatibilitySettings { side_by_side: true, ..Default::default() };
-    ///                                                     
-    /// let mut cw1 = Crossword::<u8, &str>::new(wcs.clone());                                               //     ---------
-    ///                                                                                                      //    |h e l l o|
-    /// cw1.add_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right));                   //    |    o    |
-    /// cw1.add_word(PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down));                    //    |    c a t|
-    /// cw1.add_word(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right));                     //    |    a n o|
-    /// cw1.add_word(PlacedWord::new("and", Position { x: 3, y: 2 }, Direction::Down));                      //    |    l d y|
-    /// cw1.add_word(PlacedWord::new("toy", Position { x: 4, y: 2 }, Direction::Down));                      //     ---------
-    ///                                                                                                      
-    ///                                                                                         
-    ///
-    /// let mut cw2 = Crossword::new(wcs.clone());                                                           //     -----                 
-    ///                                                                                                      //    |c a t|
-    /// cw2.add_word(PlacedWord::new("cat", Position { x: 0, y: 0 }, Direction::Right));                     //    |  n o|
-    /// cw2.add_word(PlacedWord::new("and", Position { x: 1, y: 0 }, Direction::Down));                      //    |  d y|
-    /// cw2.add_word(PlacedWord::new("toy", Position { x: 2, y: 0 }, Direction::Down));                      //     -----
-    ///     
-    /// assert!(cw1.contains_crossword(&cw2));
-    /// ```
-    pub fn contains_crossword(&self, other: &Crossword<CharT, StrT>) -> bool 
+    fn remove<StrT: CrosswordString<CharT>>(&mut self, word: &PlacedWord<CharT, StrT>)
     {
-        if other.words.len() > self.words.len() { return false; }
-        let mut offset: Option<(i16, i16)> = None;
-        for other_word in other.words.iter()
+        if word.value.as_ref().is_empty() { return; }
+        let shift = DirectionMask::shift(word.direction.is_horizontal());
+        let mut on_border = false;
+        for (pos, c) in word.cells()
         {
-            let cur_word = self.find_word(&other_word.value);
-            if cur_word.is_none()
+            let key = self.key(&pos);
+            if let Some(occurrences) = self.letters.get_mut(c)
             {
-                return false;
+                if let Some(ind) = occurrences.iter().position(|(k, dir)| *k == key && *dir == word.direction) { occurrences.swap_remove(ind); }
+                if occurrences.is_empty() { self.letters.remove(c); }
             }
-            let cur_word = cur_word.unwrap();
-            if cur_word.direction != other_word.direction
-            {
-                return false;
+
+            let Some((_, mask)) = self.cells.get_mut(&key) else { continue; };
+            mask.0 &= !(0b111 << shift);
+            if mask.0 == 0 
+            { 
+                self.cells.remove(&key);
+                on_border |= self.bounds.is_some_and(|(min, max)| key.0 == min.0 || key.1 == min.1 || key.0 == max.0 || key.1 == max.1);
             }
+        }
 
-            match &offset
+        match self.added.last()
+        {
+            Some((key, dir, len, bounds)) if *key == self.key(&word.position) && *dir == word.direction && *len == word.value.as_ref().len() =>
             {
-                None => offset = Some((cur_word.position.x - other_word.position.x, cur_word.position.y - other_word.position.y)),
-                Some(offset) => 
-                {
-                    if *offset != (cur_word.position.x - other_word.position.x, cur_word.position.y - other_word.position.y)
-                    {
-                        return false;
-                    }
-                }
+                self.bounds = *bounds;
+                self.added.pop();
+            }
+            // the kept bounds of the words added after the removed one include its cells
+            _ =>
+            {
+                self.added.clear();
+                if on_border { self.bounds = self.cells.keys().fold(None, |bounds, key| extended_bounds(bounds, *key)); }
             }
+        }
+    }
+
+    fn clear(&mut self)
+    {
+        *self = CellMap::default();
+    }
+
+    // moves all the cells by the offset
+    fn shift(&mut self, dx: i16, dy: i16)
+    {
+        self.offset = (self.offset.0 + dx as i32, self.offset.1 + dy as i32);
 
+        // the keys are moved to the coordinates, so the offset doesn't grow without a bound
+        if self.offset.0.abs() > i16::MAX as i32 || self.offset.1.abs() > i16::MAX as i32
+        {
+            let offset = std::mem::take(&mut self.offset);
+            self.cells = std::mem::take(&mut self.cells).into_iter().map(|((x, y), cell)| ((x + offset.0, y + offset.1), cell)).collect();
+            self.letters.values_mut().flatten().for_each(|((x, y), _)| (*x, *y) = (*x + offset.0, *y + offset.1));
+            let moved = |bounds: CellBounds| bounds.map(|(min, max)| ((min.0 + offset.0, min.1 + offset.1), (max.0 + offset.0, max.1 + offset.1)));
+            self.bounds = moved(self.bounds);
+            self.added.iter_mut().for_each(|((x, y), _, _, bounds)| ((*x, *y), *bounds) = ((*x + offset.0, *y + offset.1), moved(*bounds)));
         }
+    }
+
+    // the minimum and the maximum positions of the cells
+    fn bounds(&self) -> Option<(Position, Position)>
+    {
+        let position = |(x, y): CellKey| Position { x: (x + self.offset.0) as i16, y: (y + self.offset.1) as i16 };
+        self.bounds.map(|(min, max)| (position(min), position(max)))
+    }
+
+    fn contains(&self, pos: &Position) -> bool
+    {
+        self.cells.contains_key(&self.key(pos))
+    }
+
+    fn get(&self, x: i32, y: i32) -> Option<&(CharT, DirectionMask)>
+    {
+        self.cells.get(&(x - self.offset.0, y - self.offset.1))
+    }
+
+    // the coordinates of the cells with the character, with the directions of the words that cover them
+    fn occurrences(&self, c: &CharT) -> impl Iterator<Item = (i32, i32, &Direction)>
+    {
+        self.letters.get(c).into_iter().flatten().map(|((x, y), dir)| (x + self.offset.0, y + self.offset.1, dir))
+    }
+}
+
+// the copies don't keep the bounds before the added words, the words of a copy aren't removed in the order they were added usually
+impl<CharT: CrosswordChar> Clone for CellMap<CharT>
+{
+    fn clone(&self) -> Self
+    {
+        CellMap { cells: self.cells.clone(), letters: self.letters.clone(), bounds: self.bounds, added: vec![], offset: self.offset }
+    }
+}
+
+impl<CharT: CrosswordChar> PartialEq for CellMap<CharT>
+{
+    fn eq(&self, _other: &Self) -> bool 
+    {
         true
     }
+}
+
+impl<CharT: CrosswordChar> Eq for CellMap<CharT> {}
+
+impl<CharT: CrosswordChar> PartialOrd for CellMap<CharT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
 
-    /// Returns all possible ways to add a [word](Word) into the crossword
+impl<CharT: CrosswordChar> Ord for CellMap<CharT>
+{
+    fn cmp(&self, _other: &Self) -> Ordering 
+    {
+        Ordering::Equal
+    }
+}
+
+impl<CharT: CrosswordChar> Hash for CellMap<CharT>
+{
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+// the characters are never pinned (like in the set of the words), so the crosswords stay Unpin
+impl<CharT: CrosswordChar> Unpin for CellMap<CharT> {}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Returns the minimum x and y coordinates of the words, or (0, 0) if the crossword is empty or has a [template](GridTemplate).
+    pub(crate) fn min_corner(&self) -> (i16, i16)
+    {
+        if self.grid_template.is_some() { return (0, 0); }
+
+        let (min, _) = self.bounding_box();
+        (min.x, min.y)
+    }
+
+    /// Returns the minimum and the maximum positions of the cells of the words (the corners of the bounding box, both included), or two (0, 0) positions if the crossword is empty.
+    /// 
+    /// Unlike [Crossword::get_size] it doesn't depend on the [template](GridTemplate), and it's kept up to date while editing the crossword, so it doesn't look at all the words.
     /// 
     /// # Example
     /// 
     /// ```
-    /// # use crossword_generator::word::{Word, Direction, Position};
+    /// # use crossword_generator::word::{Direction, Position};
     /// # use crossword_generator::placed_word::PlacedWord;
-    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};         
-    /// # use std::collections::BTreeSet;                                      
-    /// let mut cw = Crossword::default();                                                                  //     ---------
-    ///                                                                                                     //    |h e l l o|
-    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
-    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
-    ///                                                                                                     //    |    a    |
-    ///                                                                                                     //    |    l    |
-    ///                                                                                                     //     ---------
-    ///                                                                                         
-    /// assert_eq!(cw.calculate_possible_ways_to_add_word(&Word::new("halo", None)), 
-    ///             BTreeSet::from([
-    ///     PlacedWord::new("halo", Position { x: 0, y: 0 }, Direction::Down),
-    ///     PlacedWord::new("halo", Position { x: 4, y: -3 }, Direction::Down),
-    ///     PlacedWord::new("halo", Position { x: 0, y: 4 }, Direction::Right),
-    ///     PlacedWord::new("halo", Position { x: 1, y: 3 }, Direction::Right),
-    /// ]));
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("world", Position{x: 4, y: -1}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.bounding_box(), (Position{x: 0, y: -1}, Position{x: 4, y: 3}));
+    /// assert_eq!(Crossword::<u8, &str>::default().bounding_box(), (Position{x: 0, y: 0}, Position{x: 0, y: 0}));
     /// ```
+    pub fn bounding_box(&self) -> (Position, Position)
+    {
+        let bounds = self.cells.bounds();
+        debug_assert_eq!(bounds, self.computed_bounds(), "The cached bounding box of the crossword differs from the one of its words");
+        bounds.unwrap_or_default()
+    }
+
+    // the bounding box computed from all the words, for checking the cached one
+    fn computed_bounds(&self) -> Option<(Position, Position)>
+    {
+        self.words.iter().filter(|w| !w.value.as_ref().is_empty()).map(|w| w.get_bounding_box()).fold(None, |bounds, b|
+        {
+            let (min, max) = bounds.unwrap_or(((b.x, b.y), (b.x, b.y)));
+            Some(((min.0.min(b.x), min.1.min(b.y)), (max.0.max(b.x + b.w.max(1) - 1), max.1.max(b.y + b.h.max(1) - 1))))
+        })
+        .map(|(min, max)| (Position { x: min.0 as i16, y: min.1 as i16 }, Position { x: max.0 as i16, y: max.1 as i16 }))
+    }
+
+    /// Normalizes the crossword (check [Crossword]).
     /// 
+    /// Returns the offset (x, y) that was added to the positions of all words.
     /// 
+    /// Only needed after editing the crossword with [Crossword::add_word_unnormalized] or [Crossword::remove_word_unnormalized].
     /// 
-    /// Note that for example word halo on position 3 -2 and direction down is not allowed by a setting in word compatibility settings that forbids two words with same direction to be side to side
-    pub fn calculate_possible_ways_to_add_word(&self, word: &Word<CharT, StrT>) -> BTreeSet<PlacedWord<CharT, StrT>>
+    /// Does nothing for a crossword with a [template](GridTemplate).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the crossword is too big for the coordinates to fit in i16 after normalizing.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("world", Position{x: 4, y: -1}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.normalize(), (0, 1));
+    /// assert_eq!(cw.find_word(&"world").unwrap().position, Position{x: 4, y: 0});
+    /// ```
+    pub fn normalize(&mut self) -> (i16, i16)
     {
-        if self.words.is_empty()
-        {
-            return vec![PlacedWord::new(word.value.clone(), Position::default(), Direction::default())].into_iter().collect()
-        }
+        let offset = self.normalizing_offset();
+        if offset == (0, 0) { return offset; }
 
-        self.words.iter()
-            .flat_map(|cur_word: &PlacedWord<_, _>  | cur_word.calculate_possible_ways_to_add_word(word))
-            .filter(|w: &PlacedWord<_, _>| self.issue_when_adding_word(w).is_none())
-            .collect()
+        self.words = self.shifted_words(offset);
+        self.cells.shift(offset.0, offset.1);
+        offset
     }
 
-    /// Returns the size of the minimum rectangle that can contain the crossword.
+    /// Returns the normalized copy of the crossword (check [Crossword::normalize]), without copying the words before shifting them.
+    pub(crate) fn normalized(&self) -> Crossword<CharT, StrT>
+    {
+        let offset = self.normalizing_offset();
+        if offset == (0, 0) { return self.clone(); }
+
+        let mut cells = self.cells.clone();
+        cells.shift(offset.0, offset.1);
+        Crossword { words: self.shifted_words(offset), grid_template: self.grid_template.clone(), word_compatibility_settings: self.word_compatibility_settings.clone(), cells }
+    }
+
+    // the panic message for the crosswords that are too big to be normalized
+    const TOO_BIG: &str = "The crossword is too big for the range of the coordinates";
+
+    // the offset that normalizes the crossword
+    fn normalizing_offset(&self) -> (i16, i16)
+    {
+        let min_corner = self.min_corner();
+        min_corner.0.checked_neg().zip(min_corner.1.checked_neg()).expect(Self::TOO_BIG)
+    }
+
+    // the words shifted by the offset
+    fn shifted_words(&self, offset: (i16, i16)) -> BTreeSet<PlacedWord<CharT, StrT>>
+    {
+        self.words.iter().map(|word| word.shifted(offset.0, offset.1).expect(Self::TOO_BIG)).collect()
+    }
+
+    // creates the crossword with the words (without checking them)
+    fn from_parts(words: BTreeSet<PlacedWord<CharT, StrT>>, grid_template: Option<GridTemplate>, word_compatibility_settings: WordCompatibilitySettings<CharT>) -> Crossword<CharT, StrT>
+    {
+        let cells = CellMap::new(&words);
+        Crossword { words, grid_template, word_compatibility_settings, cells }
+    }
+
+    // adds the word without checking it
+    fn insert_word(&mut self, word: PlacedWord<CharT, StrT>)
+    {
+        self.cells.add(&word);
+        self.words.insert(word);
+    }
+
+    // removes the word, returns false if the crossword doesn't have it
+    fn take_word(&mut self, word: &PlacedWord<CharT, StrT>) -> bool
+    {
+        let removed = self.words.remove(word);
+        if removed { self.cells.remove(word); }
+        removed
+    }
+
+    /// Creates a new empty crossword with provided [settings](WordCompatibilitySettings)
+    pub fn new(word_compatibility_settings: WordCompatibilitySettings<CharT>) -> Crossword<CharT, StrT>
+    {
+        Crossword{ word_compatibility_settings, ..Default::default() }
+    }
+
+    /// Creates a crossword from already placed [words](PlacedWord) with provided [settings](WordCompatibilitySettings).
+    /// 
+    /// The words are [validated](Crossword::validate), and the crossword is normalized.
+    /// 
+    /// # Errors
+    /// 
+    /// All the errors found by [Crossword::validate].
     /// 
     /// # Example
     /// 
     /// ```
     /// # use crossword_generator::word::{Direction, Position};
     /// # use crossword_generator::placed_word::PlacedWord;
-    /// # use crossword_generator::crossword::Crossword;                                         
-    /// let mut cw = Crossword::default();                                                                  //     ---------
-    ///                                                                                                     //    |h e l l o|
-    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
-    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
-    ///                                                                                                     //    |    a    |
-    ///                                                                                                     //    |    l    |
-    ///                                                                                                     //     ---------
-    /// assert_eq!(cw.get_size(), (5, 5));
-    pub fn get_size(&self) -> (u16, u16)
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};
+    /// let cw = Crossword::from_placed_words([PlacedWord::<u8, &str>::new("hello", Position{x: 1, y: 1}, Direction::Right),
+    ///                                        PlacedWord::<u8, &str>::new("local", Position{x: 3, y: 1}, Direction::Down)], 
+    ///                                       WordCompatibilitySettings::default()).unwrap();
+    /// 
+    /// assert_eq!(cw.find_word(&"hello").unwrap().position, Position{x: 0, y: 0});
+    /// 
+    /// assert!(Crossword::from_placed_words([PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right),
+    ///                                       PlacedWord::<u8, &str>::new("world", Position{x: 2, y: 0}, Direction::Down)], 
+    ///                                      WordCompatibilitySettings::default()).is_err());
+    /// ```
+    pub fn from_placed_words(words: impl IntoIterator<Item = PlacedWord<CharT, StrT>>, word_compatibility_settings: WordCompatibilitySettings<CharT>) -> Result<Crossword<CharT, StrT>, Vec<CrosswordError<CharT, StrT>>>
     {
-        let mut max_corner = (0i16, 0i16);
-    
-        for word in self.words.iter()
-        {
-            max_corner.0 = max_corner.0.max(word.position.x + 1);
-            max_corner.1 = max_corner.1.max(word.position.y + 1);
-            match word.direction
-            {
-                Direction::Right => max_corner.0 = max_corner.0.max(word.position.x + word.value.as_ref().iter().count() as i16),
-                Direction::Down => max_corner.1 = max_corner.1.max(word.position.y + word.value.as_ref().iter().count() as i16), 
-            }
-        }
-    
-        (max_corner.0 as u16, max_corner.1 as u16)
+        Crossword::from_parts(words.into_iter().collect(), None, word_compatibility_settings).validated()
     }
 
-    /// Returns a matrix of characters that represent the crossword.
+    /// Creates a new empty crossword with the [template](GridTemplate) and provided [settings](WordCompatibilitySettings).
     /// 
     /// # Example
     /// 
     /// ```
     /// # use crossword_generator::word::{Direction, Position};
     /// # use crossword_generator::placed_word::PlacedWord;
-    /// # use crossword_generator::crossword::Crossword;                                         
-    /// let mut cw = Crossword::default();                                                                  //     ---------
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};
+    /// # use crossword_generator::template::GridTemplate;
+    /// let template = GridTemplate::from_strings(&["...#",
+    ///                                              ".#..",
+    ///                                              "...."]).unwrap();
+    /// let mut cw = Crossword::with_template(template, WordCompatibilitySettings::default());
+    /// 
+    /// // the words keep their positions
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cow", Position{x: 0, y: 2}, Direction::Right)).unwrap();
+    /// assert_eq!(cw.find_word(&"cow").unwrap().position, Position{x: 0, y: 2});
+    /// assert_eq!(cw.get_size(), (4, 3));
+    /// 
+    /// // the word covers a blocked cell
+    /// assert!(cw.add_word(PlacedWord::<u8, &str>::new("tic", Position{x: 1, y: 0}, Direction::Down)).is_err());
+    /// // the word leaves the template
+    /// assert!(cw.add_word(PlacedWord::<u8, &str>::new("bow", Position{x: 2, y: 0}, Direction::Right)).is_err());
+    /// ```
+    pub fn with_template(grid_template: GridTemplate, word_compatibility_settings: WordCompatibilitySettings<CharT>) -> Crossword<CharT, StrT>
+    {
+        Crossword{ grid_template: Some(grid_template), word_compatibility_settings, ..Default::default() }
+    }
+
+    /// Validates and normalizes the crossword.
+    fn validated(mut self) -> Result<Crossword<CharT, StrT>, Vec<CrosswordError<CharT, StrT>>>
+    {
+        self.validate()?;
+        self.normalize();
+        Ok(self)
+    }
+
+    /// Checks that the [words](PlacedWord) of the crossword are consistent with each other and with the [settings](WordCompatibilitySettings).
+    /// 
+    /// Useful for crosswords that were not built with [Crossword::add_word] (for example deserialized ones).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns all the errors found:
+    /// 
+    /// [CrosswordError::WordAlreadyExists] - Two words have the same value (contains the second one).
+    /// 
+    /// [CrosswordError::WordCompatibilityError] - Two words are not compatible (contains the first one).
+    /// 
+    /// [CrosswordError::InvalidAdjacentSequence] - A sequence of characters is rejected by the [validator](AdjacentSequenceValidator).
+    /// 
+    /// [CrosswordError::WordNotConnected] - The crossword is not connected (only if [WordCompatibilitySettings::require_intersection] is set).
+    /// 
+    /// [CrosswordError::WordDoesNotFitTemplate] - A word leaves the [template](GridTemplate) or covers a blocked cell.
+    pub fn validate(&self) -> Result<(), Vec<CrosswordError<CharT, StrT>>>
+    {
+        let mut errors = vec![];
+
+        if let Some(template) = &self.grid_template
+        {
+            errors.extend(self.words.iter().filter(|w| !template.can_contain(*w)).map(|w| CrosswordError::WordDoesNotFitTemplate(w.clone())));
+        }
+
+        for (first, second) in self.words.iter().tuple_combinations()
+        {
+            if first.value == second.value { errors.push(CrosswordError::WordAlreadyExists(second.clone())); }
+            else if let Some(err) = self.word_compatibility_settings.word_compatibility_issue(first, second) 
+            {
+                errors.push(CrosswordError::WordCompatibilityError(err, first.clone()));
+            }
+        }
+
+        errors.extend(self.words.iter().flat_map(|w| self.adjacent_sequence_issue(w)).unique());
+
+        if self.word_compatibility_settings.require_intersection && !self.is_connected() { errors.push(CrosswordError::WordNotConnected); }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Returns the [words](PlacedWord) of the crossword.
+    pub fn words(&self) -> &BTreeSet<PlacedWord<CharT, StrT>>
+    {
+        &self.words
+    }
+
+    /// Returns an iterator over the [words](PlacedWord) of the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_words([PlacedWord::<u8, &str>::new("hello", Position{ x: 0, y: 3 }, Direction::Right),
+    ///               PlacedWord::<u8, &str>::new("world", Position{ x: 2, y: 0 }, Direction::Down)].into_iter()).unwrap();
+    /// 
+    /// let placements = cw.iter().map(|w| (w.value, w.position.clone(), w.direction.clone())).collect::<Vec<_>>();
+    /// assert_eq!(placements, vec![
+    ///     ("hello", Position{ x: 0, y: 3 }, Direction::Right),
+    ///     ("world", Position{ x: 2, y: 0 }, Direction::Down),
+    /// ]);
+    /// 
+    /// // the crossword can be also iterated by reference
+    /// for word in &cw
+    /// {
+    ///     assert!(cw.find_word(&word.value).is_some());
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &PlacedWord<CharT, StrT>>
+    {
+        self.words.iter()
+    }
+
+    /// Returns the number of [words](PlacedWord) in the crossword.
+    pub fn len(&self) -> usize
+    {
+        self.words.len()
+    }
+
+    /// Returns true if the crossword has no [words](PlacedWord).
+    pub fn is_empty(&self) -> bool
+    {
+        self.words.is_empty()
+    }
+
+    /// Checks if a [word](PlacedWord) can or can't be added to the crossword
+    /// 
+    /// Returns [None] if the word can be added to the crossword
+    /// 
+    /// Returns Some([CrosswordError]) if the word can't be added to the crossword
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};                                         
+    /// let mut cw = Crossword::new(WordCompatibilitySettings::default());                                  //     ---------
     ///                                                                                                     //    |h e l l o|
     /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
     /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
     ///                                                                                                     //    |    a    |
     ///                                                                                                     //    |    l    |
     ///                                                                                                     //     ---------
-    /// assert_eq!(cw.generate_char_table(), vec!
-    /// [
-    ///     vec![ b'h',  b'e', b'l',  b'l',  b'o'],    
-    ///     vec![b'\0', b'\0', b'o', b'\0', b'\0'],
-    ///     vec![b'\0', b'\0', b'c', b'\0', b'\0'],
-    ///     vec![b'\0', b'\0', b'a', b'\0', b'\0'],
-    ///     vec![b'\0', b'\0', b'l', b'\0', b'\0']
-    /// ]);   
+    ///                                                                                             
+    /// assert!(cw.issue_when_adding_word(&PlacedWord::new("halo", Position { x: 0, y: 0 }, Direction::Down)).is_none());
+    /// ```
     /// 
-    /// // uses the default value for the empty cells                                              
+    /// Note that for example word halo on position (3, -2) and direction down is not allowed by a setting in word compatibility settings that forbids two words with same direction to be side to side
+    pub fn issue_when_adding_word(&self, word: &PlacedWord<CharT, StrT>) -> Option<CrosswordError<CharT, StrT>>
+    {
+        if let Some(w) = self.find_word(&word.value) { Some(CrosswordError::WordAlreadyExists(w.clone())) }
+        else if self.grid_template.as_ref().is_some_and(|template| !template.can_contain(word)) { Some(CrosswordError::WordDoesNotFitTemplate(word.clone())) }
+        else 
+        {
+            // the words are scanned only to find the incompatible word
+            let err = self.touches_incompatible_cells(word)
+                .then(|| self.words.iter()
+                    .flat_map(|w| self.word_compatibility_settings.word_compatibility_issue(w, word).map(|err| CrosswordError::WordCompatibilityError(err, w.clone())))
+                    .next())
+                .flatten();
+
+            if err.is_none()
+            {
+                self.shared_cells_issue(word).or_else(|| self.adjacent_sequence_issue(word))
+            }
+            else { err }
+        }
+    }
+
+    /// Returns true if the [word](PlacedWord) can be added to the crossword, same as checking that [Crossword::issue_when_adding_word] returns [None], 
+    /// but only the cells around the word are checked, without going through all words of the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// 
+    /// assert!(cw.can_word_be_added(&PlacedWord::new("local", Position{x: 2, y: 0}, Direction::Down)));
+    /// // side by side with hello
+    /// assert!(!cw.can_word_be_added(&PlacedWord::new("cat", Position{x: 1, y: 1}, Direction::Right)));
+    /// ```
+    pub fn can_word_be_added(&self, word: &PlacedWord<CharT, StrT>) -> bool
+    {
+        self.find_word(&word.value).is_none() &&
+        self.grid_template.as_ref().is_none_or(|template| template.can_contain(word)) &&
+        !self.touches_incompatible_cells(word) &&
+        self.shared_cells_issue(word).is_none() &&
+        self.adjacent_sequence_issue(word).is_none()
+    }
+
+    // the error if the word doesn't intersect any words, but it has to, or it shares too few cells with them 
+    // (check WordCompatibilitySettings::require_intersection and WordCompatibilitySettings::min_shared_cells)
+    fn shared_cells_issue(&self, word: &PlacedWord<CharT, StrT>) -> Option<CrosswordError<CharT, StrT>>
+    {
+        let settings = &self.word_compatibility_settings;
+        if self.words.is_empty() { return None; }
+        if settings.require_intersection && word.cells().all(|(pos, _)| !self.cells.contains(&pos)) { return Some(CrosswordError::WordNotConnected); }
+
+        let min = settings.min_shared_cells?;
+        let shared = word.cells().filter(|(pos, _)| self.cells.contains(pos)).count();
+        (shared < min).then_some(CrosswordError::NotEnoughSharedCells(shared))
+    }
+
+    // true if the word is not compatible with some word of the crossword (check WordCompatibilitySettings::word_compatibility_issue), 
+    // the relations of the words are derived from the cells of the word and the cells around it
+    fn touches_incompatible_cells(&self, word: &PlacedWord<CharT, StrT>) -> bool
+    {
+        const FIRST: u8 = DirectionMask::FIRST;
+        const LAST: u8 = DirectionMask::LAST;
+        let settings = &self.word_compatibility_settings;
+
+        // the coordinates along and across the word
+        let horizontal = word.direction.is_horizontal();
+        let bounding_box = word.get_bounding_box();
+        let (start, end, line) = if horizontal { (bounding_box.x, bounding_box.x + bounding_box.w - 1, bounding_box.y) } else { (bounding_box.y, bounding_box.y + bounding_box.h - 1, bounding_box.x) };
+        let cell = |along: i32, across: i32| if horizontal { self.cells.get(along, across) } else { self.cells.get(across, along) };
+        let covered = |mask: DirectionMask, same: bool| mask.has(same == horizontal, DirectionMask::COVERED);
+        let ends = |mask: DirectionMask, same: bool, flag: u8| mask.has(same == horizontal, flag);
+
+        // overlapping words, and intersections with different characters
+        for (pos, c) in word.cells()
+        {
+            let Some((char, mask)) = self.cells.get(pos.x as i32, pos.y as i32) else { continue; };
+            if covered(*mask, true) || char != c { return true; }
+        }
+
+        // the words on the neighboring lines, and the perpendicular words that end next to the word
+        for along in start..=end
+        {
+            for (across, end_flag) in [(line - 1, LAST), (line + 1, FIRST)]
+            {
+                let Some((_, mask)) = cell(along, across) else { continue; };
+                if covered(*mask, true) && !settings.side_by_side.get(&word.direction) { return true; }
+                if ends(*mask, false, end_flag) && !settings.side_by_head { return true; }
+            }
+        }
+
+        // the words before and after the word, and the words that touch its corners
+        for (along, end_flag) in [(start - 1, LAST), (end + 1, FIRST)]
+        {
+            if let Some((_, mask)) = cell(along, line)
+            {
+                if covered(*mask, true) && !settings.head_by_head.get(&word.direction) { return true; }
+                if covered(*mask, false) && !settings.side_by_head { return true; }
+            }
+            if settings.corner_by_corner { continue; }
+
+            for (across, across_end_flag) in [(line - 1, LAST), (line + 1, FIRST)]
+            {
+                let Some((_, mask)) = cell(along, across) else { continue; };
+                if ends(*mask, true, end_flag) || ends(*mask, false, across_end_flag) { return true; }
+            }
+        }
+
+        false
+    }
+
+    /// Checks the sequences of characters created by adding the word with the [validator](AdjacentSequenceValidator) (if there is one).
+    fn adjacent_sequence_issue(&self, word: &PlacedWord<CharT, StrT>) -> Option<CrosswordError<CharT, StrT>>
+    {
+        let validator = self.word_compatibility_settings.adjacent_sequence_validator.as_ref()?;
+
+        let mut cells = HashMap::new();
+        for w in self.words.iter().chain(std::iter::once(word))
+        {
+            for (pos, char) in w.cells()
+            {
+                cells.insert((pos.x as i32, pos.y as i32), char);
+            }
+        }
+
+        // the sequences are read from left to right or from top to bottom
+        let (dx, dy) = if word.direction.is_horizontal() { (0, 1) } else { (1, 0) };
+        for ind in 0..word.value.as_ref().len()
+        {
+            let pos = word.char_position(ind);
+            let mut start = (pos.x as i32, pos.y as i32);
+            while cells.contains_key(&(start.0 - dx, start.1 - dy)) { start = (start.0 - dx, start.1 - dy); }
+
+            let mut sequence = vec![];
+            let mut current = start;
+            while let Some(char) = cells.get(&current)
+            {
+                sequence.push((*char).clone());
+                current = (current.0 + dx, current.1 + dy);
+            }
+
+            if sequence.len() < 2 { continue; }
+
+            // the sequence is a word of the crossword
+            let sequence_box = WordBoundingBox { x: start.0, y: start.1, w: if dx == 1 { sequence.len() as i32 } else { 1 }, h: if dy == 1 { sequence.len() as i32 } else { 1 } };
+            if self.words.iter().any(|w| w.get_bounding_box() == sequence_box) { continue; }
+
+            if !(validator.0)(&sequence) { return Some(CrosswordError::InvalidAdjacentSequence(sequence)); }
+        }
+
+        None
+    }
+
+    /// Finds the [word](PlacedWord) given its string value.
+    pub fn find_word(&self, word: &StrT) -> Option<&PlacedWord<CharT, StrT>>
+    {
+        self.words.iter().find(|w| w.value == *word)
+    }
+
+    /// Adds the [word](PlacedWord) to the crossword without normalizing it, so the positions of other words stay the same (check [Crossword::add_word]).
+    pub fn add_word_unnormalized(&mut self, word: PlacedWord<CharT, StrT>) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        match self.issue_when_adding_word(&word)
+        {
+            None => { self.insert_word(word); Ok(()) },
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Adds the [word](PlacedWord) to the crossword.
+    /// Normalizes the crossword after adding the word.
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::WordNotConnected] - The word is isolated from the crossword (only if [WordCompatibilitySettings::require_intersection] is set).
+    /// 
+    /// [CrosswordError::NotEnoughSharedCells] - The word covers fewer cells of the other words than [WordCompatibilitySettings::min_shared_cells] (contains the count of the covered cells).
+    /// 
+    /// [CrosswordError::WordAlreadyExists] - A word with same value already exists in the crossword.
+    /// 
+    /// [CrosswordError::WordCompatibilityError] - Word can't be added because it's violates the [word compatilibity settings](WordCompatibilitySettings) or has conflict with some other word.
+    /// 
+    /// [CrosswordError::InvalidAdjacentSequence] - Word creates a sequence of characters with adjacent words, that is rejected by the [validator](AdjacentSequenceValidator).
+    /// 
+    /// [CrosswordError::WordDoesNotFitTemplate] - Word leaves the [template](GridTemplate) of the crossword or covers a blocked cell.
+    pub fn add_word(&mut self, word: PlacedWord<CharT, StrT>) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        self.add_word_unnormalized(word)?;
+        self.normalize();
+        Ok(())
+    }  
+
+    /// Adds the [words](PlacedWord) to the crossword.
+    /// Only normalizes the crossword after adding all the words. 
+    /// Note, that it's different from calling [Crossword::add_word] in a loop.
+    /// 
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::CantAddWord] - Word can't be added because it's violates the [word compatilibity settings](WordCompatibilitySettings) or has conflict with some other word.
+    /// 
+    /// [CrosswordError::WordAlreadyExists] - A word with same value already exists in the crossword.
+    pub fn add_words(&mut self, mut words: impl Iterator<Item = PlacedWord<CharT, StrT>>) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        let res = words.try_for_each(|w| self.add_word_unnormalized(w));
+        self.normalize();
+        res
+    }
+
+    /// Adds the [words](PlacedWord) to the crossword only if all of them can be added, otherwise leaves the crossword unchanged.
+    /// 
+    /// Every word is checked against the words of the crossword and the previous words of the batch.
+    /// Only normalizes the crossword after adding all the words (like [Crossword::add_words]).
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::BatchWordError] - The word with the specified index in the batch can't be added, contains the error of that word (check [Crossword::add_word]). 
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, CrosswordError};
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// 
+    /// let res = cw.try_add_words_atomic([PlacedWord::new("local", Position{x: 2, y: 0}, Direction::Down), 
+    ///                                    PlacedWord::new("hello", Position{x: 2, y: 4}, Direction::Right)].into_iter());
+    /// 
+    /// assert!(matches!(res, Err(CrosswordError::BatchWordError(1, _))));
+    /// assert_eq!(cw.len(), 1);
+    /// ```
+    pub fn try_add_words_atomic(&mut self, words: impl Iterator<Item = PlacedWord<CharT, StrT>>) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        let mut res = self.clone();
+        for (ind, word) in words.enumerate()
+        {
+            res.add_word_unnormalized(word).map_err(|err| CrosswordError::BatchWordError(ind, Box::new(err)))?;
+        }
+        res.normalize();
+
+        *self = res;
+        Ok(())
+    }
+
+    /// Returns the crossword with the words of another crossword shifted by the offset and added (check [Crossword::merge]), not normalized.
+    fn merged_with(&self, other: &Crossword<CharT, StrT>, offset: (i16, i16)) -> Result<Crossword<CharT, StrT>, CrosswordError<CharT, StrT>>
+    {
+        let shifted = other.words.iter()
+            .map(|w| w.shifted(offset.0, offset.1).map_err(|_| CrosswordError::CoordinateOverflow))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut res = self.clone();
+        shifted.iter().for_each(|w| res.insert_word(w.clone()));
+
+        for word in shifted.iter()
+        {
+            if self.grid_template.as_ref().is_some_and(|template| !template.can_contain(word)) { return Err(CrosswordError::WordDoesNotFitTemplate(word.clone())); }
+            if let Some(w) = self.find_word(&word.value) { return Err(CrosswordError::WordAlreadyExists(w.clone())); }
+
+            let err = self.words.iter()
+                .flat_map(|w| self.word_compatibility_settings.word_compatibility_issue(w, word).map(|err| CrosswordError::WordCompatibilityError(err, w.clone())))
+                .next();
+            if let Some(err) = err { return Err(err); }
+
+            if let Some(err) = res.adjacent_sequence_issue(word) { return Err(err); }
+        }
+
+        Ok(res)
+    }
+
+    /// Checks if another crossword can be merged into this crossword with the offset (check [Crossword::merge]).
+    pub fn fits_at(&self, other: &Crossword<CharT, StrT>, offset: (i16, i16)) -> bool
+    {
+        self.merged_with(other, offset).is_ok()
+    }
+
+    /// Merges another crossword into this crossword, shifting all words of the other crossword by the offset.
+    /// 
+    /// Every word of the other crossword is checked against the words of this crossword with the [settings](WordCompatibilitySettings) of this crossword, 
+    /// the words don't need to be connected. If any word can't be added, the crossword is left unchanged.
+    /// 
+    /// (normalizes the crossword after merging)
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::WordAlreadyExists] - A word of the other crossword has the same value as a word of this crossword.
+    /// 
+    /// [CrosswordError::WordCompatibilityError] - A word of the other crossword is not compatible with a word of this crossword.
+    /// 
+    /// [CrosswordError::InvalidAdjacentSequence] - A sequence of characters created by merging is rejected by the [validator](AdjacentSequenceValidator).
+    /// 
+    /// [CrosswordError::CoordinateOverflow] - A word of the other crossword can't be moved by the offset.
+    /// 
+    /// [CrosswordError::WordDoesNotFitTemplate] - A moved word of the other crossword leaves the [template](GridTemplate) of this crossword or covers a blocked cell.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw1 = Crossword::default();                                                                  //     ---------
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    ///                                                                                                      //    |    o    |
+    /// let mut cw2 = Crossword::default();                                                                  //    |    c    |
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 0, y: 0}, Direction::Down)).unwrap();  //    |    a    |
+    ///                                                                                                      //    |    l    |
+    /// assert!(!cw1.fits_at(&cw2, (1, 0)));                                                                 //     ---------
+    /// cw1.merge(cw2, (2, 0)).unwrap();
+    /// 
+    /// assert_eq!(cw1.find_word(&"local").unwrap().position, Position{x: 2, y: 0});
     /// ```
+    pub fn merge(&mut self, other: Crossword<CharT, StrT>, offset: (i16, i16)) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        let mut res = self.merged_with(&other, offset)?;
+        res.normalize();
+
+        *self = res;
+        Ok(())
+    }
+
+    /// Removes the [word](PlacedWord) from the crossword if finded.
+    /// 
+    /// Returns true if the word was succesfully removed.
+    /// 
+    /// Returns false if a word with provaded value was not found.
+    /// 
+    /// (normalizes the crossword after removing the word)
+    pub fn remove_word(&mut self, word: &StrT) -> bool
+    {
+        let removed = self.remove_word_unnormalized(word);
+        if removed { self.normalize(); }
+        removed
+    }
+
+    /// Removes the [word](PlacedWord) from the crossword without normalizing it, so the positions of other words stay the same (check [Crossword::remove_word]).
+    pub fn remove_word_unnormalized(&mut self, word: &StrT) -> bool
+    {
+        if let Some(word) = self.find_word(word).cloned()
+        {
+            self.take_word(&word)
+        }
+        else { false }
+    }
+
+    /// Removes all [words](PlacedWord) from the crossword, the settings stay the same.
+    pub fn clear(&mut self)
+    {
+        self.words.clear();
+        self.cells.clear();
+    }
+
+    /// Keeps only the [words](PlacedWord) for which the function returns true, and removes other words.
+    /// 
+    /// Like with [Crossword::remove_word] the remained words can be not [connected](Crossword::is_connected).
+    /// 
+    /// (normalizes the crossword once after removing the words)
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right)).unwrap();   //    |    c a t|
+    ///                                                                                                     //    |    a    |
+    /// cw.retain(|w| w.value.len() > 3);                                                                   //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.len(), 2);
+    /// assert!(cw.find_word(&"cat").is_none());
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&PlacedWord<CharT, StrT>) -> bool)
+    {
+        let cells = &mut self.cells;
+        self.words.retain(|w| 
+        {
+            let keep = f(w);
+            if !keep { cells.remove(w); }
+            keep
+        });
+        self.normalize();
+    }
+
+    /// Replaces the [word](PlacedWord) with provided value with the new word.
+    /// 
+    /// The new word is checked against all other words of the crossword (the replaced word is ignored).
+    /// If the new word can't be added, the crossword is left unchanged.
+    /// 
+    /// (normalizes the crossword after replacing the word)
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::WordNotFound] - A word with provided value was not found.
+    /// 
+    /// Any error that can be returned by [Crossword::add_word] for the new word.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    o    |
+    ///                                                                                                     //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// 
+    /// cw.replace_word(&"local", PlacedWord::new("lot", Position{x: 3, y: 0}, Direction::Down)).unwrap();  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// assert!(cw.find_word(&"local").is_none());                                                          //    |      o  |
+    /// assert_eq!(cw.find_word(&"lot").unwrap().position, Position{x: 3, y: 0});                           //    |      t  |
+    ///                                                                                                     //     ---------
+    /// ```
+    pub fn replace_word(&mut self, value: &StrT, new: PlacedWord<CharT, StrT>) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        let old = self.find_word(value).ok_or(CrosswordError::WordNotFound)?.clone();
+
+        let mut res = self.clone();
+        res.take_word(&old);
+        res.add_word_unnormalized(new)?;
+        res.normalize();
+
+        *self = res;
+        Ok(())
+    }
+
+    /// Moves the [word](PlacedWord) with provided value to the new position and direction.
+    /// 
+    /// Same as [Crossword::replace_word] with a word of the same value.
+    /// If the word can't be moved, the crossword is left unchanged.
+    /// 
+    /// (normalizes the crossword after moving the word)
+    pub fn move_word(&mut self, value: &StrT, new_pos: Position, new_dir: Direction) -> Result<(), CrosswordError<CharT, StrT>>
+    {
+        let meta = self.find_word(value).map(|w| w.meta.clone()).unwrap_or_default();
+        self.replace_word(value, PlacedWord::new(value.clone(), new_pos, new_dir).with_meta(meta))
+    }
+
+    /// Checks if another crossword is found inside this crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};  
+    /// // allowing two words to be side by side
+    /// let wcs = WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() };
+    ///                                                     
+    /// let mut cw1 = Crossword::<u8, &str>::new(wcs.clone());                                               //     ---------
+    ///                                                                                                      //    |h e l l o|
+    /// cw1.add_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right));                   //    |    o    |
+    /// cw1.add_word(PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down));                    //    |    c a t|
+    /// cw1.add_word(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right));                     //    |    a n o|
+    /// cw1.add_word(PlacedWord::new("and", Position { x: 3, y: 2 }, Direction::Down));                      //    |    l d y|
+    /// cw1.add_word(PlacedWord::new("toy", Position { x: 4, y: 2 }, Direction::Down));                      //     ---------
+    ///                                                                                                      
+    ///                                                                                         
+    ///
+    /// let mut cw2 = Crossword::new(wcs.clone());                                                           //     -----                 
+    ///                                                                                                      //    |c a t|
+    /// cw2.add_word(PlacedWord::new("cat", Position { x: 0, y: 0 }, Direction::Right));                     //    |  n o|
+    /// cw2.add_word(PlacedWord::new("and", Position { x: 1, y: 0 }, Direction::Down));                      //    |  d y|
+    /// cw2.add_word(PlacedWord::new("toy", Position { x: 2, y: 0 }, Direction::Down));                      //     -----
+    ///     
+    /// assert!(cw1.contains_crossword(&cw2));
+    /// ```
+    pub fn contains_crossword(&self, other: &Crossword<CharT, StrT>) -> bool 
+    {
+        if other.words.len() > self.words.len() { return false; }
+        other.words.is_empty() || self.placement_offsets(other).into_values().any(|count| count == other.words.len())
+    }
+
+    /// Returns the number of words of other crossword that are found in this crossword, for every offset (from the positions in other crossword to the positions in this crossword),
+    /// that at least one word is found with.
+    fn placement_offsets(&self, other: &Crossword<CharT, StrT>) -> HashMap<(i16, i16), usize>
+    {
+        let mut offsets = HashMap::new();
+        for other_word in other.words.iter()
+        {
+            let Some(cur_word) = self.find_word(&other_word.value) else { continue };
+            if cur_word.direction != other_word.direction { continue; }
+
+            *offsets.entry((cur_word.position.x - other_word.position.x, cur_word.position.y - other_word.position.y)).or_insert(0) += 1;
+        }
+        offsets
+    }
+
+    /// Returns the maximum number of words that are placed the same way in both crosswords, after translating one of the crosswords.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw1 = Crossword::default();                                                                  //     ---------
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    o    |
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right)).unwrap();   //    |    c a t|
+    ///                                                                                                      //    |    a    |
+    ///                                                                                                      //    |    l    |
+    ///                                                                                                      //     ---------
+    /// 
+    /// let mut cw2 = Crossword::default();                                                                  //     -----
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 0, y: 0}, Direction::Down)).unwrap();  //    |l    |
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 2}, Direction::Right)).unwrap();   //    |o    |
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 2}, Direction::Down)).unwrap();    //    |c a t|
+    ///                                                                                                      //    |a   o|
+    ///                                                                                                      //    |l   y|
+    ///                                                                                                      //     -----
+    /// assert_eq!(cw1.shared_placements(&cw2), 2);
+    /// assert_eq!(cw1.similarity(&cw2), 2.0 / 3.0);
+    /// ```
+    pub fn shared_placements(&self, other: &Crossword<CharT, StrT>) -> usize
+    {
+        self.placement_offsets(other).into_values().max().unwrap_or(0)
+    }
+
+    /// Returns the part of words that are placed the same way in both crosswords (check [Crossword::shared_placements]), from the larger crossword.
+    /// 
+    /// The similarity is symmetric, 1 for crosswords with the same layout (up to a translation) and 0 for crosswords without shared placements.
+    pub fn similarity(&self, other: &Crossword<CharT, StrT>) -> f64
+    {
+        let count = self.words.len().max(other.words.len());
+        if count == 0 { return 1.0; }
+
+        self.shared_placements(other) as f64 / count as f64
+    }
+
+    /// Checks if another crossword is found inside this crossword, possibly transposed or mirrored (depending on the [options](MatchOptions)).
+    /// 
+    /// With default options same as [Crossword::contains_crossword].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, MatchOptions};
+    /// let mut cw1 = Crossword::default();                                                                  //     ---------
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    o    |
+    ///                                                                                                      //    |    c    |
+    ///                                                                                                      //    |    a    |
+    ///                                                                                                      //    |    l    |
+    ///                                                                                                      //     ---------
+    /// 
+    /// let cw2 = cw1.transpose();
+    /// 
+    /// assert!(!cw1.contains_crossword(&cw2));
+    /// assert!(cw1.contains_crossword_with(&cw2, MatchOptions { allow_transpose: true, ..Default::default() }));
+    /// ```
+    pub fn contains_crossword_with(&self, other: &Crossword<CharT, StrT>, options: MatchOptions) -> bool
+    {
+        iproduct!([false, true], [false, true], [false, true])
+            .filter(|&(t, h, v)| (options.allow_transpose || !t) && (options.allow_mirror || !(h || v)))
+            .any(|(t, h, v)| self.contains_crossword(&other.transformed(t, h, v)))
+    }
+
+    /// Checks if two crosswords have the same [words](PlacedWord) on the same positions, ignoring the [settings](WordCompatibilitySettings).
+    pub fn same_layout(&self, other: &Crossword<CharT, StrT>) -> bool
+    {
+        self.words == other.words
+    }
+
+    /// Checks if this crossword is a shifted copy of another crossword (ignoring the [settings](WordCompatibilitySettings)).
+    /// 
+    /// Returns the offset (x, y), that needs to be added to the positions of words of the other crossword to get this crossword.
+    /// 
+    /// Returns None if the crosswords have different words, or the words are positioned differently.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};
+    /// let mut cw1 = Crossword::default();
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let mut cw2 = Crossword::new(WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() });
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: -2, y: 0}, Direction::Right)).unwrap();
+    /// 
+    /// assert_ne!(cw1, cw2);
+    /// assert!(cw1.same_layout(&cw2));
+    /// assert_eq!(cw1.is_translation_of(&cw2), Some((0, 0)));
+    /// ```
+    pub fn is_translation_of(&self, other: &Crossword<CharT, StrT>) -> Option<(i16, i16)>
+    {
+        if other.words.len() != self.words.len() { return None; }
+        if other.words.is_empty() { return Some((0, 0)); }
+
+        self.placement_offsets(other).into_iter().find_map(|(offset, count)| (count == other.words.len()).then_some(offset))
+    }
+
+    /// Returns the crossword with the placements of all words transformed by a symmetry of the grid, the result is normalized, but not validated.
+    /// 
+    /// Transposing (swapping x and y, and the directions of words) is applied first, then the mirrorings. 
+    /// The words keep their directions, so a mirrored word starts at the cell where it ended.
+    fn transformed(&self, transpose: bool, mirror_horizontal: bool, mirror_vertical: bool) -> Crossword<CharT, StrT>
+    {
+        let mut res = Crossword::new(self.word_compatibility_settings.clone());
+        res.grid_template = self.grid_template.as_ref().map(|template| template.transformed(transpose, mirror_horizontal, mirror_vertical));
+
+        // the words of a crossword with a template are mirrored inside the template
+        let (width, height) = res.grid_template.as_ref().map_or((0, 0), |template| (i32::from(template.width), i32::from(template.height)));
+        for word in self.words.iter()
+        {
+            let mut new_word = word.clone();
+            if transpose
+            {
+                new_word.position = Position { x: new_word.position.y, y: new_word.position.x };
+                new_word.direction = new_word.direction.opposite();
+            }
+
+            // the cell x becomes the cell -x - 1, and the word starts at the other end of its bounding box
+            let bounding_box = new_word.get_bounding_box();
+            let (dx, dy) = new_word.direction.step();
+            if mirror_horizontal { new_word.position.x = (width - new_word.position.x as i32 - 1 - (bounding_box.w - 1) * dx as i32) as i16; }
+            if mirror_vertical { new_word.position.y = (height - new_word.position.y as i32 - 1 - (bounding_box.h - 1) * dy as i32) as i16; }
+
+            res.insert_word(new_word);
+        }
+        res.normalize();
+        res
+    }
+
+    /// Returns the transposed crossword (x and y coordinates are swapped, horizontal words become vertical and vice versa).
+    /// 
+    /// The horizontal and vertical values of the [direction flags](DirectionFlags) in the [settings](WordCompatibilitySettings) are also swapped, so the result is always valid.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------          ---------
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|        |h        |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("lot", Position{x: 2, y: 0}, Direction::Down)).unwrap();    //    |    o    |  -->   |e        |
+    ///                                                                                                     //    |    t    |        |l o t    |
+    ///                                                                                                     //     ---------         |l        |
+    ///                                                                                                     //                       |o        |
+    ///                                                                                                     //                        ---------
+    /// let transposed = cw.transpose();
+    /// assert_eq!(transposed.get_size(), (3, 5));    
+    /// assert_eq!(transposed.find_word(&"lot").unwrap(), &PlacedWord::new("lot", Position{x: 0, y: 2}, Direction::Right));
+    /// ```
+    pub fn transpose(&self) -> Crossword<CharT, StrT>
+    {
+        let mut res = self.transformed(true, false, false);
+
+        let settings = &mut res.word_compatibility_settings;
+        for flags in [&mut settings.side_by_side, &mut settings.head_by_head]
+        {
+            std::mem::swap(&mut flags.horizontal, &mut flags.vertical);
+        }
+        settings.allowed_directions = settings.allowed_directions.iter().map(|dir| dir.opposite()).collect();
+        res
+    }
+
+    /// Returns the crossword mirrored horizontally (left and right sides are swapped).
+    /// 
+    /// The words still read left to right, so a horizontal word starts at the cell where it ended, 
+    /// and the characters on the intersections can change, making the crossword invalid.
+    /// 
+    /// # Errors
+    /// 
+    /// All the errors found by [Crossword::validate] in the mirrored crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     -----          -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("eye", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |e y e|        |e y e|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("ear", Position{x: 0, y: 0}, Direction::Down)).unwrap();    //    |a    |  -->   |    a|
+    ///                                                                                                     //    |r    |        |    r|
+    ///                                                                                                     //     -----          -----
+    /// let mirrored = cw.mirror_horizontal().unwrap();
+    /// assert_eq!(mirrored.find_word(&"ear").unwrap().position, Position{x: 2, y: 0});
+    /// 
+    /// let mut cw = Crossword::default();                                                                  //     -----          -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("ear", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |e a r|        |e a r|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("eye", Position{x: 0, y: 0}, Direction::Down)).unwrap();    //    |y    |  -->   |    y|
+    ///                                                                                                     //    |e    |        |    e|
+    ///                                                                                                     //     -----          -----
+    /// // 'r' and 'e' are on the same cell
+    /// assert!(cw.mirror_horizontal().is_err());
+    /// ```
+    pub fn mirror_horizontal(&self) -> Result<Crossword<CharT, StrT>, Vec<CrosswordError<CharT, StrT>>>
+    {
+        let res = self.transformed(false, true, false);
+        res.validate()?;
+        Ok(res)
+    }
+
+    /// Returns the crossword mirrored vertically (top and bottom sides are swapped).
+    /// 
+    /// The words still read top to bottom, so a vertical word starts at the cell where it ended, 
+    /// and the characters on the intersections can change, making the crossword invalid.
+    /// 
+    /// # Errors
+    /// 
+    /// All the errors found by [Crossword::validate] in the mirrored crossword.
+    pub fn mirror_vertical(&self) -> Result<Crossword<CharT, StrT>, Vec<CrosswordError<CharT, StrT>>>
+    {
+        let res = self.transformed(false, false, true);
+        res.validate()?;
+        Ok(res)
+    }
+
+    /// Returns the crossword rotated by 180 degrees (mirrored both horizontally and vertically).
+    /// 
+    /// The words still read left to right or top to bottom, so the characters on the intersections can change, making the crossword invalid.
+    /// 
+    /// # Errors
+    /// 
+    /// All the errors found by [Crossword::validate] in the rotated crossword.
+    pub fn rotate_180(&self) -> Result<Crossword<CharT, StrT>, Vec<CrosswordError<CharT, StrT>>>
+    {
+        let res = self.transformed(false, true, true);
+        res.validate()?;
+        Ok(res)
+    }
+
+    /// Returns the canonical form of the crossword modulo the symmetries of the grid.
+    /// 
+    /// Of all the eight transposed and mirrored versions of the crossword, that are valid (check [Crossword::validate]), returns the lexicographically smallest.
+    /// 
+    /// Note that a mirrored crossword is usually not valid, because the words still read left to right or top to bottom, 
+    /// so the characters on intersections change. A transposed crossword is valid if the [settings](WordCompatibilitySettings) are the same for both directions.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw1 = Crossword::default();                                                                  //     ---------
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap(); //    |h e l l o|
+    /// cw1.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();  //    |    o    |
+    ///                                                                                                      //    |    c    |
+    ///                                                                                                      //    |    a    |
+    ///                                                                                                      //    |    l    |
+    ///                                                                                                      //     ---------
+    /// 
+    /// let mut cw2 = Crossword::default();                                                                  //     ---------
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Down)).unwrap();  //    |h        |
+    /// cw2.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 0, y: 2}, Direction::Right)).unwrap(); //    |e        |
+    ///                                                                                                      //    |l o c a l|
+    ///                                                                                                      //    |l        |
+    ///                                                                                                      //    |o        |
+    ///                                                                                                      //     ---------
+    /// 
+    /// assert_eq!(cw1.canonicalize(), cw2.canonicalize());
+    /// assert!(cw1.equivalent_under_symmetry(&cw2));
+    /// ```
+    pub fn canonicalize(&self) -> Crossword<CharT, StrT>
+    {
+        // the symmetries keep the relations of the words (they are found from their bounding boxes), except the characters on the intersections, 
+        // so the versions of a valid crossword are valid if the characters still match, unless the settings differ for the directions (then the transposed ones are validated), 
+        // or there is an adjacent sequence validator (the mirrored sequences are reversed, all versions are validated)
+        let settings = &self.word_compatibility_settings;
+        let unchecked = self.validate().is_ok() && settings.adjacent_sequence_validator.is_none();
+        let symmetric = settings.side_by_side.horizontal == settings.side_by_side.vertical && settings.head_by_head.horizontal == settings.head_by_head.vertical;
+        let is_valid = |cw: &Crossword<CharT, StrT>, transposed: bool| match unchecked && (symmetric || !transposed)
+        {
+            true => cw.intersections_match(),
+            false => cw.validate().is_ok(),
+        };
+
+        std::iter::once(self.normalized())
+            .chain(iproduct!([false, true], [false, true], [false, true])
+                .skip(1)
+                .map(|(t, h, v)| (self.transformed(t, h, v), t))
+                .filter(|(cw, transposed)| is_valid(cw, *transposed))
+                .map(|(cw, _)| cw))
+            .min()
+            .unwrap()
+    }
+
+    // checks that the words have the same characters on all the cells they share
+    fn intersections_match(&self) -> bool
+    {
+        let mut cells = HashMap::new();
+        self.words.iter().flat_map(|w| w.cells()).all(|(pos, c)| cells.entry(pos).or_insert(c) == &c)
+    }
+
+    /// Checks if two crosswords are the same modulo the symmetries of the grid (check [Crossword::canonicalize]), ignoring the [settings](WordCompatibilitySettings).
+    pub fn equivalent_under_symmetry(&self, other: &Crossword<CharT, StrT>) -> bool
+    {
+        self.canonicalize().same_layout(&other.canonicalize())
+    }
+
+    /// Checks if all words of the crossword are connected with each other.
+    /// 
+    /// Two words are connected if they intersect and have the same character at the intersection, words that only touch each other are not connected.
+    /// 
+    /// An empty crossword is connected.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// assert!(cw.is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool
+    {
+        let words = self.words.iter().collect::<Vec<_>>();
+        if words.is_empty() { return true; }
+
+        let mut visited = vec![false; words.len()];
+        let mut stack = vec![0];
+        visited[0] = true;
+
+        while let Some(ind) = stack.pop()
+        {
+            for (other_ind, other) in words.iter().enumerate()
+            {
+                if !visited[other_ind] && words[ind].connects_with(other)
+                {
+                    visited[other_ind] = true;
+                    stack.push(other_ind);
+                }
+            }
+        }
+
+        visited.into_iter().all(|v| v)
+    }
+
+    /// Returns the number of words in the crossword that the [word](PlacedWord) intersects.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.intersection_count(cw.find_word(&"local").unwrap()), 2);
+    /// assert_eq!(cw.intersection_count(cw.find_word(&"cat").unwrap()), 1);
+    /// ```
+    pub fn intersection_count(&self, word: &PlacedWord<CharT, StrT>) -> usize
+    {
+        self.words.iter().filter(|w| w.get_intersection_indices(word).is_some()).count()
+    }
+
+    /// Returns all cells where two [words](PlacedWord) of the crossword intersect, with the intersecting words.
+    /// 
+    /// Every pair of words is reported once.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let intersections = cw.get_intersections();
+    /// assert_eq!(intersections.len(), 1);
+    /// assert_eq!(intersections[0].0, Position{x: 2, y: 0});
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn get_intersections(&self) -> Vec<(Position, &PlacedWord<CharT, StrT>, &PlacedWord<CharT, StrT>)>
+    {
+        self.words.iter()
+            .tuple_combinations()
+            .filter_map(|(first, second)| Some((first.intersection_position(second)?, first, second)))
+            .collect()
+    }
+
+    /// Returns the number of intersections in the crossword (check [Crossword::get_intersections]).
+    pub fn total_intersections(&self) -> usize
+    {
+        self.words.iter()
+            .tuple_combinations()
+            .filter(|(first, second)| first.get_intersection_indices(second).is_some())
+            .count()
+    }
+
+    /// Returns the number of cells in the crossword that are occupied by some word.
+    /// 
+    /// Doesn't generate the [character table](Crossword::generate_char_table), counts the cells from the word lengths and intersections instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.filled_cell_count(), 9);
+    /// ```
+    pub fn filled_cell_count(&self) -> usize
+    {
+        let total_length: usize = self.words.iter().map(|w| w.value.as_ref().len()).sum();
+
+        total_length - self.total_intersections()
+    }
+
+    /// Returns the [statistics](CrosswordStats) of the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let stats = cw.stats();
+    /// assert_eq!(stats.filled_cells, 11);
+    /// assert_eq!(stats.to_string(), 
+    /// "Words: 3 (2 across, 1 down)
+    /// Size: 5x5
+    /// Filled cells: 11 (44.0%)
+    /// Intersections: 2 (1.33 per word)
+    /// Word lengths: 3 to 5");
+    /// ```
+    pub fn stats(&self) -> CrosswordStats
+    {
+        let (width, height) = self.get_size();
+        let filled_cells = self.filled_cell_count();
+        let intersections = self.total_intersections();
+        let lengths = self.words.iter().map(|w| w.value.as_ref().len());
+        let across_words = self.words.iter().filter(|w| w.direction.is_horizontal()).count();
+        let area = width as usize * height as usize;
+
+        CrosswordStats
+        {
+            word_count: self.words.len(),
+            width,
+            height,
+            filled_cells,
+            fill_ratio: if area == 0 { 0.0 } else { filled_cells as f64 / area as f64 },
+            intersections,
+            average_intersections: if self.words.is_empty() { 0.0 } else { (intersections * 2) as f64 / self.words.len() as f64 },
+            longest_word: lengths.clone().max().unwrap_or(0),
+            shortest_word: lengths.min().unwrap_or(0),
+            across_words,
+            down_words: self.words.len() - across_words,
+        }
+    }
+
+    /// Returns all possible ways to add a [word](Word) into the crossword
+    /// 
+    /// The ways respect the direction constraint and the [placement constraint](Word::placement_constraint) of the word.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Word, Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};         
+    /// # use std::collections::BTreeSet;                                      
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    ///                                                                                         
+    /// assert_eq!(cw.calculate_possible_ways_to_add_word(&Word::new("halo", None)), 
+    ///             BTreeSet::from([
+    ///     PlacedWord::new("halo", Position { x: 0, y: 0 }, Direction::Down),
+    ///     PlacedWord::new("halo", Position { x: 4, y: -3 }, Direction::Down),
+    ///     PlacedWord::new("halo", Position { x: 0, y: 4 }, Direction::Right),
+    ///     PlacedWord::new("halo", Position { x: 1, y: 3 }, Direction::Right),
+    /// ]));
+    /// ```
+    /// 
+    /// 
+    /// 
+    /// Note that for example word halo on position 3 -2 and direction down is not allowed by a setting in word compatibility settings that forbids two words with same direction to be side to side
+    pub fn calculate_possible_ways_to_add_word(&self, word: &Word<CharT, StrT>) -> BTreeSet<PlacedWord<CharT, StrT>>
+    {
+        self.possible_placements(word).into_iter().collect()
+    }
+
+    // same as calculate_possible_ways_to_add_word, but in a sorted vector, for the generator that only iterates them
+    pub(crate) fn possible_placements(&self, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>
+    {
+        let directions = &self.word_compatibility_settings.allowed_directions;
+        // the constraint is relative to the top left corner of the words, so the crosswords that aren't normalized have the same placements
+        let (dx, dy) = self.min_corner();
+        let constraint = match word.placement_constraint.as_ref().map(|constr| constr.offset(dx, dy))
+        {
+            Some(None) => return vec![],
+            constraint => constraint.flatten(),
+        };
+        let allowed = |w: &PlacedWord<CharT, StrT>| constraint.as_ref().is_none_or(|constr| constr.allows(w));
+        if let (true, Some(template)) = (self.words.is_empty(), &self.grid_template)
+        {
+            // sorted like the placements of the other crosswords (the positions are iterated by rows)
+            return iproduct!(template.cells(), directions.iter())
+                .filter_map(|(pos, dir)| word.clone().place(pos, dir.clone()).ok())
+                .filter(allowed)
+                .filter(|w| self.can_word_be_added(w))
+                .sorted()
+                .collect()
+        }
+        if self.words.is_empty()
+        {
+            let dir = match &word.dir
+            {
+                Some(dir) => directions.contains(dir).then(|| dir.clone()),
+                None if directions.contains(&Direction::default()) => Some(Direction::default()),
+                None => directions.first().cloned(),
+            };
+            let pos = word.placement_constraint.as_ref().map_or(Position::default(), |constr| constr.anchor());
+            return dir.into_iter().filter_map(|dir| word.clone().place(pos.clone(), dir).ok()).collect()
+        }
+
+        self.crossing_placements(word).into_iter()
+            .filter(allowed)
+            .filter(|w: &PlacedWord<_, _>| self.can_word_be_added(w))
+            .collect()
+    }
+
+    // the placements of the word in the allowed directions that cross some word of the crossword with the same character (sorted, without duplicates), 
+    // same as the placements on all the words (check PlacedWord::calculate_possible_ways_to_add_word_in_directions), but only the cells with the characters of the word are visited
+    fn crossing_placements(&self, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>
+    {
+        let directions = &self.word_compatibility_settings.allowed_directions;
+        let mut placements = vec![];
+        for (word_ind, c) in word.value.as_ref().iter().enumerate()
+        {
+            for (x, y, cell_dir) in self.cells.occurrences(c)
+            {
+                for dir in directions.iter().filter(|dir| dir.is_horizontal() != cell_dir.is_horizontal() && word.dir.as_ref().is_none_or(|d| d == *dir))
+                {
+                    // the ways that don't fit in the range of the coordinates are skipped
+                    let (dx, dy) = dir.step();
+                    let position = (x - dx as i32 * word_ind as i32, y - dy as i32 * word_ind as i32);
+                    let Ok(position) = i16::try_from(position.0).and_then(|x| Ok(Position { x, y: i16::try_from(position.1)? })) else { continue; };
+                    if let Ok(way) = word.clone().place(position, dir.clone()) { placements.push(way); }
+                }
+            }
+        }
+
+        // a placement crossing more than one word is found for every crossed cell
+        placements.sort_unstable();
+        placements.dedup();
+        placements
+    }
+
+    /// Returns the size of the minimum rectangle that can contain the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.get_size(), (5, 5));
+    pub fn get_size(&self) -> (u16, u16)
+    {
+        if let Some(template) = &self.grid_template { return (template.width, template.height); }
+        if self.words.is_empty() { return (0, 0); }
+
+        let (min, max) = self.bounding_box();
+        ((max.x as i32 - min.x as i32 + 1) as u16, (max.y as i32 - min.y as i32 + 1) as u16)
+    }
+
+    /// Returns the [slots](Slot) of the [template](GridTemplate) that are not filled yet, with the known characters of every slot, or nothing if the crossword has no template.
+    /// 
+    /// A slot is filled if a [word](PlacedWord) with the same orientation covers all of its cells, 
+    /// so a slot is still unfilled if all its characters are known from the crossing words.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};
+    /// # use crossword_generator::template::{GridTemplate, Slot};
+    /// let template = GridTemplate::from_strings(&["...",
+    ///                                              ".#.",
+    ///                                              "..."]).unwrap();
+    /// let mut cw = Crossword::with_template(template, WordCompatibilitySettings::default());             //     -------
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();  //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("tie", Position{x: 2, y: 0}, Direction::Down)).unwrap();   //    |. # i|
+    ///                                                                                                    //    |. . e|
+    ///                                                                                                    //     -------
+    /// assert_eq!(cw.unfilled_slots(), vec![
+    ///     (Slot { position: Position { x: 0, y: 0 }, direction: Direction::Down, length: 3 }, vec![Some(b'c'), None, None]),
+    ///     (Slot { position: Position { x: 0, y: 2 }, direction: Direction::Right, length: 3 }, vec![None, None, Some(b'e')]),
+    /// ]);
+    /// ```
+    pub fn unfilled_slots(&self) -> Vec<(Slot, Vec<Option<CharT>>)>
+    {
+        let Some(template) = &self.grid_template else { return vec![]; };
+
+        template.slots().into_iter()
+            .filter(|slot| !self.words.iter().any(|w| w.direction.is_horizontal() == slot.direction.is_horizontal() && slot.positions().all(|pos| w.index_at_position(&pos).is_some())))
+            .map(|slot| 
+            {
+                let pattern = slot.positions().map(|pos| self.get(pos).cloned()).collect();
+                (slot, pattern)
+            })
+            .collect()
+    }
+
+    /// Returns the character on the position, or [None] if the cell is empty.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.char_at(Position{x: 2, y: 2}), Some(b'c'));
+    /// assert_eq!(cw.char_at(Position{x: 3, y: 2}), None);
+    /// assert_eq!(cw.char_at(Position{x: -1, y: 0}), None);
+    /// ```
+    pub fn char_at(&self, pos: Position) -> Option<CharT>
+    {
+        self.get(pos).cloned()
+    }
+
+    /// Returns a reference to the character on the position, or [None] if the cell is empty (check [Crossword::char_at]).
+    /// 
+    /// The crossword can also be indexed with positions, that panics for empty cells.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.get(Position{x: 2, y: 2}), Some(&b'c'));
+    /// assert_eq!(cw.get(Position{x: 5, y: 0}), None);
+    /// assert_eq!(cw[Position{x: 4, y: 0}], b'o');
+    /// ```
+    pub fn get(&self, pos: Position) -> Option<&CharT>
+    {
+        self.words.iter()
+            .find_map(|w| w.char_at_position(pos.clone()))
+    }
+
+    /// Returns an iterator over the filled cells of the crossword with their characters, in row-major order.
+    /// 
+    /// Every cell is returned once, even if it is shared by two words.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     -----
+    ///                                                                                                     //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right));            //    |    o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down));             //    |    y|
+    ///                                                                                                     //     -----
+    /// let cells = cw.cells().map(|(pos, c)| (pos.x, pos.y, *c)).collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![(0, 0, b'c'), (1, 0, b'a'), (2, 0, b't'), (2, 1, b'o'), (2, 2, b'y')]);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &CharT)>
+    {
+        self.words.iter()
+            .flat_map(|w| w.cells().map(|(pos, c)| ((pos.y, pos.x), c)))
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .map(|((y, x), c)| (Position { x, y }, c))
+    }
+
+    /// Returns the [words](PlacedWord) that cover the position, horizontal word first.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let words = cw.words_at(Position{x: 2, y: 0}).into_iter().map(|w| w.value).collect::<Vec<_>>();
+    /// assert_eq!(words, vec!["hello", "local"]);
+    /// assert!(cw.words_at(Position{x: 3, y: 2}).is_empty());
+    /// ```
+    pub fn words_at(&self, pos: Position) -> Vec<&PlacedWord<CharT, StrT>>
+    {
+        let mut words = self.words.iter()
+            .filter(|w| w.contains_position(pos.clone()))
+            .collect::<Vec<_>>();
+        
+        words.sort_by_key(|w| !w.direction.is_horizontal());
+        words
+    }
+
+    /// Returns the [words](PlacedWord) that have a character in the row, horizontal words first.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let words = cw.words_in_row(2).into_iter().map(|w| w.value).collect::<Vec<_>>();
+    /// assert_eq!(words, vec!["cat", "local"]);
+    /// assert!(cw.words_in_row(5).is_empty());
+    /// ```
+    pub fn words_in_row(&self, y: i16) -> Vec<&PlacedWord<CharT, StrT>>
+    {
+        self.words_in_line(true, y)
+    }
+
+    /// Returns the [words](PlacedWord) that have a character in the column, horizontal words first.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let words = cw.words_in_column(3).into_iter().map(|w| w.value).collect::<Vec<_>>();
+    /// assert_eq!(words, vec!["hello", "cat"]);
+    /// ```
+    pub fn words_in_column(&self, x: i16) -> Vec<&PlacedWord<CharT, StrT>>
+    {
+        self.words_in_line(false, x)
+    }
+
+    /// Returns the characters of the row, from the left side to the right side of the [size](Crossword::get_size) rectangle, [None] for the empty cells.
+    /// 
+    /// Rows outside of the crossword have only empty cells, the length is always the width of the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.row(2), vec![None, None, Some(b'c'), Some(b'a'), Some(b't')]);
+    /// assert_eq!(cw.row(-1), vec![None; 5]);
+    /// ```
+    pub fn row(&self, y: i16) -> Vec<Option<CharT>>
+    {
+        self.line(true, y)
+    }
+
+    /// Returns the characters of the column, from the top side to the bottom side of the [size](Crossword::get_size) rectangle, [None] for the empty cells.
+    /// 
+    /// Columns outside of the crossword have only empty cells, the length is always the height of the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 2}, Direction::Right));            //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.column(3), vec![Some(b'l'), None, Some(b'a'), None, None]);
+    /// ```
+    pub fn column(&self, x: i16) -> Vec<Option<CharT>>
+    {
+        self.line(false, x)
+    }
+
+    /// Returns the words that have a character in the row (if horizontal) or in the column, horizontal words first.
+    fn words_in_line(&self, horizontal: bool, index: i16) -> Vec<&PlacedWord<CharT, StrT>>
+    {
+        let mut words = self.words.iter()
+            .filter(|w|
+            {
+                let bounding_box = w.get_bounding_box();
+                let (start, length) = if horizontal { (bounding_box.y, bounding_box.h) } else { (bounding_box.x, bounding_box.w) };
+                (start..start + length).contains(&(index as i32))
+            })
+            .collect::<Vec<_>>();
+
+        words.sort_by_key(|w| !w.direction.is_horizontal());
+        words
+    }
+
+    /// Returns the characters of the row (if horizontal) or of the column.
+    fn line(&self, horizontal: bool, index: i16) -> Vec<Option<CharT>>
+    {
+        let min_corner = self.min_corner();
+        let (width, height) = self.get_size();
+        let (start, length) = if horizontal { (min_corner.0, width) } else { (min_corner.1, height) };
+
+        let mut line = vec![None; length as usize];
+        for word in self.words_in_line(horizontal, index)
+        {
+            for (pos, c) in word.cells()
+            {
+                let (along, across) = if horizontal { (pos.x, pos.y) } else { (pos.y, pos.x) };
+                if across == index { line[(along - start) as usize] = Some(c.clone()); }
+            }
+        }
+        line
+    }
+
+    /// Returns the entries of the crossword with the standard crossword numbering.
+    /// 
+    /// Cells that are the first cell of some word get consecutive numbers in row-major order, 
+    /// if a cell is the first cell of both horizontal and vertical words, both entries have the same number.
+    /// 
+    /// Entries are sorted by their numbers, horizontal entries first.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |1 e 2 l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |a   o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |l   c    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("halo", Position{x: 0, y: 0}, Direction::Down));            //    |o   a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// let entries = cw.numbered_entries().into_iter().map(|e| (e.number, e.direction, e.word.value)).collect::<Vec<_>>();
+    /// assert_eq!(entries, vec![(1, Direction::Right, "hello"), (1, Direction::Down, "halo"), (2, Direction::Down, "local")]);
+    /// ```
+    pub fn numbered_entries(&self) -> Vec<NumberedEntry<CharT, StrT>>
+    {
+        let mut words = self.words.iter().collect::<Vec<_>>();
+        words.sort_by_key(|w| (w.position.y, w.position.x, w.direction.clone()));
+
+        let mut entries: Vec<NumberedEntry<CharT, StrT>> = Vec::with_capacity(words.len());
+        for word in words
+        {
+            let number = match entries.last()
+            {
+                Some(last) if last.position == word.position => last.number,
+                Some(last) => last.number + 1,
+                None => 1,
+            };
+
+            entries.push(NumberedEntry { number, direction: word.direction.clone(), position: word.position.clone(), word: word.clone() });
+        }
+
+        entries
+    }
+
+    /// Returns a matrix of characters that represent the crossword.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;                                         
+    /// let mut cw = Crossword::default();                                                                  //     ---------
+    ///                                                                                                     //    |h e l l o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right));          //    |    o    |
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down));           //    |    c    |
+    ///                                                                                                     //    |    a    |
+    ///                                                                                                     //    |    l    |
+    ///                                                                                                     //     ---------
+    /// assert_eq!(cw.generate_char_table(), vec!
+    /// [
+    ///     vec![ b'h',  b'e', b'l',  b'l',  b'o'],    
+    ///     vec![b'\0', b'\0', b'o', b'\0', b'\0'],
+    ///     vec![b'\0', b'\0', b'c', b'\0', b'\0'],
+    ///     vec![b'\0', b'\0', b'a', b'\0', b'\0'],
+    ///     vec![b'\0', b'\0', b'l', b'\0', b'\0']
+    /// ]);   
+    /// 
+    /// // uses the default value for the empty cells                                              
+    /// ```
+    /// 
+    /// If the default value is a valid character, use [Crossword::generate_char_table_opt] or [Crossword::generate_char_table_with].
+    pub fn generate_char_table(&self) ->Vec<Vec<CharT>>
+    where
+        CharT: Default
+    {
+        self.generate_char_table_with(CharT::default())
+    }
+
+    /// Returns a matrix of characters that represent the crossword, with the filler for the empty cells.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();    //    |    o|
+    ///                                                                                                     //    |    y|
+    /// assert_eq!(cw.generate_char_table_with(b'.'), vec![b"cat".to_vec(), b"..o".to_vec(), b"..y".to_vec()]);
+    /// ```
+    pub fn generate_char_table_with(&self, filler: CharT) -> Vec<Vec<CharT>>
+    {
+        self.generate_char_table_opt().into_iter()
+            .map(|row| row.into_iter().map(|c| c.unwrap_or_else(|| filler.clone())).collect())
+            .collect()
+    }
+
+    /// Returns a matrix of characters that represent the crossword, with [None] for the empty cells.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();    //    |    o|
+    ///                                                                                                     //    |    y|
+    /// assert_eq!(cw.generate_char_table_opt(), vec![
+    ///     vec![Some(b'c'), Some(b'a'), Some(b't')],
+    ///     vec![None,       None,       Some(b'o')],
+    ///     vec![None,       None,       Some(b'y')],
+    /// ]);
+    /// ```
+    pub fn generate_char_table_opt(&self) -> Vec<Vec<Option<CharT>>>
+    {
+        let size = self.get_size();
+        let min_corner = self.min_corner();
+        let mut table = vec![vec![None; size.0 as usize]; size.1 as usize];
+        for word in self.words.iter()
+        {
+            for (pos, char) in word.cells()
+            {
+                table[(pos.y - min_corner.1) as usize][(pos.x - min_corner.0) as usize] = Some(char.clone());
+            }
+        }
+    
+        table
+    }
+
+    /// Returns a matrix of the cells of the crossword (in its [size](Crossword::get_size)), true for the filled ones.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();    //    |    o|
+    ///                                                                                                     //    |    y|
+    /// assert_eq!(cw.occupancy(), vec![vec![true, true, true], vec![false, false, true], vec![false, false, true]]);
+    /// ```
+    pub fn occupancy(&self) -> Vec<Vec<bool>>
+    {
+        let size = self.get_size();
+        let min_corner = self.min_corner();
+        let mut occupancy = vec![vec![false; size.0 as usize]; size.1 as usize];
+        for word in self.words.iter()
+        {
+            for (pos, _) in word.cells()
+            {
+                occupancy[(pos.y - min_corner.1) as usize][(pos.x - min_corner.0) as usize] = true;
+            }
+        }
+
+        occupancy
+    }
+
+    /// Returns true if the [pattern of the filled cells](Crossword::occupancy) is the same after rotating it by 180 degrees around the center of the crossword 
+    /// (the center of the bounding box of the words, or of the [template](GridTemplate)).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();                                                                  //     -----
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();   //    |c a t|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();    //    |i   o|
+    /// cw.add_word(PlacedWord::<u8, &str>::new("any", Position{x: 0, y: 2}, Direction::Right)).unwrap();   //    |a n y|
+    /// assert!(!cw.is_rotationally_symmetric());                                                           //     -----
+    /// 
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cia", Position{x: 0, y: 0}, Direction::Down)).unwrap();
+    /// assert!(cw.is_rotationally_symmetric());
+    /// ```
+    pub fn is_rotationally_symmetric(&self) -> bool
+    {
+        let occupancy = self.occupancy();
+        occupancy.iter().zip(occupancy.iter().rev()).all(|(row, rotated)| row.iter().eq(rotated.iter().rev()))
+    }
+
+    /// Returns the same crossword, but with words borrowed as slices.
+    pub(crate) fn as_slices(&self) -> Crossword<CharT, &[CharT]>
+    {
+        Crossword
+        {
+            word_compatibility_settings: self.word_compatibility_settings.clone(),
+            grid_template: self.grid_template.clone(),
+            words: self.words.iter().map(|w| PlacedWord::new(w.value.as_ref(), w.position.clone(), w.direction.clone()).with_meta(w.meta.clone())).collect(),
+            cells: self.cells.clone(),
+        }
+    }
+
+    /// Converts the crossword to a crossword with another string type, keeping the positions, directions and metadata of all words.
+    pub fn convert_to<StrT2: CrosswordString<CharT>>(self, f: impl Fn(StrT) -> StrT2) -> Crossword<CharT, StrT2>
+    {
+        // the words are already validated, so there is no need to add them one by one (that can fail, if a word is connected to the rest only through words that come after it)
+        Crossword
+        {
+            word_compatibility_settings: self.word_compatibility_settings,
+            grid_template: self.grid_template,
+            words: self.words
+                .into_iter()
+                .map(|w| 
+                    PlacedWord::new(f(w.value), w.position, w.direction).with_meta(w.meta)
+                ).collect(),
+            cells: self.cells,
+        }
+    }
+}
+
+impl<CharT: CrosswordChar> Crossword<CharT, Vec<CharT>>
+{
+    /// Creates a crossword from a matrix of characters (rows of cells, [None] for the empty cells), check [Crossword::generate_char_table_opt].
+    /// 
+    /// Every maximal horizontal or vertical sequence of at least two characters becomes a [word](PlacedWord), 
+    /// then the words are [validated](Crossword::validate) with provided [settings](WordCompatibilitySettings).
+    /// 
+    /// # Errors
+    /// 
+    /// [CrosswordError::IsolatedCharacter] - A character is not part of any sequence (single letter words are not supported).
+    /// 
+    /// [CrosswordError::WordAlreadyExists] - Two sequences are the same word (a crossword can't have two words with the same value).
+    /// 
+    /// The first error found by [Crossword::validate] otherwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::{Crossword, WordCompatibilitySettings};
+    /// let table = ["cat", "  o", "  y"].map(|row| row.bytes().map(|c| (c != b' ').then_some(c)).collect::<Vec<_>>());
+    /// 
+    /// let cw = Crossword::from_char_table(&table, WordCompatibilitySettings::default()).unwrap();
+    /// 
+    /// assert_eq!(cw.find_word(&b"cat".to_vec()).unwrap().position, Position{x: 0, y: 0});
+    /// assert_eq!(cw.find_word(&b"toy".to_vec()).unwrap().direction, Direction::Down);
+    /// ```
+    pub fn from_char_table(table: &[Vec<Option<CharT>>], word_compatibility_settings: WordCompatibilitySettings<CharT>) -> Result<Crossword<CharT, Vec<CharT>>, CrosswordError<CharT, Vec<CharT>>>
+    {
+        let cell = |x: usize, y: usize| table.get(y).and_then(|row| row.get(x)).and_then(|c| c.as_ref());
+        let width = table.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        let mut words: Vec<PlacedWord<CharT, Vec<CharT>>> = vec![];
+        let mut covered = BTreeSet::new();
+        for dir in [Direction::Right, Direction::Down]
+        {
+            let (lines, length) = if dir.is_horizontal() { (table.len(), width) } else { (width, table.len()) };
+            
+            for line in 0..lines
+            {
+                let at = |ind: usize| if dir.is_horizontal() { (ind, line) } else { (line, ind) };
+
+                let mut ind = 0;
+                while ind < length
+                {
+                    let start = ind;
+                    let mut value = vec![];
+                    while let Some(c) = (ind < length).then(|| at(ind)).and_then(|(x, y)| cell(x, y))
+                    {
+                        value.push(c.clone());
+                        ind += 1;
+                    }
+                    
+                    if value.len() >= 2
+                    {
+                        (start..ind).for_each(|i| { covered.insert(at(i)); });
+
+                        let (x, y) = at(start);
+                        let word = PlacedWord::new(value, Position { x: x as i16, y: y as i16 }, dir.clone());
+                        if let Some(w) = words.iter().find(|w| w.value == word.value) { return Err(CrosswordError::WordAlreadyExists(w.clone())); }
+                        words.push(word);
+                    }
+                    ind = ind.max(start + 1);
+                }
+            }
+        }
+
+        for (y, row) in table.iter().enumerate()
+        {
+            if let Some(x) = (0..row.len()).find(|&x| row[x].is_some() && !covered.contains(&(x, y)))
+            {
+                return Err(CrosswordError::IsolatedCharacter(Position { x: x as i16, y: y as i16 }));
+            }
+        }
+
+        Crossword::from_placed_words(words, word_compatibility_settings).map_err(|mut errs| errs.swap_remove(0))
+    }
+}
+
+/// Represents the compact form of a [crossword](Crossword): the rows of the char table and the words without positions (check [Crossword::to_grid_repr]).
+/// 
+/// The bytes of the cells are written as the characters with the same codes, and the empty cells as spaces.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct GridRepr
+{
+    pub grid: Vec<String>,
+    pub words: Vec<String>,
+}
+
+/// Error type for possible errors when restoring a [crossword](Crossword) from a [GridRepr].
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum GridReprError
+{
+    #[error("The character {0:?} in the grid is not a byte")]
+    InvalidCharacter(char),
+    #[error("The grid is not a valid crossword. Error: {0:?}")]
+    InvalidCrossword(CrosswordError<u8, Vec<u8>>),
+    #[error("The words of the grid are not the words of the list")]
+    WordsMismatch,
+}
+
+impl Crossword<u8, String>
+{
+    /// Returns the compact form of the crossword, that is smaller when serialized.
+    /// 
+    /// The crossword is restored with [Crossword::from_grid_repr], that finds the words in the grid like [Crossword::from_char_table], 
+    /// so only crosswords where every word is a maximal sequence of characters (for example the crossword with default [settings](WordCompatibilitySettings)), 
+    /// and without spaces in the words, can be restored.
+    /// 
+    /// To use the compact form when serializing a crossword in another struct, check [serde_grid].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, String>::new("cat".to_owned(), Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, String>::new("toy".to_owned(), Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let repr = cw.to_grid_repr();
+    /// assert_eq!(repr.grid, vec!["cat", "  o", "  y"]);
+    /// assert_eq!(repr.words, vec!["cat", "toy"]);
+    /// assert_eq!(Crossword::from_grid_repr(repr), Ok(cw));
+    /// ```
+    pub fn to_grid_repr(&self) -> GridRepr
+    {
+        GridRepr
+        {
+            grid: self.generate_char_table_opt().into_iter()
+                .map(|row| row.into_iter().map(|c| c.map_or(' ', char::from)).collect())
+                .collect(),
+            words: self.words.iter().map(|w| w.value.clone()).collect(),
+        }
+    }
+
+    /// Restores the crossword from the [compact form](GridRepr) (check [Crossword::to_grid_repr]), words are validated with default [settings](WordCompatibilitySettings).
+    /// 
+    /// # Errors
+    /// 
+    /// [GridReprError::InvalidCharacter] - The grid has a character that is not a byte.
+    /// 
+    /// [GridReprError::InvalidCrossword] - The grid is not a valid crossword (check [Crossword::from_char_table]).
+    /// 
+    /// [GridReprError::WordsMismatch] - The words found in the grid are not the words of the list.
+    pub fn from_grid_repr(repr: GridRepr) -> Result<Crossword<u8, String>, GridReprError>
+    {
+        let table = repr.grid.iter()
+            .map(|row| row.chars().map(|c| match c
+            {
+                ' ' => Ok(None),
+                c => u8::try_from(c).map(Some).map_err(|_| GridReprError::InvalidCharacter(c)),
+            }).collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cw = Crossword::from_char_table(&table, WordCompatibilitySettings::default()).map_err(GridReprError::InvalidCrossword)?;
+        
+        // the words of the list are taken by their indices, so the same value can be used by multiple words
+        let mut words = repr.words.into_iter().map(Some).collect::<Vec<_>>();
+        let words = cw.words.into_iter()
+            .map(|w| words.iter_mut().find(|value| value.as_ref().is_some_and(|value| value.as_bytes() == w.value)).and_then(Option::take)
+                .map(|value| PlacedWord::new(value, w.position, w.direction)))
+            .collect::<Option<Vec<_>>>()
+            .filter(|_| words.iter().all(Option::is_none))
+            .ok_or(GridReprError::WordsMismatch)?;
+
+        Ok(Crossword::from_parts(words.into_iter().collect(), None, WordCompatibilitySettings::default()))
+    }
+}
+
+/// Helper functions for serializing a [crossword](Crossword) in the [compact form](GridRepr) inside other structs, with `#[serde(with = "serde_grid")]`.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::{serde_grid, Crossword};
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Puzzle
+/// {
+///     title: String,
+///     #[serde(with = "serde_grid")]
+///     crossword: Crossword<u8, String>,
+/// }
+/// 
+/// let mut crossword = Crossword::default();
+/// crossword.add_word(PlacedWord::<u8, String>::new("cat".to_owned(), Position{x: 0, y: 0}, Direction::Right)).unwrap();
+/// crossword.add_word(PlacedWord::<u8, String>::new("toy".to_owned(), Position{x: 2, y: 0}, Direction::Down)).unwrap();
+/// let puzzle = Puzzle { title: "Cat".to_owned(), crossword };
+/// 
+/// let json = serde_json::to_string(&puzzle).unwrap();
+/// assert_eq!(json, r#"{"title":"Cat","crossword":{"grid":["cat","  o","  y"],"words":["cat","toy"]}}"#);
+/// assert_eq!(serde_json::from_str::<Puzzle>(&json).unwrap(), puzzle);
+/// ```
+pub mod serde_grid
+{
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{Crossword, GridRepr};
+
+    pub fn serialize<S: Serializer>(crossword: &Crossword<u8, String>, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        crossword.to_grid_repr().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Crossword<u8, String>, D::Error>
+    {
+        Crossword::from_grid_repr(GridRepr::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Index<Position> for Crossword<CharT, StrT>
+{
+    type Output = CharT;
+
+    /// Returns the character on the position (check [Crossword::get]).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the cell is empty.
+    fn index(&self, pos: Position) -> &CharT
+    {
+        self.get(pos).expect("The cell of the crossword is empty")
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Default for Crossword<CharT, StrT>
+{
+    fn default() -> Crossword<CharT, StrT>
+    {
+        Crossword
+        {
+            words: BTreeSet::new(),
+            grid_template: None,
+            word_compatibility_settings: WordCompatibilitySettings::default(),
+            cells: CellMap::default(),
+        }
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> IntoIterator for Crossword<CharT, StrT>
+{
+    type Item = PlacedWord<CharT, StrT>;
+    type IntoIter = <BTreeSet<PlacedWord<CharT, StrT>> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.into_iter()
+    }
+}
+
+impl<'a, CharT: CrosswordChar, StrT: CrosswordString<CharT>> IntoIterator for &'a Crossword<CharT, StrT>
+{
+    type Item = &'a PlacedWord<CharT, StrT>;
+    type IntoIter = <&'a BTreeSet<PlacedWord<CharT, StrT>> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.iter()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    
+    use std::collections::HashSet;
+
+    use crate::word::{PlacementConstraint, WordMetadata};
+
+    use super::*;
+
+    #[test]
+    fn test_crossword_contains_crossword() {
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        
+        let mut cw1 = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );
+        cw1.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let mut cw2 = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw2.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 1, y: 0 }, Direction::Down)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let mut cw3 = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        // the words are not connected, so they can't be added with add_word
+        cw3.insert_word(PlacedWord::<u8, &str>::new( "and", Position { x: 0, y: 1 }, Direction::Down));
+        cw3.insert_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 1, y: 0 }, Direction::Down));
+
+        assert_eq!([cw.contains_crossword(&cw1), cw.contains_crossword(&cw2), cw.contains_crossword(&cw3)], [true, true, false]);
+    }
+
+    #[derive(Debug)]
+    struct MaxWordCount(usize);
+
+    impl CrosswordConstraintFn<u8> for MaxWordCount
+    {
+        fn check(&self, crossword: &Crossword<u8, &[u8]>) -> bool
+        {
+            crossword.words.len() <= self.0
+        }
+    }
+
+    // records the addresses of the checked crosswords
+    #[derive(Debug, Default)]
+    struct CheckedCrosswords(std::sync::Mutex<Vec<usize>>);
+
+    impl CrosswordConstraintFn<u8> for CheckedCrosswords
+    {
+        fn check(&self, crossword: &Crossword<u8, &[u8]>) -> bool
+        {
+            self.0.lock().unwrap().push(crossword as *const _ as usize);
+            true
+        }
+    }
+
+    #[test]
+    fn test_crossword_custom_constraint() {
+        let custom: Arc<dyn CrosswordConstraintFn<u8>> = Arc::new(MaxWordCount(1));
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::Custom(custom.clone())] };
+
+        assert_eq!(settings.constraints[0], CrosswordConstraint::Custom(custom));
+        assert_ne!(settings.constraints[0], CrosswordConstraint::Custom(Arc::new(MaxWordCount(1))));
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert!(settings.check_nonrecoverables_constraints(&cw));
+        
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        assert!(!settings.check_nonrecoverables_constraints(&cw));
+        assert!(settings.check_recoverable_constraints(&cw));
+
+        // all the checks with the same view get the same converted crossword
+        let checked = Arc::new(CheckedCrosswords::default());
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::Custom(checked.clone()), CrosswordConstraint::Custom(checked.clone())] };
+        let view = ConstraintView::new();
+        assert_eq!(settings.failed_nonrecoverable_constraint_in(&cw, &view), None);
+        assert_eq!(settings.failed_nonrecoverable_constraint_in(&cw, &view), None);
+        let addresses = checked.0.lock().unwrap();
+        assert_eq!(addresses.len(), 4);
+        assert!(addresses.iter().all_equal());
+        assert_eq!(view.get().map(|view| view.words.len()), Some(2));
+    }
+
+    #[test]
+    fn test_crossword_failed_constraints() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::MaxLength(10), CrosswordConstraint::MaxHeight(4), CrosswordConstraint::ExactSize(5, 6), CrosswordConstraint::ContainsWord(b"cat".to_vec())] };
+        assert_eq!(settings.failed_nonrecoverable_constraint(&cw), Some(&CrosswordConstraint::MaxHeight(4)));
+        assert_eq!(settings.failed_recoverable_constraint(&cw), Some(&CrosswordConstraint::ExactSize(5, 6)));
+        assert_eq!(settings.failed_required_word(&cw, [b"cat".as_slice()].into_iter()), None);
+        assert_eq!(settings.failed_required_word(&cw, [b"toy".as_slice()].into_iter()), Some(&CrosswordConstraint::ContainsWord(b"cat".to_vec())));
+    }
+
+    #[test]
+    fn test_crossword_can_word_be_added() {
+        // the checks of all words, without the cells
+        fn issue_by_words(cw: &Crossword<u8, &'static str>, word: &PlacedWord<u8, &'static str>) -> Option<CrosswordError<u8, &'static str>>
+        {
+            let settings = &cw.word_compatibility_settings;
+            if let Some(w) = cw.words.iter().find(|w| w.value == word.value) { return Some(CrosswordError::WordAlreadyExists(w.clone())); }
+            if let Some(err) = cw.words.iter().flat_map(|w| settings.word_compatibility_issue(w, word).map(|err| CrosswordError::WordCompatibilityError(err, w.clone()))).next() { return Some(err); }
+            if settings.require_intersection && cw.words.iter().all(|w| !w.intersects(word)) { return Some(CrosswordError::WordNotConnected); }
+            let shared = word.cells().filter(|(pos, _)| cw.words.iter().any(|w| w.cells().any(|(p, _)| p == *pos))).count();
+            if settings.min_shared_cells.is_some_and(|min| shared < min) { return Some(CrosswordError::NotEnoughSharedCells(shared)); }
+            None
+        }
+
+        // compares the checks for words around the crossword, returns the count of the words that can be added
+        fn matches_words(cw: &Crossword<u8, &'static str>) -> usize
+        {
+            let mut added = 0;
+            for (value, x, y, dir) in iproduct!(["a", "at", "lot", "halo", "cat"], -3..=8, -3..=7, [Direction::Right, Direction::Down, Direction::Left, Direction::Up])
+            {
+                let word = PlacedWord::new(value, Position { x, y }, dir);
+                let issue = issue_by_words(cw, &word);
+                assert_eq!(cw.can_word_be_added(&word), issue.is_none(), "{:?} with settings {:?}", word, cw.word_compatibility_settings);
+                assert_eq!(cw.issue_when_adding_word(&word), issue);
+                added += issue.is_none() as usize;
+            }
+            added
+        }
+
+        //  -------------
+        // |h e l l o n  |
+        // |    o     o  |
+        // |    c a t    |
+        // |    a        |
+        // |    l   c a t|
+        //  -------------
+        let permissive = WordCompatibilitySettings { side_by_side: true.into(), head_by_head: true.into(), side_by_head: true, require_intersection: false, ..Default::default() };
+        let base = Crossword::from_placed_words([
+            PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right),
+            PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right),
+            PlacedWord::new("tac", Position { x: 6, y: 4 }, Direction::Left),
+            PlacedWord::new("on", Position { x: 5, y: 1 }, Direction::Up),
+        ], permissive).unwrap();
+
+        for (a, av, b, bv, c, d, e) in iproduct!(0..2, 0..2, 0..2, 0..2, 0..2, 0..2, 0..2)
+        {
+            let mut cw = base.clone();
+            cw.word_compatibility_settings = WordCompatibilitySettings 
+            { 
+                side_by_side: DirectionFlags { horizontal: a != 0, vertical: av != 0 }, 
+                head_by_head: DirectionFlags { horizontal: b != 0, vertical: bv != 0 }, 
+                side_by_head: c != 0, 
+                corner_by_corner: d != 0,
+                require_intersection: e != 0,
+                ..Default::default()
+            };
+
+            assert!(matches_words(&cw) > 0);
+        }
+
+        // one shared cell is the same as the required intersection
+        let mut cw = base.clone();
+        cw.word_compatibility_settings.min_shared_cells = Some(1);
+        let one_shared = matches_words(&cw);
+        cw.word_compatibility_settings = WordCompatibilitySettings { min_shared_cells: None, require_intersection: true, ..cw.word_compatibility_settings };
+        assert_eq!(matches_words(&cw), one_shared);
+        cw.word_compatibility_settings.min_shared_cells = Some(2);
+        assert_eq!(matches_words(&cw), 0);
+
+        // the cells follow the changes of the words
+        let mut cw = base.clone();
+        cw.word_compatibility_settings = WordCompatibilitySettings::default();
+        assert!(cw.remove_word(&"cat"));
+        matches_words(&cw);
+        cw.add_word(PlacedWord::new("oh", Position { x: 0, y: -1 }, Direction::Down)).unwrap();
+        assert_eq!(cw.find_word(&"hello").unwrap().position, Position { x: 0, y: 1 });
+        matches_words(&cw);
+        cw.replace_word(&"on", PlacedWord::new("on", Position { x: 4, y: 1 }, Direction::Down)).unwrap();
+        matches_words(&cw);
+        cw.retain(|w| w.value != "local");
+        matches_words(&cw);
+        matches_words(&cw.transpose());
+        cw.clear();
+        assert!(cw.cells.cells.is_empty());
+    }
+
+    #[test]
+    fn test_crossword_crossing_placements() {
+        // the placements on every word of the crossword
+        fn placements_on_words(cw: &Crossword<u8, &'static str>, word: &Word<u8, &'static str>) -> BTreeSet<PlacedWord<u8, &'static str>>
+        {
+            cw.words.iter().flat_map(|w| w.calculate_possible_ways_to_add_word_in_directions(word, &cw.word_compatibility_settings.allowed_directions)).collect()
+        }
+
+        let all_directions = BTreeSet::from([Direction::Right, Direction::Down, Direction::Left, Direction::Up]);
+        let permissive = WordCompatibilitySettings { side_by_side: true.into(), head_by_head: true.into(), side_by_head: true, require_intersection: false, allowed_directions: all_directions.clone(), ..Default::default() };
+        let mut cw = Crossword::from_placed_words([
+            PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right),
+            PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right),
+            PlacedWord::new("tac", Position { x: 6, y: 4 }, Direction::Left),
+            PlacedWord::new("on", Position { x: 5, y: 1 }, Direction::Up),
+        ], permissive).unwrap();
+
+        let words = ["a", "tall", "hollow", "cola", "hello"].into_iter()
+            .flat_map(|value| [None, Some(Direction::Right), Some(Direction::Up)].map(|dir| Word::new(value, dir)))
+            .collect::<Vec<_>>();
+        let check = |cw: &Crossword<u8, &'static str>|
+        {
+            for word in words.iter()
+            {
+                assert_eq!(cw.crossing_placements(word), placements_on_words(cw, word).into_iter().collect::<Vec<_>>(), "{:?}", word);
+            }
+        };
+
+        check(&cw);
+        cw.word_compatibility_settings.allowed_directions = BTreeSet::from([Direction::Down]);
+        check(&cw);
+        cw.word_compatibility_settings.allowed_directions = all_directions;
+
+        // the letters are moved with the words
+        cw.add_word(PlacedWord::new("oh", Position { x: 0, y: -1 }, Direction::Down)).unwrap();
+        check(&cw);
+        assert!(cw.remove_word(&"local"));
+        check(&cw);
+        cw.retain(|w| w.value != "oh");
+        check(&cw);
+        assert!(!cw.crossing_placements(&Word::new("tall", None)).is_empty());
+        cw.clear();
+        assert!(cw.crossing_placements(&Word::new("tall", None)).is_empty());
+    }
+
+    #[test]
+    fn test_crossword_is_connected() {
+        let mut cw = Crossword::<u8, &str>::default();
+        assert!(cw.is_connected());
+
+        // the words are not connected, so they can't be added with add_word
+        cw.insert_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right));
+        cw.insert_word(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right));
+        cw.insert_word(PlacedWord::new("toy", Position { x: 5, y: 0 }, Direction::Down));
+        assert!(!cw.is_connected());
+
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::Connected] };
+        assert!(settings.check_nonrecoverables_constraints(&cw));
+        assert!(!settings.check_recoverable_constraints(&cw));
+
+        // local connects hello and cat, but toy is only touching hello, so it's still not connected
+        cw.insert_word(PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down));
+        assert!(!cw.is_connected());
+
+        cw.take_word(&PlacedWord::new("toy", Position { x: 5, y: 0 }, Direction::Down));
+        assert!(cw.is_connected());
+        assert!(settings.check_recoverable_constraints(&cw));
+    }
+
+    #[test]
+    fn test_crossword_min_intersections_per_word() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+
+        let settings = |count| CrosswordSettings { constraints: vec![CrosswordConstraint::MinIntersectionsPerWord(count)] };
+
+        assert!(settings(1).check_recoverable_constraints(&cw));
+        assert!(!settings(2).check_recoverable_constraints(&cw));
+        assert!(settings(2).check_nonrecoverables_constraints(&cw));
+    }
+
+    #[test]
+    fn test_crossword_min_fill_ratio() {
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let filled = cw.generate_char_table().into_iter().flatten().filter(|c| *c != 0).count();
+        assert_eq!(cw.filled_cell_count(), filled);
+        assert_eq!(filled, 15);
+
+        let settings = |ratio| CrosswordSettings { constraints: vec![CrosswordConstraint::MinFillRatio(ratio)] };
+        assert!(settings(0.6).check_recoverable_constraints(&cw));
+        assert!(!settings(0.61).check_recoverable_constraints(&cw));
+        assert!(settings(0.61).check_nonrecoverables_constraints(&cw));
+    }
+
+    #[test]
+    fn test_crossword_contains_word_constraint() {
+        let mut cw1 = Crossword::default();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let mut cw2 = Crossword::default();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "halo", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::ContainsWord(b"local".to_vec())] };
+        assert!(settings.check_recoverable_constraints(&cw1));
+        assert!(!settings.check_recoverable_constraints(&cw2));
+        assert!(settings.check_nonrecoverables_constraints(&cw2));
+
+        assert!(settings.check_required_words(&cw1, std::iter::empty()));
+        assert!(!settings.check_required_words(&cw2, [b"cat".as_slice()].into_iter()));
+        assert!(settings.check_required_words(&cw2, [b"cat".as_slice(), b"local".as_slice()].into_iter()));
+    }
+
+    #[test]
+    fn test_crossword_adjacent_sequence_validator() {
+        let dictionary: BTreeSet<&[u8]> = BTreeSet::from([b"la".as_slice(), b"ok".as_slice()]);
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                adjacent_sequence_validator: Some(AdjacentSequenceValidator(Arc::new(move |seq: &[u8]| dictionary.contains(seq)))),
+                ..Default::default()
+            }
+        );
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        assert_eq!(cw.issue_when_adding_word(&PlacedWord::new("oat", Position { x: 2, y: 1 }, Direction::Right)), Some(CrosswordError::InvalidAdjacentSequence(b"ot".to_vec())));
+        assert_eq!(cw.add_word(PlacedWord::new("oak", Position { x: 2, y: 1 }, Direction::Right)), Ok(()));
+        
+        // the sequences that are words of the crossword are not validated
+        cw.add_word(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap_err();
+        cw.word_compatibility_settings.adjacent_sequence_validator = None;
+        assert_eq!(cw.add_word(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right)), Ok(()));
+    }
+
+    #[test]
+    fn test_crossword_require_intersection() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+
+        let corner = PlacedWord::new("cat", Position { x: 5, y: 1 }, Direction::Down);
+        let intersecting = PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down);
+
+        assert_eq!(cw.issue_when_adding_word(&corner), Some(CrosswordError::WordNotConnected));
+        assert_eq!(cw.issue_when_adding_word(&intersecting), None);
+
+        cw.word_compatibility_settings.require_intersection = false;
+        assert_eq!(cw.issue_when_adding_word(&corner), None);
+        assert_eq!(cw.issue_when_adding_word(&intersecting), None);
+
+        cw.word_compatibility_settings.corner_by_corner = false;
+        assert_eq!(cw.issue_when_adding_word(&corner), Some(CrosswordError::WordCompatibilityError(WordCompatibilityError::CornerByCorner, PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right))));
+    }
+
+    #[test]
+    fn test_crossword_min_shared_cells() {
+        //  ---------
+        // |h e l l o|
+        // |    o    |
+        // |    c a t|
+        // |    a    |
+        // |    l    |
+        //  ---------
+        // the validation doesn't count the shared cells
+        let mut cw = Crossword::<u8, &str>::from_placed_words([
+            PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right),
+            PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right),
+        ], WordCompatibilitySettings { min_shared_cells: Some(2), ..Default::default() }).unwrap();
+
+        // crosses hello and cat
+        let oat = PlacedWord::new("oat", Position { x: 4, y: 0 }, Direction::Down);
+        let halo = PlacedWord::new("halo", Position { x: 0, y: 0 }, Direction::Down);
+        assert_eq!(cw.issue_when_adding_word(&oat), None);
+        assert_eq!(cw.issue_when_adding_word(&halo), Some(CrosswordError::NotEnoughSharedCells(1)));
+        assert!(!cw.can_word_be_added(&halo));
+        assert_eq!(cw.clone().add_word(halo), Err(CrosswordError::NotEnoughSharedCells(1)));
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&Word::new("oat", None)), BTreeSet::from([oat.clone()]));
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&Word::new("halo", None)), BTreeSet::new());
+
+        // without the required intersection the count of the shared cells is still checked
+        let corner = PlacedWord::new("tea", Position { x: 5, y: 3 }, Direction::Down);
+        cw.word_compatibility_settings.require_intersection = false;
+        assert_eq!(cw.issue_when_adding_word(&corner), Some(CrosswordError::NotEnoughSharedCells(0)));
+        cw.word_compatibility_settings.min_shared_cells = None;
+        assert_eq!(cw.issue_when_adding_word(&corner), None);
+
+        assert_eq!(cw.add_word(oat), Ok(()));
+    }
+
+    #[test]
+    fn test_crossword_char_at_and_words_at() {
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let table = cw.generate_char_table();
+        for y in -1..=5i16
+        {
+            for x in -1..=5i16
+            {
+                let expected = table.get(y as usize).and_then(|row| row.get(x as usize)).filter(|c| **c != 0).cloned();
+                assert_eq!(cw.char_at(Position { x, y }), expected, "char at ({}, {})", x, y);
+                assert_eq!(cw.words_at(Position { x, y }).is_empty(), expected.is_none(), "words at ({}, {})", x, y);
+            }
+        }
+
+        let values = |pos| cw.words_at(pos).into_iter().map(|w| w.value).collect::<Vec<_>>();
+        assert_eq!(values(Position { x: 4, y: 2 }), vec!["cat", "toy"]);
+        assert_eq!(values(Position { x: 3, y: 3 }), vec!["and"]);
+        assert_eq!(values(Position { x: 2, y: 4 }), vec!["local"]);
+    }
+
+    #[test]
+    fn test_crossword_numbered_entries() {
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "halo", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+
+        //  ---------
+        // |1 e 2 l o|
+        // |a   o    |
+        // |l   3 4 5|
+        // |o   a n o|
+        // |    l d y|
+        //  ---------
+
+        let entries = cw.numbered_entries();
+        assert_eq!(entries.iter().map(|e| (e.number, e.direction.clone(), e.position.clone(), e.word.value)).collect::<Vec<_>>(), vec![
+            (1, Direction::Right, Position { x: 0, y: 0 }, "hello"),
+            (1, Direction::Down, Position { x: 0, y: 0 }, "halo"),
+            (2, Direction::Down, Position { x: 2, y: 0 }, "local"),
+            (3, Direction::Right, Position { x: 2, y: 2 }, "cat"),
+            (4, Direction::Down, Position { x: 3, y: 2 }, "and"),
+            (5, Direction::Down, Position { x: 4, y: 2 }, "toy"),
+        ]);
+
+        let json = serde_json::to_string(&entries[0]).unwrap();
+        assert_eq!(json, r#"{"number":1,"direction":"across","position":{"x":0,"y":0},"word":{"position":{"x":0,"y":0},"direction":"across","value":"hello"}}"#);
+    }
+
+    #[test]
+    fn test_crossword_get_intersections() {
+        let mut cw = Crossword::new(
+            WordCompatibilitySettings
+            {
+                side_by_side: true.into(),
+                ..Default::default()
+            }
+        );   
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.get_intersections().into_iter().map(|(pos, first, second)| (pos, first.value, second.value)).collect::<Vec<_>>(), vec![(Position { x: 2, y: 0 }, "hello", "local")]);
+
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let mut positions = cw.get_intersections().into_iter().map(|(pos, _, _)| pos).collect::<Vec<_>>();
+        positions.sort();
+        assert_eq!(positions, vec![Position { x: 2, y: 0 }, Position { x: 2, y: 2 }, Position { x: 3, y: 2 }, Position { x: 4, y: 2 }]);
+        assert_eq!(cw.total_intersections(), 4);
+        assert!(cw.get_intersections().into_iter().all(|(pos, first, second)| cw.words_at(pos).len() == 2 && first.direction != second.direction));
+    }
+
+    #[test]
+    fn test_crossword_try_add_words_atomic() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let before = cw.clone();
+        let before_json = serde_json::to_string(&cw).unwrap();
+
+        let res = cw.try_add_words_atomic([
+            PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right),
+            PlacedWord::new("toy", Position { x: 2, y: 2 }, Direction::Down),
+        ].into_iter());
+
+        assert!(matches!(res, Err(CrosswordError::BatchWordError(2, _))));
+        assert_eq!(cw, before);
+        assert_eq!(serde_json::to_string(&cw).unwrap(), before_json);
+
+        // words can be connected to the crossword only through the previous words of the batch
+        cw.try_add_words_atomic([
+            PlacedWord::new("cat", Position { x: 3, y: -2 }, Direction::Right),
+            PlacedWord::new("halo", Position { x: 4, y: -3 }, Direction::Down),
+        ].into_iter()).unwrap_err();
+        assert_eq!(cw, before);
+
+        cw.try_add_words_atomic([
+            PlacedWord::new("halo", Position { x: 4, y: -3 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 3, y: -2 }, Direction::Right),
+        ].into_iter()).unwrap();
+
+        let mut expected = Crossword::default();
+        expected.add_words([
+            PlacedWord::new("hello", Position { x: 0, y: 3 }, Direction::Right),
+            PlacedWord::new("halo", Position { x: 4, y: 0 }, Direction::Down),
+            PlacedWord::new("cat", Position { x: 3, y: 1 }, Direction::Right),
+        ].into_iter()).unwrap();
+        assert_eq!(cw, expected);
+    }
+
+    #[test]
+    fn test_crossword_move_word() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "lol", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        // the new placement is side by side only with the old placement of the word itself
+        cw.move_word(&"lol", Position { x: 3, y: 0 }, Direction::Down).unwrap();
+        assert_eq!(cw.len(), 2);
+        assert_eq!(cw.find_word(&"lol"), Some(&PlacedWord::new("lol", Position { x: 3, y: 0 }, Direction::Down)));
+
+        let before = cw.clone();
+        let res = cw.move_word(&"lol", Position { x: 4, y: 0 }, Direction::Down);
+        assert!(matches!(res, Err(CrosswordError::WordCompatibilityError(_, _))));
+        assert_eq!(cw, before);
+
+        assert_eq!(cw.move_word(&"low", Position { x: 4, y: 0 }, Direction::Down), Err(CrosswordError::WordNotFound));
+        assert_eq!(cw, before);
+
+        // the crossword is normalized once after moving
+        cw.move_word(&"lol", Position { x: 3, y: -2 }, Direction::Down).unwrap();
+        assert_eq!(cw.find_word(&"hello").unwrap().position, Position { x: 0, y: 2 });
+        assert_eq!(cw.find_word(&"lol").unwrap().position, Position { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn test_crossword_validate() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, String>::new("hello".to_owned(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, String>::new("local".to_owned(), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.validate(), Ok(()));
+
+        let json = serde_json::to_string(&cw).unwrap();
+        assert_eq!(serde_json::from_str::<Crossword<u8, String>>(&json).unwrap(), cw);
+
+        // world intersects hello at a different letter (the old direction names are also accepted)
+        let conflict = r#"{"words":[
+            {"position":{"x":0,"y":0},"direction":"Right","value":"hello"},
+            {"position":{"x":2,"y":0},"direction":"Down","value":"world"}
+        ]}"#;
+        let err = serde_json::from_str::<Crossword<u8, String>>(conflict).unwrap_err();
+        assert!(err.to_string().contains("InvalidIntersection"));
+
+        let errors = Crossword::from_placed_words([
+            PlacedWord::<u8, String>::new("hello".to_owned(), Position { x: 0, y: 0 }, Direction::Right),
+            PlacedWord::<u8, String>::new("world".to_owned(), Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::<u8, String>::new("hello".to_owned(), Position { x: 4, y: 0 }, Direction::Down),
+        ], WordCompatibilitySettings::default()).unwrap_err();
+        assert_eq!(errors, vec![
+            CrosswordError::WordCompatibilityError(WordCompatibilityError::InvalidIntersection, PlacedWord::new("hello".to_owned(), Position { x: 0, y: 0 }, Direction::Right)),
+            CrosswordError::WordAlreadyExists(PlacedWord::new("hello".to_owned(), Position { x: 4, y: 0 }, Direction::Down)),
+            CrosswordError::WordNotConnected,
+        ]);
+
+        // the deserialized crossword is normalized
+        let shifted = r#"{"words":[
+            {"position":{"x":1,"y":2},"direction":"across","value":"hello"},
+            {"position":{"x":3,"y":2},"direction":"down","value":"local"}
+        ]}"#;
+        assert_eq!(serde_json::from_str::<Crossword<u8, String>>(shifted).unwrap(), cw);
+    }
+
+    #[test]
+    fn test_crossword_deserialize_empty_word() {
+        // an empty word doesn't cover any cell (and doesn't panic while building the cells)
+        let empty = r#"{"words":[{"position":{"x":0,"y":0},"direction":"across","value":""}]}"#;
+        let cw = serde_json::from_str::<Crossword<u8, String>>(empty).unwrap();
+        assert_eq!(cw.words().len(), 1);
+        assert_eq!(cw.char_at(Position { x: 0, y: 0 }), None);
+        assert!(cw.cells.cells.is_empty());
+
+        let with_hello = r#"{"words":[
+            {"position":{"x":0,"y":0},"direction":"across","value":""},
+            {"position":{"x":0,"y":0},"direction":"across","value":"hello"}
+        ]}"#;
+        let err = serde_json::from_str::<Crossword<u8, String>>(with_hello).unwrap_err();
+        assert!(err.to_string().contains("WordNotConnected"));
+    }
+
+    #[test]
+    fn test_crossword_same_layout_and_translation() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let mut other_settings = cw.clone();
+        other_settings.word_compatibility_settings.side_by_head = true;
+        assert_ne!(cw, other_settings);
+        assert!(cw.same_layout(&other_settings));
+        assert_eq!(cw.is_translation_of(&other_settings), Some((0, 0)));
+
+        let mut shifted = Crossword::default();
+        shifted.insert_word(PlacedWord::new("hello", Position { x: 3, y: 2 }, Direction::Right));
+        shifted.insert_word(PlacedWord::new("local", Position { x: 5, y: 2 }, Direction::Down));
+        assert!(!cw.same_layout(&shifted));
+        assert_eq!(shifted.is_translation_of(&cw), Some((3, 2)));
+        assert_eq!(cw.is_translation_of(&shifted), Some((-3, -2)));
+
+        //  ---------          ---------
+        // |h e l l o|        |    l    |
+        // |    o    |        |    o    |
+        // |    c    |  -->   |    c    |
+        // |    a    |        |    a    |
+        // |    l    |        |h e l l o|
+        //  ---------          ---------
+        let mut mirrored = Crossword::default();
+        mirrored.add_words([PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 4 }, Direction::Right),
+                            PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)].into_iter()).unwrap();
+        assert!(!cw.same_layout(&mirrored));
+        assert_eq!(cw.is_translation_of(&mirrored), None);
+
+        // different word counts
+        let mut smaller = cw.clone();
+        smaller.remove_word(&"local");
+        assert!(cw.contains_crossword(&smaller));
+        assert_eq!(cw.is_translation_of(&smaller), None);
+    }
+
+    #[test]
+    fn test_crossword_transpose_mirror_rotate() {
+        let mut cw = Crossword::new(WordCompatibilitySettings { side_by_side: DirectionFlags { horizontal: true, vertical: false }, ..Default::default() });
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("ant", Position { x: 2, y: 3 }, Direction::Right)).unwrap();
+        let (w, h) = cw.get_size();
+
+        let transposed = cw.transpose();
+        assert_eq!(transposed.get_size(), (h, w));
+        assert_eq!(transposed.word_compatibility_settings.side_by_side, DirectionFlags { horizontal: false, vertical: true });
+        assert_eq!(transposed.validate(), Ok(()));
+        assert_ne!(transposed, cw);
+        assert_eq!(transposed.transpose(), cw);
+
+        // cat and ant become invalid
+        assert!(cw.mirror_horizontal().is_err());
+        assert!(cw.mirror_vertical().is_err());
+        assert!(cw.rotate_180().is_err());
+
+        //  ---------
+        // |h e l l o|
+        // |    o    |
+        // |    c    |
+        // |    a    |
+        // |    l    |
+        //  ---------
+        cw.remove_word(&"cat");
+        cw.remove_word(&"ant");
+        for mirrored in [cw.mirror_horizontal().unwrap(), cw.mirror_vertical().unwrap(), cw.rotate_180().unwrap()]
+        {
+            assert_eq!(mirrored.get_size(), cw.get_size());
+        }
+        assert_eq!(cw.mirror_horizontal().unwrap(), cw);
+        assert_eq!(cw.mirror_vertical().unwrap().find_word(&"hello").unwrap().position, Position { x: 0, y: 4 });
+        assert_eq!(cw.mirror_vertical().unwrap().mirror_vertical().unwrap(), cw);
+        assert_eq!(cw.rotate_180().unwrap(), cw.mirror_vertical().unwrap().mirror_horizontal().unwrap());
+        assert_eq!(cw.rotate_180().unwrap().rotate_180().unwrap(), cw);
+    }
+
+    #[test]
+    fn test_crossword_canonicalize() {
+        //  -----------
+        // |        w    |
+        // |h e l l o    |
+        // |        r    |
+        // |        l    |
+        // |        d o t|
+        //  -----------
+        let mut cw = Crossword::default();
+        cw.add_words([PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 1 }, Direction::Right),
+                      PlacedWord::<u8, &str>::new("world", Position { x: 4, y: 0 }, Direction::Down),
+                      PlacedWord::<u8, &str>::new("dot", Position { x: 4, y: 4 }, Direction::Right)].into_iter()).unwrap();
+
+        let mut transposed = Crossword::default();
+        transposed.add_words(cw.words.iter().map(|w| PlacedWord::new(w.value, Position { x: w.position.y, y: w.position.x }, w.direction.opposite()))).unwrap();
+        assert_eq!(transposed, cw.transformed(true, false, false));
+        assert_ne!(transposed, cw);
+
+        assert_eq!(cw.canonicalize(), transposed.canonicalize());
+        assert!(cw.equivalent_under_symmetry(&transposed));
+        assert!(cw.canonicalize() <= cw);
+        assert_eq!(cw.canonicalize().canonicalize(), cw.canonicalize());
+
+        // the mirrored crossword is not valid, so it is not considered
+        assert!(cw.transformed(false, true, false).validate().is_err());
+
+        let mut other = Crossword::default();
+        other.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        other.add_word(PlacedWord::<u8, &str>::new("world", Position { x: 4, y: -1 }, Direction::Down)).unwrap();
+        assert!(!cw.equivalent_under_symmetry(&other));
+
+        // the versions are not validated, but the result is the same as the smallest of the validated versions
+        fn validated<'a>(cw: &Crossword<u8, &'a str>) -> Crossword<u8, &'a str>
+        {
+            std::iter::once(cw.normalized())
+                .chain(iproduct!([false, true], [false, true], [false, true]).skip(1).map(|(t, h, v)| cw.transformed(t, h, v)).filter(|cw| cw.validate().is_ok()))
+                .min().unwrap()
+        }
+        assert_eq!(cw.canonicalize(), validated(&cw));
+
+        // "eye" and "eve" are the same mirrored
+        let mut mirrorable = Crossword::default();
+        mirrorable.add_words([PlacedWord::<u8, &str>::new("eye", Position { x: 0, y: 0 }, Direction::Right),
+                              PlacedWord::<u8, &str>::new("eve", Position { x: 0, y: 0 }, Direction::Down)].into_iter()).unwrap();
+        assert!(mirrorable.transformed(false, true, true).validate().is_ok());
+        assert_eq!(mirrorable.canonicalize(), validated(&mirrorable));
+
+        // the horizontal words can be side by side, but not the vertical ones, so the transposed versions are not valid
+        let settings = WordCompatibilitySettings { side_by_side: DirectionFlags { horizontal: true, vertical: false }, require_intersection: false, ..Default::default() };
+        let mut side_by_side = Crossword::new(settings);
+        side_by_side.add_words([PlacedWord::<u8, &str>::new("ab", Position { x: 0, y: 0 }, Direction::Right),
+                                PlacedWord::<u8, &str>::new("cd", Position { x: 0, y: 1 }, Direction::Right)].into_iter()).unwrap();
+        assert!(side_by_side.transformed(true, false, false).validate().is_err());
+        assert_eq!(side_by_side.canonicalize(), validated(&side_by_side));
+        assert!(side_by_side.canonicalize().iter().all(|w| w.direction == Direction::Right));
+    }
+
+    #[test]
+    fn test_crossword_merge() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let mut other = Crossword::default();
+        other.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        other.add_word(PlacedWord::<u8, &str>::new("toy", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        // disjoint regions
+        let mut merged = cw.clone();
+        assert!(merged.fits_at(&other, (-4, 6)));
+        merged.merge(other.clone(), (-4, 6)).unwrap();
+        assert_eq!(merged.len(), 4);
+        assert!(!merged.is_connected());
+        assert_eq!(merged.find_word(&"hello").unwrap().position, Position { x: 4, y: 0 });
+        assert_eq!(merged.find_word(&"cat").unwrap().position, Position { x: 0, y: 6 });
+        
+        // the 't' of toy is on the 'l' of hello
+        let before = cw.clone();
+        assert!(!cw.fits_at(&other, (1, 0)));
+        assert!(matches!(cw.merge(other.clone(), (1, 0)), Err(CrosswordError::WordCompatibilityError(WordCompatibilityError::InvalidIntersection, _))));
+        assert_eq!(cw, before);
+
+        let mut other = Crossword::default();
+        other.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert!(matches!(cw.merge(other.clone(), (0, 7)), Err(CrosswordError::WordAlreadyExists(_))));
+        assert_eq!(cw, before);
+
+        let mut other = Crossword::default();
+        other.add_word(PlacedWord::<u8, &str>::new("told", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+        cw.merge(other, (4, -1)).unwrap();
+        assert_eq!(cw.find_word(&"told").unwrap().position, Position { x: 4, y: 0 });
+        assert_eq!(cw.find_word(&"hello").unwrap().position, Position { x: 0, y: 1 });
+        assert!(cw.is_connected());
+    }
+
+    #[test]
+    fn test_crossword_contains_crossword_with() {
+        //  -----------
+        // |        w    |
+        // |h e l l o    |
+        // |        r    |
+        // |        l    |
+        // |        d o t|
+        //  -----------
+        let mut cw = Crossword::default();
+        cw.add_words([PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 1 }, Direction::Right),
+                      PlacedWord::<u8, &str>::new("world", Position { x: 4, y: 0 }, Direction::Down),
+                      PlacedWord::<u8, &str>::new("dot", Position { x: 4, y: 4 }, Direction::Right)].into_iter()).unwrap();
 
-    pub fn generate_char_table(&self) ->Vec<Vec<CharT>>
-    {
-        let size = self.get_size();
-        let mut table = vec![vec![CharT::default(); size.0 as usize]; size.1 as usize];
-        for word in self.words.iter()
-        {
-            for (index, char) in word.value.as_ref().iter().enumerate()
-            {
-                match word.direction
-                {
-                    Direction::Right => table[word.position.y as usize][word.position.x as usize + index] = char.clone(),
-                    Direction::Down => table[word.position.y as usize + index][word.position.x as usize] = char.clone(),
-                }
-            }
-        }
-    
-        table
+        let mut sub = Crossword::default();
+        sub.add_words([PlacedWord::<u8, &str>::new("world", Position { x: 0, y: 0 }, Direction::Down),
+                       PlacedWord::<u8, &str>::new("dot", Position { x: 0, y: 4 }, Direction::Right)].into_iter()).unwrap();
+        assert!(cw.contains_crossword(&sub));
+
+        let transposed = sub.transpose();
+        assert!(!cw.contains_crossword(&transposed));
+        assert!(!cw.contains_crossword_with(&transposed, MatchOptions::default()));
+        assert!(!cw.contains_crossword_with(&transposed, MatchOptions { allow_mirror: true, ..Default::default() }));
+        assert!(cw.contains_crossword_with(&transposed, MatchOptions { allow_transpose: true, ..Default::default() }));
+
+        // positions of words are mirrored, but the words still read left to right or top to bottom
+        let mut mirrored = Crossword::default();
+        mirrored.insert_word(PlacedWord::new("hello", Position { x: 0, y: 1 }, Direction::Right));
+        mirrored.insert_word(PlacedWord::new("world", Position { x: 0, y: 0 }, Direction::Down));
+        assert!(!cw.contains_crossword_with(&mirrored, MatchOptions { allow_transpose: true, ..Default::default() }));
+        assert!(cw.contains_crossword_with(&mirrored, MatchOptions { allow_mirror: true, ..Default::default() }));
     }
 
-    pub fn convert_to<StrT2: CrosswordString<CharT>>(self, f: impl Fn(StrT) -> StrT2) -> Crossword<CharT, StrT2>
-    {
-        let mut res = Crossword::default();
+    #[test]
+    fn test_crossword_unnormalized_edits() {
+        let mut cw = Crossword::default();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position { x: -3, y: -2 }, Direction::Right)).unwrap();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("local", Position { x: -1, y: -2 }, Direction::Down)).unwrap();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("cat", Position { x: -1, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw.find_word(&"hello").unwrap().position, Position { x: -3, y: -2 });
 
-        res.add_words(self
-            .into_iter()
-            .map(|w| 
-                PlacedWord::new(f(w.value), w.position, w.direction)
-            )).unwrap();
-    
-        res
+        assert_eq!(cw.get_size(), (5, 5));
+        assert_eq!(cw.generate_char_table(), vec![
+            b"hello".to_vec(),
+            b"\0\0o\0\0".to_vec(),
+            b"\0\0cat".to_vec(),
+            b"\0\0a\0\0".to_vec(),
+            b"\0\0l\0\0".to_vec(),
+        ]);
+        assert_eq!(cw.char_at(Position { x: 1, y: 0 }), Some(b't'));
+        assert_eq!(cw.words_at(Position { x: -1, y: 0 }).len(), 2);
+
+        assert!(cw.remove_word_unnormalized(&"hello"));
+        assert!(!cw.remove_word_unnormalized(&"hello"));
+        assert_eq!(cw.find_word(&"local").unwrap().position, Position { x: -1, y: -2 });
+        assert_eq!(cw.get_size(), (3, 5));
+
+        let mut normalized = Crossword::default();
+        normalized.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+        normalized.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 0, y: 2 }, Direction::Right)).unwrap();
+        assert_ne!(cw, normalized);
+
+        assert_eq!(cw.normalize(), (1, 2));
+        assert_eq!(cw, normalized);
+        assert_eq!(cw.normalize(), (0, 0));
     }
-}
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Default for Crossword<CharT, StrT>
-{
-    fn default() -> Crossword<CharT, StrT>
-    {
-        Crossword
+    #[test]
+    fn test_crossword_bounding_box() {
+        let corners = |min: (i16, i16), max: (i16, i16)| (Position { x: min.0, y: min.1 }, Position { x: max.0, y: max.1 });
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("ice", Position { x: 1, y: 2 }, Direction::Right)).unwrap();
+        assert_eq!(cw.bounding_box(), corners((0, 0), (4, 4)));
+        assert_eq!(cw.get_size(), (5, 5));
+
+        // an interior word
+        assert!(cw.remove_word_unnormalized(&"ice"));
+        assert_eq!(cw.bounding_box(), corners((0, 0), (4, 4)));
+        cw.add_word_unnormalized(PlacedWord::new("ice", Position { x: 1, y: 2 }, Direction::Right)).unwrap();
+
+        // a word on the bottom border
+        assert!(cw.remove_word_unnormalized(&"local"));
+        assert_eq!(cw.bounding_box(), corners((0, 0), (4, 2)));
+        assert_eq!(cw.get_size(), (5, 3));
+        assert!(cw.remove_word_unnormalized(&"ice"));
+        assert_eq!(cw.bounding_box(), corners((0, 0), (4, 0)));
+        cw.add_word_unnormalized(PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        // a word on the top border, with a cell on the border covered by another word
+        assert!(cw.remove_word_unnormalized(&"hello"));
+        assert_eq!(cw.bounding_box(), corners((2, 0), (2, 4)));
+        assert_eq!(cw.get_size(), (1, 5));
+
+        // the bounding box moves with the words
+        cw.add_word_unnormalized(PlacedWord::new("zinc", Position { x: -1, y: 2 }, Direction::Right)).unwrap();
+        assert_eq!(cw.bounding_box(), corners((-1, 0), (2, 4)));
+        // the last added word on the border
+        assert!(cw.remove_word_unnormalized(&"zinc"));
+        assert_eq!(cw.bounding_box(), corners((2, 0), (2, 4)));
+        cw.add_word_unnormalized(PlacedWord::new("zinc", Position { x: -1, y: 2 }, Direction::Right)).unwrap();
+        assert_eq!(cw.normalize(), (1, 0));
+        assert_eq!(cw.bounding_box(), corners((0, 0), (3, 4)));
+        assert!(cw.remove_word(&"local"));
+        assert_eq!(cw.bounding_box(), corners((0, 0), (3, 0)));
+
+        cw.clear();
+        assert_eq!(cw.bounding_box(), corners((0, 0), (0, 0)));
+        assert_eq!(cw.get_size(), (0, 0));
+
+        // the bounds are kept only for the words that can be removed in the reverse order of adding
+        let mut cw = Crossword::from_placed_words([
+            PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right), 
+            PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down),
+        ], WordCompatibilitySettings::default()).unwrap();
+        assert!(cw.cells.added.is_empty());
+        for _ in 0..10
         {
-            words: BTreeSet::new(),
-            word_compatibility_settings: WordCompatibilitySettings::default()
+            cw.add_word_unnormalized(PlacedWord::new("ice", Position { x: 1, y: 2 }, Direction::Right)).unwrap();
+            cw.add_word_unnormalized(PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Down)).unwrap_err();
+            assert_eq!(cw.cells.added.len(), 1);
+            assert!(cw.remove_word_unnormalized(&"ice"));
+            assert!(cw.cells.added.is_empty());
         }
+        cw.add_word_unnormalized(PlacedWord::new("ice", Position { x: 1, y: 2 }, Direction::Right)).unwrap();
+        assert!(cw.remove_word_unnormalized(&"hello"));
+        assert!(cw.cells.added.is_empty());
+        assert_eq!(cw.bounding_box(), corners((1, 0), (3, 4)));
+
+        // only the words are in the bounding box of a crossword with a template
+        let template = GridTemplate::from_strings(&["....", "....", "...."]).unwrap();
+        let mut cw = Crossword::<u8, &str>::with_template(template, WordCompatibilitySettings::default());
+        cw.add_word(PlacedWord::new("cow", Position { x: 1, y: 1 }, Direction::Right)).unwrap();
+        assert_eq!(cw.bounding_box(), corners((1, 1), (3, 1)));
+        assert_eq!(cw.get_size(), (4, 3));
     }
-}
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> IntoIterator for Crossword<CharT, StrT>
-{
-    type Item = PlacedWord<CharT, StrT>;
-    type IntoIter = <BTreeSet<PlacedWord<CharT, StrT>> as IntoIterator>::IntoIter;
+    #[test]
+    fn test_crossword_generate_char_table_opt() {
+        // the default digit is a valid character
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Debug, Hash)]
+        struct Digit(u8);
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.words.into_iter()
-    }
-}
+        let digits = |s: &str| s.bytes().map(|b| Digit(b - b'0')).collect::<Vec<_>>();
 
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<Digit, Vec<Digit>>::new(digits("102"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<Digit, Vec<Digit>>::new(digits("200"), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
 
-#[cfg(test)]
-mod tests {
-    
+        let table = cw.generate_char_table_opt();
+        assert_eq!(table, vec![
+            vec![Some(Digit(1)), Some(Digit(0)), Some(Digit(2))],
+            vec![None, None, Some(Digit(0))],
+            vec![None, None, Some(Digit(0))],
+        ]);
 
-    use super::*;
+        // the empty cells and the zeros are indistinguishable in the plain table
+        assert_eq!(cw.generate_char_table()[1], vec![Digit(0), Digit(0), Digit(0)]);
+        assert_eq!(cw.generate_char_table_with(Digit(9))[1], vec![Digit(9), Digit(9), Digit(0)]);
+
+        let filled = table.iter().flatten().flatten().count();
+        assert_eq!(filled, cw.filled_cell_count());
+    }
 
     #[test]
-    fn test_crossword_contains_crossword() {
-        let mut cw = Crossword::new(
-            WordCompatibilitySettings
-            {
-                side_by_side: true,
-                ..Default::default()
-            }
-        );   
-        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
-        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
-        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
-        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
-        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+    fn test_crossword_from_char_table() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+        let cw = cw.convert_to(|w| w.as_bytes().to_vec());
 
-        
-        let mut cw1 = Crossword::new(
-            WordCompatibilitySettings
-            {
-                side_by_side: true,
-                ..Default::default()
-            }
-        );
-        cw1.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
-        cw1.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
-        cw1.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
-        cw1.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
-        cw1.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+        let parsed = Crossword::from_char_table(&cw.generate_char_table_opt(), WordCompatibilitySettings::default()).unwrap();
+        assert!(parsed.same_layout(&cw));
+        assert_eq!(parsed.generate_char_table_opt(), cw.generate_char_table_opt());
 
-        let mut cw2 = Crossword::new(
-            WordCompatibilitySettings
-            {
-                side_by_side: true,
-                ..Default::default()
-            }
-        );   
-        cw2.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
-        cw2.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 1, y: 0 }, Direction::Down)).unwrap();
-        cw2.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        let table = |rows: &[&str]| rows.iter().map(|row| row.bytes().map(|c| (c != b' ').then_some(c)).collect::<Vec<_>>()).collect::<Vec<_>>();
 
-        let mut cw3 = Crossword::new(
-            WordCompatibilitySettings
-            {
-                side_by_side: true,
-                ..Default::default()
-            }
-        );   
-        cw3.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
-        cw3.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 1, y: -1 }, Direction::Down)).unwrap();
+        assert_eq!(Crossword::from_char_table(&table(&["cat", "   ", "  x"]), WordCompatibilitySettings::default()), Err(CrosswordError::IsolatedCharacter(Position { x: 2, y: 2 })));
+        assert_eq!(Crossword::from_char_table(&table(&["on", "n "]), WordCompatibilitySettings::default()), Err(CrosswordError::WordAlreadyExists(PlacedWord::new(b"on".to_vec(), Position { x: 0, y: 0 }, Direction::Right))));
+        
+        // side by side words are rejected by the settings
+        assert!(matches!(Crossword::from_char_table(&table(&["at", "no"]), WordCompatibilitySettings::default()), Err(CrosswordError::WordCompatibilityError(WordCompatibilityError::SideBySide, _))));
+        let parsed = Crossword::from_char_table(&table(&["at", "no"]), WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() }).unwrap();
+        assert_eq!(parsed.len(), 4);
 
-        assert_eq!([cw.contains_crossword(&cw1), cw.contains_crossword(&cw2), cw.contains_crossword(&cw3)], [true, true, false]);
+        assert!(Crossword::<u8, Vec<u8>>::from_char_table(&[], WordCompatibilitySettings::default()).unwrap().is_empty());
     }
 
     #[test]
@@ -675,7 +4204,7 @@ mod tests {
         let mut cw = Crossword::new(
             WordCompatibilitySettings
             {
-                side_by_side: true,
+                side_by_side: true.into(),
                 ..Default::default()
             }
         );   
@@ -690,7 +4219,7 @@ mod tests {
         let mut cw_rm = Crossword::new(
             WordCompatibilitySettings
             {
-                side_by_side: true,
+                side_by_side: true.into(),
                 ..Default::default()
             }
         );   
@@ -707,7 +4236,7 @@ mod tests {
         let mut cw = Crossword::new(
             WordCompatibilitySettings
             {
-                side_by_side: true, // |-
+                side_by_side: true.into(), // |-
                 side_by_head: true, // ||
                 ..Default::default()
             }
@@ -727,8 +4256,606 @@ mod tests {
             PlacedWord::new(new_word.value, Position { x: -2, y: 1 }, Direction::Right), //||
             PlacedWord::new(new_word.value, Position { x: 4, y: -4 }, Direction::Down),
             ].into_iter().collect());
+
+        // the direction constraint of the word is respected in an empty crossword
+        let empty = Crossword::<u8, &str>::default();
+        assert_eq!(empty.calculate_possible_ways_to_add_word(&Word::new("hello", Some(Direction::Down))), BTreeSet::from([("hello", Position::default(), Direction::Down).into()]));
+        assert_eq!(empty.calculate_possible_ways_to_add_word(&"hello".into()), BTreeSet::from([("hello", Position::default(), Direction::Right).into()]));
+        assert!(empty.calculate_possible_ways_to_add_word(&Word::new("hello", Some(Direction::Up))).is_empty());
+    }
+
+
+
+
+    #[test]
+    fn test_crossword_grid_repr() {
+        // crossword, camel, otter, snake, whale, rainbow, mat, rye, ebb, wax
+        let grid = [
+            "crossword  ",
+            "a t n h a  ",
+            "mat a a i  ",
+            "e e k l n  ",
+            "l rye ebb  ",
+            "        o  ",
+            "        wax",
+        ];
+        let table = grid.map(|row| row.bytes().map(|c| (c != b' ').then_some(c)).collect::<Vec<_>>());
+        let cw = Crossword::from_char_table(&table, WordCompatibilitySettings::default()).unwrap();
+        let cw = Crossword::from_placed_words(cw.into_iter().map(|w| PlacedWord::<u8, String>::new(String::from_utf8(w.value).unwrap(), w.position, w.direction)), WordCompatibilitySettings::default()).unwrap();
+        assert_eq!(cw.len(), 10);
+
+        let repr = cw.to_grid_repr();
+        assert_eq!(repr.grid, grid);
+        assert_eq!(Crossword::from_grid_repr(repr.clone()), Ok(cw.clone()));
+
+        let compact = serde_json::to_string(&repr).unwrap();
+        let verbose = serde_json::to_string(&cw).unwrap();
+        assert!(compact.len() < verbose.len());
+        assert_eq!(Crossword::from_grid_repr(serde_json::from_str(&compact).unwrap()), Ok(cw));
+
+        let missing = GridRepr { words: repr.words[1..].to_vec(), ..repr.clone() };
+        assert_eq!(Crossword::from_grid_repr(missing), Err(GridReprError::WordsMismatch));
+        let extra = GridRepr { words: [repr.words.clone(), vec!["ant".to_owned()]].concat(), ..repr.clone() };
+        assert_eq!(Crossword::from_grid_repr(extra), Err(GridReprError::WordsMismatch));
+        // a repeated word of the list is not used by the grid
+        let repeated = GridRepr { words: [repr.words.clone(), vec![repr.words[0].clone()]].concat(), ..repr.clone() };
+        assert_eq!(Crossword::from_grid_repr(repeated), Err(GridReprError::WordsMismatch));
+        let invalid = GridRepr { grid: vec!["c\u{100}t".to_owned()], ..repr };
+        assert_eq!(Crossword::from_grid_repr(invalid), Err(GridReprError::InvalidCharacter('\u{100}')));
+    }
+
+    #[test]
+    fn test_crossword_hash() {
+        let mut cw1 = Crossword::default();
+        cw1.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let mut cw2 = Crossword::default();
+        cw2.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: -2, y: 0 }, Direction::Right)).unwrap();
+
+        let mut with_validator = cw1.clone();
+        with_validator.word_compatibility_settings.adjacent_sequence_validator = Some(AdjacentSequenceValidator(Arc::new(|_: &[u8]| true)));
+        let mut other_validator = cw1.clone();
+        other_validator.word_compatibility_settings.adjacent_sequence_validator = Some(AdjacentSequenceValidator(Arc::new(|_: &[u8]| false)));
+
+        let mut other_settings = cw1.clone();
+        other_settings.word_compatibility_settings.side_by_head = true;
+
+        let set = HashSet::from([cw1.clone(), cw2, with_validator, other_validator, other_settings.clone()]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&cw1));
+        assert!(set.contains(&other_settings));
+        assert!(cw1.same_layout(&other_settings));
+
+        let mut cw3 = cw1.clone();
+        cw3.remove_word(&"local");
+        assert!(!set.contains(&cw3));
+
+        let words = HashSet::<&PlacedWord<u8, &str>>::from_iter(&cw1);
+        assert_eq!(words, HashSet::from_iter(&other_settings));
+    }
+
+    #[test]
+    fn test_crossword_stats() {
+        let mut cw = Crossword::new(WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() });
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let stats = cw.stats();
+        assert_eq!(stats, CrosswordStats
+        {
+            word_count: 5,
+            width: 5,
+            height: 5,
+            filled_cells: 15,
+            fill_ratio: 0.6,
+            intersections: 4,
+            average_intersections: 1.6,
+            longest_word: 5,
+            shortest_word: 3,
+            across_words: 2,
+            down_words: 3,
+        });
+        assert_eq!(stats.to_string(), [
+            "Words: 5 (2 across, 3 down)",
+            "Size: 5x5",
+            "Filled cells: 15 (60.0%)",
+            "Intersections: 4 (1.60 per word)",
+            "Word lengths: 3 to 5",
+        ].join("\n"));
+        assert_eq!(serde_json::from_str::<CrosswordStats>(&serde_json::to_string(&stats).unwrap()).unwrap(), stats);
+
+        assert_eq!(Crossword::<u8, &str>::default().stats(), CrosswordStats::default());
+    }
+
+    #[test]
+    fn test_crossword_rows_and_columns() {
+        let mut cw = Crossword::new(WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() });
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let table = cw.generate_char_table_opt();
+        for y in 0..5
+        {
+            assert_eq!(cw.row(y), table[y as usize]);
+        }
+        for x in 0..5
+        {
+            assert_eq!(cw.column(x), table.iter().map(|row| row[x as usize]).collect::<Vec<_>>());
+        }
+
+        let values = |words: Vec<&PlacedWord<u8, &'static str>>| words.into_iter().map(|w| w.value).collect::<Vec<_>>();
+        assert_eq!(values(cw.words_in_row(2)), vec!["cat", "local", "and", "toy"]);
+        assert_eq!(values(cw.words_in_row(4)), vec!["local", "and", "toy"]);
+        assert_eq!(values(cw.words_in_column(4)), vec!["hello", "cat", "toy"]);
+        assert_eq!(cw.row(3), vec![None, None, Some(b'a'), Some(b'n'), Some(b'o')]);
+
+        assert_eq!(cw.row(5), vec![None; 5]);
+        assert_eq!(cw.column(-3), vec![None; 5]);
+        assert!(cw.words_in_row(-1).is_empty());
+        assert!(Crossword::<u8, &str>::default().row(0).is_empty());
+
+        // the lines are in the coordinates of the words for a not normalized crossword
+        cw.add_word_unnormalized(PlacedWord::new("oh", Position { x: 0, y: -1 }, Direction::Down)).unwrap();
+        assert_eq!(cw.row(-1), vec![Some(b'o'), None, None, None, None]);
+        assert_eq!(cw.column(0), vec![Some(b'o'), Some(b'h'), None, None, None, None]);
+    }
+
+    #[test]
+    fn test_crossword_cells() {
+        let mut cw = Crossword::new(WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() });
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let cells = cw.cells().collect::<Vec<_>>();
+        assert_eq!(cells.len(), cw.filled_cell_count());
+        assert!(cells.iter().tuple_windows().all(|((a, _), (b, _))| (a.y, a.x) < (b.y, b.x)));
+        
+        let expected = cw.generate_char_table_opt().into_iter().enumerate()
+            .flat_map(|(y, row)| row.into_iter().enumerate().filter_map(move |(x, c)| c.map(|c| (Position { x: x as i16, y: y as i16 }, c))))
+            .collect::<Vec<_>>();
+        assert_eq!(cells.iter().map(|(pos, c)| (pos.clone(), **c)).collect::<Vec<_>>(), expected);
+
+        for (pos, c) in &cells
+        {
+            assert_eq!(cw.get(pos.clone()), Some(*c));
+            assert_eq!(&cw[pos.clone()], *c);
+        }
+
+        assert_eq!(cw.get(Position { x: 1, y: 1 }), None);
+        assert_eq!(cw.get(Position { x: -1, y: 0 }), None);
+        assert_eq!(cw.get(Position { x: 5, y: 2 }), None);
+        assert_eq!(cw.get(Position { x: 2, y: 5 }), None);
+        assert_eq!(Crossword::<u8, &str>::default().cells().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crossword_index_empty_cell() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let _ = cw[Position { x: 0, y: 1 }];
+    }
+
+    #[test]
+    fn test_crossword_retain_and_clear() {
+        let mut cw = Crossword::new(WordCompatibilitySettings { side_by_side: true.into(), ..Default::default() });
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "and", Position { x: 3, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        let mut right = cw.clone();
+        right.retain(|w| w.direction == Direction::Right);
+        assert_eq!(right.into_iter().collect::<Vec<_>>(), vec![
+            PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right),
+            PlacedWord::new("cat", Position { x: 2, y: 2 }, Direction::Right),
+        ]);
+
+        //  ---------          -----
+        // |h e l l o|        |l    |
+        // |    o    |        |o    |
+        // |    c a t|   ->   |c a t|
+        // |    a n o|        |a n o|
+        // |    l d y|        |l d y|
+        //  ---------          -----
+        let mut down = cw.clone();
+        down.retain(|w| w.direction == Direction::Down);
+        assert_eq!(down.into_iter().collect::<Vec<_>>(), vec![
+            PlacedWord::new("local", Position { x: 0, y: 0 }, Direction::Down),
+            PlacedWord::new("and", Position { x: 1, y: 2 }, Direction::Down),
+            PlacedWord::new("toy", Position { x: 2, y: 2 }, Direction::Down),
+        ]);
+
+        let settings = cw.word_compatibility_settings.clone();
+        cw.clear();
+        assert!(cw.is_empty());
+        assert_eq!(cw.word_compatibility_settings, settings);
+    }
+
+    #[test]
+    fn test_crossword_similarity() {
+        let mut cw1 = Crossword::default();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw1.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        // all placements are shared
+        assert_eq!(cw1.shared_placements(&cw1), 4);
+        assert_eq!(cw1.similarity(&cw1), 1.0);
+
+        // local, cat and toy are shared, hello is placed differently
+        let mut cw2 = Crossword::default();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 0, y: 2 }, Direction::Right)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "toy", Position { x: 2, y: 2 }, Direction::Down)).unwrap();
+        cw2.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: -3, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw1.shared_placements(&cw2), 3);
+        assert_eq!(cw2.shared_placements(&cw1), 3);
+        assert_eq!(cw1.similarity(&cw2), 0.75);
+        assert_eq!(cw2.similarity(&cw1), 0.75);
+
+        // the same words, but no placements are shared
+        let transposed = cw1.transpose();
+        assert_eq!(cw1.shared_placements(&transposed), 0);
+        assert_eq!(transposed.similarity(&cw1), 0.0);
+
+        // a part of the crossword
+        let mut part = cw1.clone();
+        part.retain(|w| w.value == "cat" || w.value == "toy");
+        assert_eq!(cw1.similarity(&part), 0.5);
+        assert_eq!(part.similarity(&cw1), 0.5);
+        assert!(cw1.contains_crossword(&part));
+
+        assert_eq!(Crossword::<u8, &str>::default().similarity(&Crossword::default()), 1.0);
+        assert_eq!(cw1.similarity(&Crossword::default()), 0.0);
+    }
+
+    #[test]
+    fn test_crossword_left_and_up() {
+        //  ---------
+        // |h e l l o|   olleh is placed to the left from (4, 0)
+        // |    o    |   lacol is placed upwards from (2, 4)
+        // |    c    |
+        // |    a    |
+        // |    l    |
+        //  ---------
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("olleh", Position { x: 4, y: 0 }, Direction::Left)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("lacol", Position { x: 2, y: 4 }, Direction::Up)).unwrap();
+
+        let mut forward = Crossword::default();
+        forward.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        forward.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        assert_eq!(cw.find_word(&"olleh").unwrap().position, Position { x: 4, y: 0 });
+        assert_eq!(cw.get_size(), (5, 5));
+        assert_eq!(cw.generate_char_table_opt(), forward.generate_char_table_opt());
+        assert_eq!(cw.cells().collect::<Vec<_>>(), forward.cells().collect::<Vec<_>>());
+        assert_eq!(cw.get_intersections().len(), 1);
+        assert_eq!(cw.get(Position { x: 2, y: 0 }), Some(&b'l'));
+        assert_eq!(cw.validate(), Ok(()));
+
+        // a word written over the same cells in the opposite direction is still a conflict
+        assert!(cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).is_err());
+
+        let transposed = cw.transpose();
+        assert_eq!(transposed.find_word(&"olleh").unwrap().direction, Direction::Up);
+        assert_eq!(transposed.find_word(&"lacol").unwrap().direction, Direction::Left);
+        assert_eq!(transposed.generate_char_table_opt(), forward.transpose().generate_char_table_opt());
+        assert_eq!(transposed.transpose(), cw);
+
+        // the words keep their directions, so they are still read the same way
+        let mirrored = cw.mirror_horizontal().unwrap();
+        assert_eq!(mirrored, cw);
+        let mirrored = cw.mirror_vertical().unwrap();
+        assert_eq!(mirrored.find_word(&"olleh").unwrap().position, Position { x: 4, y: 4 });
+        assert_eq!(mirrored.row(4), "hello".bytes().map(Some).collect::<Vec<_>>());
+        assert_eq!(mirrored.column(2), "local".bytes().map(Some).collect::<Vec<_>>());
+        assert_eq!(mirrored.mirror_vertical().unwrap(), cw);
+        assert_eq!(cw.rotate_180().unwrap().rotate_180().unwrap(), cw);
+    }
+
+    #[test]
+    fn test_crossword_allowed_directions() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let new_word = Word::new("lid", None);
+
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&new_word), vec![
+            PlacedWord::new(new_word.value, Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new(new_word.value, Position { x: 3, y: 0 }, Direction::Down),
+            ].into_iter().collect());
+
+        cw.word_compatibility_settings.allowed_directions = Direction::iter().collect();
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&new_word), vec![
+            PlacedWord::new(new_word.value, Position { x: 2, y: 0 }, Direction::Down),
+            PlacedWord::new(new_word.value, Position { x: 3, y: 0 }, Direction::Down),
+            PlacedWord::new(new_word.value, Position { x: 2, y: 0 }, Direction::Up),
+            PlacedWord::new(new_word.value, Position { x: 3, y: 0 }, Direction::Up),
+            ].into_iter().collect());
+
+        cw.word_compatibility_settings.allowed_directions = [Direction::Up].into_iter().collect();
+        assert!(cw.calculate_possible_ways_to_add_word(&new_word).iter().all(|w| w.direction == Direction::Up));
+        assert!(cw.calculate_possible_ways_to_add_word(&Word::new("lid", Some(Direction::Down))).is_empty());
+
+        // a word can be added to an empty crossword only in an allowed direction
+        let mut empty = Crossword::<u8, &str>::new(WordCompatibilitySettings { allowed_directions: [Direction::Left].into_iter().collect(), ..Default::default() });
+        assert_eq!(empty.calculate_possible_ways_to_add_word(&new_word), vec![PlacedWord::new(new_word.value, Position { x: 0, y: 0 }, Direction::Left)].into_iter().collect());
+        empty.word_compatibility_settings.allowed_directions.clear();
+        assert!(empty.calculate_possible_ways_to_add_word(&new_word).is_empty());
+
+        // settings serialized before the allowed directions existed get the default ones
+        let mut value = serde_json::to_value(WordCompatibilitySettings::<u8>::default()).unwrap();
+        value.as_object_mut().unwrap().remove("allowed_directions");
+        let settings: WordCompatibilitySettings<u8> = serde_json::from_value(value).unwrap();
+        assert_eq!(settings.allowed_directions, [Direction::Right, Direction::Down].into_iter().collect());
+    }
+
+    #[test]
+    fn test_crossword_grid_template() {
+        let template = GridTemplate::from_strings(&[".....",
+                                                    ".#.#.",
+                                                    ".....",
+                                                    ".#.#.",
+                                                    "....."]).unwrap();
+        let mut cw = Crossword::<u8, &str>::with_template(template.clone(), WordCompatibilitySettings::default());
+
+        let crane = Word::new("crane", None);
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&crane), [0, 2, 4].into_iter().flat_map(|i| [
+            PlacedWord::new("crane", Position { x: 0, y: i }, Direction::Right),
+            PlacedWord::new("crane", Position { x: i, y: 0 }, Direction::Down),
+            ]).collect());
+        assert!(cw.calculate_possible_ways_to_add_word(&Word::new("cranes", None)).is_empty());
+        // the generator gets the placements of an empty template in the same order
+        assert_eq!(cw.possible_placements(&crane), cw.calculate_possible_ways_to_add_word(&crane).into_iter().collect::<Vec<_>>());
+
+        //  ---------
+        // |c r a n e|
+        // |e # d # l|
+        // |d h o t i|
+        // |a # r # t|
+        // |r i n s e|
+        //  ---------
+        cw.add_word(PlacedWord::new("crane", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw.calculate_possible_ways_to_add_word(&Word::new("cedar", None)), BTreeSet::from([PlacedWord::new("cedar", Position { x: 0, y: 0 }, Direction::Down)]));
+        cw.add_word(PlacedWord::new("cedar", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::new("adorn", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::new("elite", Position { x: 4, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::new("dhoti", Position { x: 0, y: 2 }, Direction::Right)).unwrap();
+
+        // the words are not shifted, even when the top rows are empty
+        let mut part = Crossword::<u8, &str>::with_template(template.clone(), WordCompatibilitySettings::default());
+        part.add_word(PlacedWord::new("dhoti", Position { x: 0, y: 2 }, Direction::Right)).unwrap();
+        assert_eq!(part.normalize(), (0, 0));
+        assert_eq!(part.find_word(&"dhoti").unwrap().position, Position { x: 0, y: 2 });
+        assert_eq!(part.get_size(), (5, 5));
+
+        // the word leaves the template
+        let outside = PlacedWord::new("rinses", Position { x: 0, y: 4 }, Direction::Right);
+        assert_eq!(cw.add_word(outside.clone()), Err(CrosswordError::WordDoesNotFitTemplate(outside)));
+        // the word covers a blocked cell
+        let blocked = PlacedWord::new("ohs", Position { x: 1, y: 2 }, Direction::Down);
+        assert_eq!(cw.issue_when_adding_word(&blocked), Some(CrosswordError::WordDoesNotFitTemplate(blocked)));
+
+        // the merged words are checked against the template too
+        let merged = |value, dir| Crossword::from_placed_words([PlacedWord::new(value, Position { x: 0, y: 0 }, dir)], WordCompatibilitySettings::default()).unwrap();
+        assert!(cw.fits_at(&merged("rinse", Direction::Right), (0, 4)));
+        assert!(!cw.fits_at(&merged("rinses", Direction::Right), (0, 4)));
+        assert_eq!(cw.clone().merge(merged("rinses", Direction::Right), (0, 4)), Err(CrosswordError::WordDoesNotFitTemplate(PlacedWord::new("rinses", Position { x: 0, y: 4 }, Direction::Right))));
+        assert!(!cw.fits_at(&merged("hen", Direction::Down), (1, 2)));
+        assert_eq!(cw.clone().merge(merged("hen", Direction::Down), (1, 2)), Err(CrosswordError::WordDoesNotFitTemplate(PlacedWord::new("hen", Position { x: 1, y: 2 }, Direction::Down))));
+
+        cw.add_word(PlacedWord::new("rinse", Position { x: 0, y: 4 }, Direction::Right)).unwrap();
+        assert_eq!(cw.len(), 6);
+        assert_eq!(cw.validate(), Ok(()));
+        assert_eq!(cw.get_size(), (5, 5));
+        assert_eq!(cw.row(1), vec![Some(b'e'), None, Some(b'd'), None, Some(b'l')]);
+
+        let transposed = cw.transpose();
+        assert_eq!(transposed.grid_template, Some(template.clone()));
+        assert_eq!(transposed.find_word(&"crane").unwrap(), &PlacedWord::new("crane", Position { x: 0, y: 0 }, Direction::Down));
+        assert_eq!(transposed.validate(), Ok(()));
+
+        let mut moved = cw.clone();
+        moved.grid_template = Some(GridTemplate::from_strings(&[".....", ".....", "..#..", ".....", "....."]).unwrap());
+        assert_eq!(moved.validate().unwrap_err().len(), 2);
+
+        let json = serde_json::to_string(&cw).unwrap();
+        assert_eq!(serde_json::from_str::<Crossword<u8, &str>>(&json).unwrap(), cw);
+        assert!(!serde_json::to_string(&Crossword::<u8, &str>::default()).unwrap().contains("grid_template"));
+    }
+
+    #[test]
+    fn test_crossword_unfilled_slots() {
+        let template = GridTemplate::from_strings(&[".....",
+                                                    ".#.#.",
+                                                    ".....",
+                                                    ".#.#.",
+                                                    "....."]).unwrap();
+        let slots = template.slots();
+        let mut cw = Crossword::<u8, &str>::with_template(template, WordCompatibilitySettings::default());
+        assert_eq!(cw.unfilled_slots(), slots.iter().map(|slot| (slot.clone(), vec![None; 5])).collect::<Vec<_>>());
+
+        // a word that is shorter than the slot doesn't fill it
+        cw.add_word(PlacedWord::new("cran", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw.unfilled_slots().len(), 6);
+        assert!(cw.remove_word(&"cran"));
+
+        //  ---------
+        // |c r a n e|
+        // |e # . # .|
+        // |d . . . .|
+        // |a # . # .|
+        // |r . . . .|
+        //  ---------
+        cw.add_word(PlacedWord::new("crane", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::new("cedar", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+
+        let slot = |x, y, direction| Slot { position: Position { x, y }, direction, length: 5 };
+        assert_eq!(cw.unfilled_slots(), vec![
+            (slot(2, 0, Direction::Down), vec![Some(b'a'), None, None, None, None]),
+            (slot(4, 0, Direction::Down), vec![Some(b'e'), None, None, None, None]),
+            (slot(0, 2, Direction::Right), vec![Some(b'd'), None, None, None, None]),
+            (slot(0, 4, Direction::Right), vec![Some(b'r'), None, None, None, None]),
+        ]);
+
+        for (value, x, y, direction) in [("adorn", 2, 0, Direction::Down), ("elite", 4, 0, Direction::Down), ("dhoti", 0, 2, Direction::Right)]
+        {
+            cw.add_word(PlacedWord::new(value, Position { x, y }, direction)).unwrap();
+        }
+        // the characters of the crossing words are known
+        assert_eq!(cw.unfilled_slots(), vec![(slot(0, 4, Direction::Right), vec![Some(b'r'), None, Some(b'n'), None, Some(b'e')])]);
+
+        cw.add_word(PlacedWord::new("rinse", Position { x: 0, y: 4 }, Direction::Right)).unwrap();
+        assert!(cw.unfilled_slots().is_empty());
+        assert!(Crossword::<u8, &str>::default().unfilled_slots().is_empty());
+    }
+
+    #[test]
+    fn test_crossword_merge_overflow() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let mut other = Crossword::default();
+        other.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 0, y: 0 }, Direction::Down)).unwrap();
+
+        assert_eq!(cw.clone().merge(other.clone(), (2, i16::MAX - 3)), Err(CrosswordError::CoordinateOverflow));
+        assert!(!cw.fits_at(&other, (0, i16::MAX)));
+        assert!(cw.fits_at(&other, (2, i16::MAX - 10)));
+        assert!(cw.fits_at(&other, (2, 0)));
+
+        // the words near the end of the range are normalized
+        let mut far = Crossword::default();
+        far.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position { x: i16::MAX - 10, y: i16::MIN + 1 }, Direction::Right)).unwrap();
+        far.add_word_unnormalized(PlacedWord::<u8, &str>::new("local", Position { x: i16::MAX - 8, y: i16::MIN + 1 }, Direction::Down)).unwrap();
+        assert_eq!(far.normalize(), (10 - i16::MAX, i16::MAX));
+    }
+
+    #[test]
+    fn test_crossword_far_placements() {
+        let mut cw = Crossword::default();
+        cw.word_compatibility_settings.require_intersection = false;
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position { x: -30_000, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("world", Position { x: 30_000, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.get_size(), (60_001, 5));
+
+        let long = "a".repeat(39_999) + "b";
+        let mut cw = Crossword::default();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new(&long, Position { x: -20_000, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word_unnormalized(PlacedWord::<u8, &str>::new("cbc", Position { x: 19_999, y: -1 }, Direction::Down)).unwrap();
+        assert_eq!(cw.get_size(), (40_000, 3));
+        assert_eq!(cw.get_intersections().len(), 1);
+        assert!(cw.calculate_possible_ways_to_add_word(&Word::new("ab", None)).contains(&PlacedWord::new("ab", Position { x: 19_000, y: 0 }, Direction::Down)));
+    }
+
+    #[test]
+    fn test_crossword_error_display() {
+        let hello = PlacedWord::<u8, String>::new("hello".to_owned(), Position { x: 2, y: 0 }, Direction::Down);
+        assert_eq!(CrosswordError::WordAlreadyExists(hello.clone()).to_string(), "The word is already in the crossword. Word: hello @(2,0) Down");
+        assert_eq!(
+            CrosswordError::BatchWordError(1, Box::new(CrosswordError::WordDoesNotFitTemplate(hello))).to_string(), 
+            "Can't add the word with index 1 in the batch. Error: The word leaves the template of the crossword or covers a blocked cell. Word: hello @(2,0) Down"
+        );
+        assert_eq!(CrosswordError::<u8, String>::InvalidAdjacentSequence(b"lo".to_vec()).to_string(), "The word creates a sequence of characters with adjacent words, that is rejected by the validator. Sequence: lo");
+        assert_eq!(CrosswordError::<char, Vec<char>>::IsolatedCharacter(Position { x: -1, y: 3 }).to_string(), "The character is not part of any word. Position: (-1,3)");
+    }
+
+    #[test]
+    fn test_crossword_placement_constraint() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+
+        let word = Word::new("lot", None);
+        let all = cw.calculate_possible_ways_to_add_word(&word);
+        let ways = |constraint: PlacementConstraint| cw.calculate_possible_ways_to_add_word(&word.clone().with_placement_constraint(constraint));
+
+        assert_eq!(ways(PlacementConstraint::Through(Position { x: 4, y: 0 })), BTreeSet::from([("lot", Position { x: 4, y: -1 }, Direction::Down).into()]));
+        assert_eq!(all, BTreeSet::from([("lot", Position { x: 4, y: -1 }, Direction::Down).into(), ("lot", Position { x: 2, y: 4 }, Direction::Right).into()]));
+        assert_eq!(ways(PlacementConstraint::Through(Position { x: 3, y: 4 })), BTreeSet::from([("lot", Position { x: 2, y: 4 }, Direction::Right).into()]));
+        assert_eq!(ways(PlacementConstraint::Column(4)), BTreeSet::from([("lot", Position { x: 4, y: -1 }, Direction::Down).into()]));
+        assert_eq!(ways(PlacementConstraint::Row(4)), BTreeSet::from([("lot", Position { x: 2, y: 4 }, Direction::Right).into()]));
+        assert_eq!(ways(PlacementConstraint::ExactPosition(Position { x: 4, y: -1 })), BTreeSet::from([("lot", Position { x: 4, y: -1 }, Direction::Down).into()]));
+        assert!(ways(PlacementConstraint::ExactPosition(Position { x: 0, y: 0 })).is_empty());
+        assert!(ways(PlacementConstraint::Through(Position { x: 2, y: 2 })).is_empty());
+
+        // the constraint is relative to the top left corner of the words of a crossword that isn't normalized
+        let mut moved = Crossword::default();
+        moved.add_word_unnormalized(PlacedWord::<u8, &str>::new("hello", Position { x: 3, y: 5 }, Direction::Right)).unwrap();
+        moved.add_word_unnormalized(PlacedWord::<u8, &str>::new("local", Position { x: 5, y: 5 }, Direction::Down)).unwrap();
+        assert_eq!(moved.calculate_possible_ways_to_add_word(&word.clone().with_placement_constraint(PlacementConstraint::Column(4))), BTreeSet::from([("lot", Position { x: 7, y: 4 }, Direction::Down).into()]));
+        assert_eq!(moved.calculate_possible_ways_to_add_word(&word.clone().with_placement_constraint(PlacementConstraint::Row(4))), BTreeSet::from([("lot", Position { x: 5, y: 9 }, Direction::Right).into()]));
+        assert!(moved.calculate_possible_ways_to_add_word(&word.clone().with_placement_constraint(PlacementConstraint::Column(7))).is_empty());
+
+        // the word is placed on the constrained position of an empty crossword
+        let empty = Crossword::<u8, &str>::default();
+        assert_eq!(
+            empty.calculate_possible_ways_to_add_word(&word.clone().with_placement_constraint(PlacementConstraint::Row(2))), 
+            BTreeSet::from([("lot", Position { x: 0, y: 2 }, Direction::Right).into()])
+        );
+    }
+
+    #[test]
+    fn test_crossword_words_with_tag_constraints() {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new( "hello", Position { x: 0, y: 0 }, Direction::Right).with_meta(WordMetadata { category: Some("greeting".to_owned()), ..Default::default() })).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "local", Position { x: 2, y: 0 }, Direction::Down).with_meta(WordMetadata { tags: ["place".to_owned()].into(), ..Default::default() })).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new( "cat", Position { x: 2, y: 2 }, Direction::Right).with_meta(WordMetadata { category: Some("place".to_owned()), ..Default::default() })).unwrap();
+
+        let settings = |constraint| CrosswordSettings { constraints: vec![constraint] };
+
+        assert!(settings(CrosswordConstraint::MinWordsWithTag("place".to_owned(), 2)).check_recoverable_constraints(&cw));
+        assert!(!settings(CrosswordConstraint::MinWordsWithTag("place".to_owned(), 3)).check_recoverable_constraints(&cw));
+        assert!(settings(CrosswordConstraint::MinWordsWithTag("place".to_owned(), 3)).check_nonrecoverables_constraints(&cw));
+        assert!(!settings(CrosswordConstraint::MinWordsWithTag("animal".to_owned(), 1)).check_recoverable_constraints(&cw));
+
+        assert!(settings(CrosswordConstraint::MaxWordsWithTag("greeting".to_owned(), 1)).check_nonrecoverables_constraints(&cw));
+        assert!(!settings(CrosswordConstraint::MaxWordsWithTag("place".to_owned(), 1)).check_nonrecoverables_constraints(&cw));
     }
 
+    #[test]
+    fn test_crossword_rotational_symmetry() {
+        let settings = CrosswordSettings { constraints: vec![CrosswordConstraint::RotationalSymmetry] };
+        let mut cw = Crossword::default();
+        assert!(cw.is_rotationally_symmetric());
+
+        // a Z shape
+        cw.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("tie", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        assert!(!settings.check_recoverable_constraints(&cw));
+        assert!(settings.check_nonrecoverables_constraints(&cw));
+        cw.add_word(PlacedWord::<u8, &str>::new("eel", Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        assert_eq!(cw.occupancy(), vec![vec![true, true, true, false, false], vec![false, false, true, false, false], vec![false, false, true, true, true]]);
+        assert!(settings.check_recoverable_constraints(&cw));
 
+        // the symmetry doesn't depend on the position of the words
+        let mut moved = Crossword::default();
+        moved.add_words(cw.iter().map(|w| w.shifted(-7, 3).unwrap())).unwrap();
+        assert!(moved.is_rotationally_symmetric());
 
+        // in a template the center is the center of the template, and a filled cell can't be rotated to a blocked one
+        let template = GridTemplate::from_strings(&[".....", 
+                                                    ".....", 
+                                                    "....#"]).unwrap();
+        let mut cw = Crossword::with_template(template.clone(), WordCompatibilitySettings::default());
+        cw.add_word(PlacedWord::<u8, &str>::new("cat", Position { x: 1, y: 0 }, Direction::Right)).unwrap();
+        assert!(!cw.is_rotationally_symmetric());
+        assert!(settings.check_nonrecoverables_constraints(&cw));
+
+        let mut cw = Crossword::with_template(template, WordCompatibilitySettings::default());
+        cw.add_word(PlacedWord::<u8, &str>::new("acts", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert!(!settings.check_nonrecoverables_constraints(&cw));
+    }
 }
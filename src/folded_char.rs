@@ -0,0 +1,216 @@
+use std::{cmp::Ordering, fmt::Debug, hash::{Hash, Hasher}, marker::PhantomData};
+
+use crate::traits::RenderChar;
+
+/// Trait for the folding of [FoldedChar], characters with the same folded value are considered equal.
+pub trait CharFolder<C>
+{
+    type Folded: Ord + Hash;
+
+    /// Returns the folded value of the character.
+    fn fold(c: &C) -> Self::Folded;
+}
+
+/// Folder that ignores the case of ascii characters (the other characters are compared exactly).
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Hash)]
+pub struct AsciiCaseInsensitive;
+
+impl CharFolder<u8> for AsciiCaseInsensitive
+{
+    type Folded = u8;
+
+    fn fold(c: &u8) -> u8
+    {
+        c.to_ascii_lowercase()
+    }
+}
+
+impl CharFolder<char> for AsciiCaseInsensitive
+{
+    type Folded = char;
+
+    fn fold(c: &char) -> char
+    {
+        c.to_ascii_lowercase()
+    }
+}
+
+/// Represents a character that is compared by its folded value (check [CharFolder]), so words with equivalent characters can intersect.
+///
+/// The comparisons of the words and [crosswords](crate::crossword::Crossword) go through the folding too, for example with [AsciiCaseInsensitive] "Hello" and "hELLo" at the same position are the same word.
+/// Rendering uses the original characters, a cell shared by two words shows the character of one of them.
+///
+/// # Example
+///
+/// ```
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::Crossword;
+/// # use crossword_generator::folded_char::{AsciiCaseInsensitive, FoldedChar};
+/// let folded = |s: &str| s.chars().map(FoldedChar::<char, AsciiCaseInsensitive>::from).collect::<Vec<_>>();
+///
+/// let mut cw = Crossword::default();
+/// cw.add_word(PlacedWord::new(folded("Hello"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+/// cw.add_word(PlacedWord::new(folded("LOCAL"), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+///
+/// assert_eq!(cw.to_string(),
+/// " --------- 
+/// |H e L l o|
+/// |    O    |
+/// |    C    |
+/// |    A    |
+/// |    L    |
+///  --------- ");
+/// ```
+pub struct FoldedChar<C, F: CharFolder<C>>
+{
+    pub value: C,
+    folder: PhantomData<fn() -> F>,
+}
+
+impl<C, F: CharFolder<C>> FoldedChar<C, F>
+{
+    /// Creates the character from the original character.
+    pub fn new(value: C) -> FoldedChar<C, F>
+    {
+        FoldedChar { value, folder: PhantomData }
+    }
+
+    /// Returns the folded value of the character.
+    pub fn folded(&self) -> F::Folded
+    {
+        F::fold(&self.value)
+    }
+}
+
+impl<C, F: CharFolder<C>> From<C> for FoldedChar<C, F>
+{
+    fn from(value: C) -> Self
+    {
+        FoldedChar::new(value)
+    }
+}
+
+impl<C: Clone, F: CharFolder<C>> Clone for FoldedChar<C, F>
+{
+    fn clone(&self) -> Self
+    {
+        FoldedChar::new(self.value.clone())
+    }
+}
+
+impl<C: Default, F: CharFolder<C>> Default for FoldedChar<C, F>
+{
+    fn default() -> Self
+    {
+        FoldedChar::new(C::default())
+    }
+}
+
+impl<C: Debug, F: CharFolder<C>> Debug for FoldedChar<C, F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.value.fmt(f)
+    }
+}
+
+impl<C, F: CharFolder<C>> PartialEq for FoldedChar<C, F>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.folded() == other.folded()
+    }
+}
+
+impl<C, F: CharFolder<C>> Eq for FoldedChar<C, F> {}
+
+impl<C, F: CharFolder<C>> PartialOrd for FoldedChar<C, F>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C, F: CharFolder<C>> Ord for FoldedChar<C, F>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.folded().cmp(&other.folded())
+    }
+}
+
+impl<C, F: CharFolder<C>> Hash for FoldedChar<C, F>
+{
+    fn hash<H: Hasher>(&self, state: &mut H)
+    {
+        self.folded().hash(state);
+    }
+}
+
+impl<C: RenderChar, F: CharFolder<C>> RenderChar for FoldedChar<C, F>
+{
+    fn render(&self) -> String
+    {
+        self.value.render()
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{crossword::{Crossword, CrosswordError}, placed_word::PlacedWord, word::{Direction, Position}};
+
+    use super::*;
+
+    /// Folds 'ß' to 's', so it can cross with an 's'.
+    struct SharpS;
+
+    impl CharFolder<char> for SharpS
+    {
+        type Folded = char;
+
+        fn fold(c: &char) -> char
+        {
+            if *c == 'ß' { 's' } else { *c }
+        }
+    }
+
+    fn folded<F: CharFolder<u8>>(s: &str) -> Vec<FoldedChar<u8, F>>
+    {
+        s.bytes().map(FoldedChar::new).collect()
+    }
+
+    #[test]
+    fn test_folded_char_case_insensitive()
+    {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::new(folded::<AsciiCaseInsensitive>("Hello"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::new(folded::<AsciiCaseInsensitive>("LOCAL"), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.len(), 2);
+
+        // the same word with another case is a duplicate
+        assert!(matches!(cw.add_word(PlacedWord::new(folded::<AsciiCaseInsensitive>("hELLO"), Position { x: 0, y: 0 }, Direction::Right)), Err(CrosswordError::WordAlreadyExists(_))));
+
+        let mut cw = Crossword::<u8, &str>::default();
+        cw.add_word(PlacedWord::new("Hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert!(matches!(cw.add_word(PlacedWord::new("LOCAL", Position { x: 2, y: 0 }, Direction::Down)), Err(CrosswordError::WordCompatibilityError(_, _))));
+    }
+
+    #[test]
+    fn test_folded_char_custom_folder()
+    {
+        let folded = |s: &str| s.chars().map(FoldedChar::<char, SharpS>::new).collect::<Vec<_>>();
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::new(folded("straße"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::new(folded("see"), Position { x: 4, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.iter().map(|w| w.value.iter().map(|c| c.value).collect::<String>()).collect::<Vec<_>>(), vec!["straße", "see"]);
+
+        assert_eq!(FoldedChar::<char, SharpS>::new('ß'), FoldedChar::new('s'));
+        assert_ne!(FoldedChar::<char, AsciiCaseInsensitive>::new('ß'), FoldedChar::new('s'));
+        assert!(FoldedChar::<u8, AsciiCaseInsensitive>::new(b'B') > FoldedChar::new(b'a'));
+    }
+}
@@ -0,0 +1,608 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::{crossword::{Crossword, CrosswordError, NumberedEntry, WordCompatibilitySettings}, traits::{CrosswordChar, CrosswordString, RenderChar}, word::{Direction, Position}};
+
+/// Represents the metadata of an ipuz puzzle (check [Crossword::to_ipuz]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+pub struct IpuzMetadata<StrT: Ord>
+{
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub copyright: Option<String>,
+    /// The clues of the words by the values of the words, the clue of the word itself (check [WordMetadata](crate::word::WordMetadata)) or its value is used for missing clues.
+    pub clues: BTreeMap<StrT, String>,
+    /// Write the empty cells as omitted cells (null) instead of blocks ("#"), for crosswords that should not be drawn as rectangles.
+    pub omit_empty_cells: bool,
+}
+
+impl<StrT: Ord> Default for IpuzMetadata<StrT>
+{
+    fn default() -> Self 
+    {
+        IpuzMetadata { title: None, author: None, copyright: None, clues: BTreeMap::new(), omit_empty_cells: false }
+    }
+}
+
+/// Error type for possible errors when reading a [crossword](Crossword) from a puzzle format.
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+pub enum ImportError
+{
+    #[error("The puzzle is not in a valid format. {0}")]
+    InvalidFormat(String),
+    #[error("The puzzle is not a valid crossword. Error: {0:?}")]
+    InvalidCrossword(CrosswordError<char, Vec<char>>),
+}
+
+/// Represents the metadata of a puzzle (check [Crossword::to_acrosslite_text]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct PuzzleMetadata
+{
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub copyright: Option<String>,
+}
+
+/// Error type for possible errors when writing a [crossword](Crossword) in a puzzle format.
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum ExportError
+{
+    #[error("The format supports only ASCII characters. Text: {0}")]
+    NonAsciiCharacter(String),
+    #[error("The character can't be in a cell of the grid. Character: {0}")]
+    UnsupportedCharacter(String),
+    #[error("The format supports only across and down words. Direction: {0}")]
+    UnsupportedDirection(Direction),
+}
+
+/// Renders the characters of a word as a string.
+fn render_word<CharT: CrosswordChar + RenderChar>(word: &[CharT]) -> String
+{
+    word.iter().map(|c| c.render()).collect()
+}
+
+/// The grid of a [crossword](Crossword) with the clue numbers, that is written by the exporters.
+struct GridModel<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    width: u16,
+    height: u16,
+    /// Rows of the cells, [None] for the empty cells.
+    cells: Vec<Vec<Option<CharT>>>,
+    /// The clue numbers of the cells by their coordinates in the grid.
+    numbers: BTreeMap<(usize, usize), u32>,
+    /// The numbered entries, with positions relative to the grid.
+    entries: Vec<NumberedEntry<CharT, StrT>>,
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> GridModel<CharT, StrT>
+{
+    /// Fails with [ExportError::UnsupportedDirection] for the left and up words, the formats have no clue lists for reversed entries.
+    fn new(crossword: &Crossword<CharT, StrT>) -> Result<GridModel<CharT, StrT>, ExportError>
+    {
+        if let Some(word) = crossword.iter().find(|w| !matches!(w.direction, Direction::Right | Direction::Down))
+        {
+            return Err(ExportError::UnsupportedDirection(word.direction.clone()));
+        }
+
+        let (width, height) = crossword.get_size();
+        let min_corner = crossword.min_corner();
+        
+        let entries = crossword.numbered_entries().into_iter()
+            .map(|mut e| { e.position = Position { x: e.position.x - min_corner.0, y: e.position.y - min_corner.1 }; e })
+            .collect::<Vec<_>>();
+        let numbers = entries.iter().map(|e| ((e.position.x as usize, e.position.y as usize), e.number)).collect();
+
+        Ok(GridModel { width, height, cells: crossword.generate_char_table_opt(), numbers, entries })
+    }
+
+    /// Returns the entries in the direction.
+    fn entries(&self, dir: Direction) -> impl Iterator<Item = &NumberedEntry<CharT, StrT>>
+    {
+        self.entries.iter().filter(move |e| e.direction == dir)
+    }
+
+    /// Returns the clue of the entry from the map, or the clue of the word, or the value of the word for missing clues.
+    fn clue(entry: &NumberedEntry<CharT, StrT>, clues: &BTreeMap<StrT, String>) -> String
+    {
+        clues.get(&entry.word.value).or(entry.word.meta.clue.as_ref()).cloned().unwrap_or_else(|| render_word(entry.word.value.as_ref()))
+    }
+
+    /// Returns the rows of the grid as strings, with uppercase characters and the block for the empty cells.
+    fn rows(&self, block: char) -> Vec<String>
+    {
+        self.cells.iter()
+            .map(|row| row.iter().map(|c| c.as_ref().map_or(block.to_string(), |c| c.render().to_uppercase())).collect())
+            .collect()
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Exports the crossword in the [ipuz](http://ipuz.org) format, with the "puzzle", "solution" and "clues" sections.
+    /// 
+    /// Clue numbers come from [Crossword::numbered_entries].
+    /// 
+    /// # Errors
+    /// 
+    /// [ExportError::UnsupportedDirection] - A word goes left or up.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::formats::IpuzMetadata;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let ipuz = cw.to_ipuz(IpuzMetadata { clues: [("cat", "Feline".to_owned())].into(), ..Default::default() }).unwrap();
+    /// 
+    /// assert_eq!(ipuz["puzzle"], serde_json::json!([[1, 0, 2], ["#", "#", 0], ["#", "#", 0]]));
+    /// assert_eq!(ipuz["solution"][0], serde_json::json!(["c", "a", "t"]));
+    /// assert_eq!(ipuz["clues"]["Across"], serde_json::json!([[1, "Feline"]]));
+    /// assert_eq!(ipuz["clues"]["Down"], serde_json::json!([[2, "toy"]]));
+    /// ```
+    pub fn to_ipuz(&self, metadata: IpuzMetadata<StrT>) -> Result<Value, ExportError>
+    {
+        let grid = GridModel::new(self)?;
+        let empty = if metadata.omit_empty_cells { Value::Null } else { json!("#") };
+
+        let puzzle = grid.cells.iter().enumerate()
+            .map(|(y, row)| row.iter().enumerate().map(|(x, c)| match c
+            {
+                Some(_) => json!(grid.numbers.get(&(x, y)).copied().unwrap_or(0)),
+                None => empty.clone(),
+            }).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let solution = grid.cells.iter()
+            .map(|row| row.iter().map(|c| match c
+            {
+                Some(c) => json!(c.render()),
+                None => empty.clone(),
+            }).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let clues = |dir: Direction| grid.entries(dir)
+            .map(|e| json!([e.number, GridModel::clue(e, &metadata.clues)]))
+            .collect::<Vec<_>>();
+
+        let mut ipuz = json!({
+            "version": "http://ipuz.org/v2",
+            "kind": ["http://ipuz.org/crossword#1"],
+            "dimensions": { "width": grid.width, "height": grid.height },
+            "block": "#",
+            "empty": 0,
+            "puzzle": puzzle,
+            "solution": solution,
+            "clues": { "Across": clues(Direction::Right), "Down": clues(Direction::Down) },
+        });
+
+        for (key, value) in [("title", metadata.title), ("author", metadata.author), ("copyright", metadata.copyright)]
+        {
+            if let Some(value) = value { ipuz[key] = json!(value); }
+        }
+
+        Ok(ipuz)
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Exports the crossword in the text variant of the AcrossLite format (the one starting with "\<ACROSS PUZZLE\>").
+    /// 
+    /// Empty cells are written as blocks ("."), and the characters are converted to uppercase. 
+    /// Clues come from the map by the values of the words, the clue of the word itself or its value is used for missing clues.
+    /// 
+    /// # Errors
+    /// 
+    /// [ExportError::NonAsciiCharacter] - A character, a clue or the metadata is not ASCII.
+    /// 
+    /// [ExportError::UnsupportedCharacter] - A character is not a single letter or digit.
+    /// 
+    /// [ExportError::UnsupportedDirection] - A word goes left or up.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::formats::PuzzleMetadata;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let text = cw.to_acrosslite_text(PuzzleMetadata::default(), &[("cat", "Feline".to_owned())].into()).unwrap();
+    /// 
+    /// assert!(text.starts_with("<ACROSS PUZZLE>\n"));
+    /// assert!(text.contains("<SIZE>\n\t3x3\n<GRID>\n\tCAT\n\t..O\n\t..Y\n"));
+    /// assert!(text.ends_with("<ACROSS>\n\tFeline\n<DOWN>\n\ttoy\n"));
+    /// ```
+    pub fn to_acrosslite_text(&self, meta: PuzzleMetadata, clues: &BTreeMap<StrT, String>) -> Result<String, ExportError>
+    {
+        let check_ascii = |text: String| if text.is_ascii() { Ok(text) } else { Err(ExportError::NonAsciiCharacter(text)) };
+
+        let grid = GridModel::new(self)?;
+        for c in grid.cells.iter().flatten().flatten()
+        {
+            let c = check_ascii(c.render())?;
+            if !c.chars().exactly_one().is_ok_and(|c| c.is_ascii_alphanumeric()) { return Err(ExportError::UnsupportedCharacter(c)); }
+        }
+
+        let clues = |dir: Direction| grid.entries(dir)
+            .map(|e| check_ascii(GridModel::clue(e, clues).replace('\n', " ")))
+            .collect::<Result<Vec<_>, _>>();
+
+        let mut sections = vec![("TITLE", vec![meta.title.unwrap_or_default()]), ("AUTHOR", vec![meta.author.unwrap_or_default()])];
+        if let Some(copyright) = meta.copyright { sections.push(("COPYRIGHT", vec![copyright])); }
+        sections.push(("SIZE", vec![format!("{}x{}", grid.width, grid.height)]));
+        sections.push(("GRID", grid.rows('.')));
+        sections.push(("ACROSS", clues(Direction::Right)?));
+        sections.push(("DOWN", clues(Direction::Down)?));
+
+        let mut text = "<ACROSS PUZZLE>\n".to_owned();
+        for (name, lines) in sections
+        {
+            text += &format!("<{name}>\n");
+            for line in lines { text += &format!("\t{}\n", check_ascii(line)?); }
+        }
+
+        Ok(text)
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Exports the crossword in the [XD](https://github.com/century-arcade/xd) format.
+    /// 
+    /// Empty cells are written as blocks ("#"), and the characters are converted to uppercase. 
+    /// Clues come from the map by the values of the words, the clue of the word itself or its value is used for missing clues.
+    /// 
+    /// # Errors
+    /// 
+    /// [ExportError::UnsupportedDirection] - A word goes left or up.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::formats::PuzzleMetadata;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.to_xd(PuzzleMetadata::default(), &[("cat", "Feline".to_owned())].into()).unwrap(), 
+    ///            "\n\nCAT\n##O\n##Y\n\n\nA1. Feline ~ CAT\n\nD2. toy ~ TOY\n");
+    /// ```
+    pub fn to_xd(&self, meta: PuzzleMetadata, clues: &BTreeMap<StrT, String>) -> Result<String, ExportError>
+    {
+        let grid = GridModel::new(self)?;
+
+        let mut text = String::new();
+        for (key, value) in [("Title", meta.title), ("Author", meta.author), ("Copyright", meta.copyright)]
+        {
+            if let Some(value) = value { text += &format!("{key}: {value}\n"); }
+        }
+        
+        text += "\n\n";
+        grid.rows('#').into_iter().for_each(|row| text += &format!("{row}\n"));
+        
+        for (prefix, dir) in [("A", Direction::Right), ("D", Direction::Down)]
+        {
+            text += "\n";
+            if prefix == "A" { text += "\n"; }
+            for e in grid.entries(dir)
+            {
+                text += &format!("{prefix}{}. {} ~ {}\n", e.number, GridModel::clue(e, clues).replace('\n', " "), render_word(e.word.value.as_ref()).to_uppercase());
+            }
+        }
+
+        Ok(text)
+    }
+
+    /// Exports the crossword as an [Exolve](https://github.com/viresh-ratnakar/exolve) puzzle specification (from "exolve-begin" to "exolve-end").
+    /// 
+    /// Empty cells are written as blocks ("."), and the characters are converted to uppercase. 
+    /// Clues come from the map by the values of the words, the clue of the word itself or its value is used for missing clues.
+    /// 
+    /// # Errors
+    /// 
+    /// [ExportError::UnsupportedDirection] - A word goes left or up.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::formats::PuzzleMetadata;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let exolve = cw.to_exolve(PuzzleMetadata::default(), &[("cat", "Feline".to_owned())].into()).unwrap();
+    /// 
+    /// assert!(exolve.starts_with("exolve-begin\n  exolve-width: 3\n  exolve-height: 3\n  exolve-grid:\n    CAT\n    ..O\n    ..Y\n"));
+    /// assert!(exolve.ends_with("  exolve-across:\n    1 Feline (3)\n  exolve-down:\n    2 toy (3)\nexolve-end\n"));
+    /// ```
+    pub fn to_exolve(&self, meta: PuzzleMetadata, clues: &BTreeMap<StrT, String>) -> Result<String, ExportError>
+    {
+        let grid = GridModel::new(self)?;
+
+        let mut text = "exolve-begin\n".to_owned();
+        for (key, value) in [("title", meta.title), ("setter", meta.author), ("copyright", meta.copyright)]
+        {
+            if let Some(value) = value { text += &format!("  exolve-{key}: {value}\n"); }
+        }
+
+        text += &format!("  exolve-width: {}\n  exolve-height: {}\n  exolve-grid:\n", grid.width, grid.height);
+        grid.rows('.').into_iter().for_each(|row| text += &format!("    {row}\n"));
+
+        for (section, dir) in [("across", Direction::Right), ("down", Direction::Down)]
+        {
+            text += &format!("  exolve-{section}:\n");
+            for e in grid.entries(dir)
+            {
+                text += &format!("    {} {} ({})\n", e.number, GridModel::clue(e, clues).replace('\n', " "), e.word.value.as_ref().len());
+            }
+        }
+
+        text += "exolve-end\n";
+        Ok(text)
+    }
+}
+
+impl Crossword<char, Vec<char>>
+{
+    /// Imports a crossword from the [ipuz](http://ipuz.org) format, the placements of words are reconstructed from the "solution" section (check [Crossword::from_char_table]).
+    /// 
+    /// Blocks and omitted cells (null) are empty cells. Cells with more than one character (rebus) are not supported.
+    /// 
+    /// The words are validated with settings that allow any relative positioning of words (words of a finished grid are often side by side), 
+    /// use [Crossword::from_placed_words] to validate them with other settings.
+    /// 
+    /// # Errors
+    /// 
+    /// [ImportError::InvalidFormat] - The solution is missing or has invalid cells.
+    /// 
+    /// [ImportError::InvalidCrossword] - The words of the solution are not a valid crossword.
+    pub fn from_ipuz(ipuz: &Value) -> Result<Crossword<char, Vec<char>>, ImportError>
+    {
+        let block = ipuz.get("block").and_then(|b| b.as_str()).unwrap_or("#");
+
+        let rows = ipuz.get("solution").and_then(|s| s.as_array()).ok_or(ImportError::InvalidFormat("The solution is missing.".to_owned()))?;
+        let table = rows.iter().enumerate()
+            .map(|(y, row)| row.as_array()
+                .ok_or(ImportError::InvalidFormat(format!("The row {y} of the solution is not an array.")))?
+                .iter().enumerate()
+                .map(|(x, cell)| 
+                {
+                    let cell = match cell
+                    {
+                        Value::Object(obj) => obj.get("value").unwrap_or(&Value::Null),
+                        cell => cell,
+                    };
+
+                    match cell
+                    {
+                        Value::Null => Ok(None),
+                        Value::String(s) if s == block || s.is_empty() => Ok(None),
+                        Value::String(s) if s.chars().count() == 1 => Ok(s.chars().next()),
+                        cell => Err(ImportError::InvalidFormat(format!("The cell ({x}, {y}) of the solution is not supported: {cell}."))),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let settings = WordCompatibilitySettings
+        {
+            side_by_side: true.into(),
+            head_by_head: true.into(),
+            side_by_head: true,
+            corner_by_corner: true,
+            require_intersection: false,
+            ..Default::default()
+        };
+
+        Crossword::from_char_table(&table, settings).map_err(ImportError::InvalidCrossword)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{placed_word::PlacedWord, word::{Position, Word}};
+
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char>
+    {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_formats_ipuz_round_trip()
+    {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("hello"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("local"), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("cat"), Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("toy"), Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+
+        for omit_empty_cells in [false, true]
+        {
+            let ipuz = cw.to_ipuz(IpuzMetadata { title: Some("Test".to_owned()), omit_empty_cells, ..Default::default() }).unwrap();
+            assert_eq!(ipuz["title"], "Test");
+            assert_eq!(ipuz["dimensions"], json!({ "width": 5, "height": 5 }));
+            assert_eq!(ipuz["puzzle"][2], if omit_empty_cells { json!([null, null, 3, 0, 4]) } else { json!(["#", "#", 3, 0, 4]) });
+
+            let parsed = Crossword::from_ipuz(&serde_json::from_str(&ipuz.to_string()).unwrap()).unwrap();
+            assert!(parsed.same_layout(&cw));
+        }
+    }
+
+    #[test]
+    fn test_formats_ipuz_fixture()
+    {
+        // the fixture is written by hand after the crossword examples of the ipuz specification (a styled cell, a solution cell object and a clue object), 
+        // not exported by an ipuz producer, a file exported by one should replace it
+        let ipuz = serde_json::from_str(include_str!("../fixtures/mini.ipuz")).unwrap();
+        let cw = Crossword::from_ipuz(&ipuz).unwrap();
+
+        assert_eq!(cw.len(), 5);
+        assert_eq!(cw.find_word(&chars("ROYAL")).unwrap(), &PlacedWord::new(chars("ROYAL"), Position { x: 0, y: 2 }, Direction::Right));
+        assert_eq!(cw.find_word(&chars("LED")).unwrap(), &PlacedWord::new(chars("LED"), Position { x: 4, y: 2 }, Direction::Down));
+
+        let numbers = cw.numbered_entries().into_iter().map(|e| (e.number, e.direction, e.word.value.into_iter().collect::<String>())).collect::<Vec<_>>();
+        assert_eq!(numbers, vec![
+            (1, Direction::Right, "CAT".to_owned()), 
+            (1, Direction::Down, "CAR".to_owned()), 
+            (2, Direction::Down, "TOY".to_owned()), 
+            (3, Direction::Right, "ROYAL".to_owned()), 
+            (4, Direction::Down, "LED".to_owned()),
+        ]);
+
+        let exported = cw.to_ipuz(IpuzMetadata::default()).unwrap();
+        assert_eq!(exported["puzzle"][2], ipuz["puzzle"][2]);
+        assert_eq!(exported["solution"][0], ipuz["solution"][0]);
+        assert_eq!(exported["solution"][2], ipuz["solution"][2]);
+    }
+
+    #[test]
+    fn test_formats_ipuz_invalid()
+    {
+        assert!(matches!(Crossword::from_ipuz(&json!({})), Err(ImportError::InvalidFormat(_))));
+        assert!(matches!(Crossword::from_ipuz(&json!({ "solution": [["AB", "C"]] })), Err(ImportError::InvalidFormat(_))));
+        assert_eq!(Crossword::from_ipuz(&json!({ "solution": [["A", "#", "C"]] })), Err(ImportError::InvalidCrossword(CrosswordError::IsolatedCharacter(Position { x: 0, y: 0 }))));
+    }
+
+    #[test]
+    fn test_formats_acrosslite_text()
+    {
+        let table = ["CAT  ", "A O  ", "ROYAL", "    E", "    D"].map(|row| row.chars().map(|c| (c != ' ').then_some(c)).collect::<Vec<_>>());
+        let cw = Crossword::from_char_table(&table, WordCompatibilitySettings::default()).unwrap();
+
+        let clues = [("CAT", "Feline"), ("ROYAL", "Regal"), ("CAR", "Automobile"), ("TOY", "Plaything")]
+            .into_iter()
+            .map(|(w, c)| (chars(w), c.to_owned()))
+            .collect();
+        let meta = PuzzleMetadata { title: Some("Mini".to_owned()), author: Some("Hayk Khachatryan".to_owned()), copyright: Some("2024".to_owned()) };
+
+        assert_eq!(cw.to_acrosslite_text(meta.clone(), &clues).unwrap(), include_str!("../fixtures/mini_acrosslite.txt"));
+
+        let mut clues = clues;
+        clues.insert(chars("LED"), "Light source, \u{2018}briefly\u{2019}".to_owned());
+        assert!(matches!(cw.to_acrosslite_text(meta, &clues), Err(ExportError::NonAsciiCharacter(_))));
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("caf\u{e9}"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw.to_acrosslite_text(PuzzleMetadata::default(), &BTreeMap::new()), Err(ExportError::NonAsciiCharacter("\u{e9}".to_owned())));
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(chars("a.b"), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert_eq!(cw.to_acrosslite_text(PuzzleMetadata::default(), &BTreeMap::new()), Err(ExportError::UnsupportedCharacter(".".to_owned())));
+    }
+
+    fn hello_local() -> Crossword<u8, &'static str>
+    {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw
+    }
+
+    #[test]
+    fn test_formats_xd()
+    {
+        let meta = PuzzleMetadata { title: Some("Hello".to_owned()), author: Some("Hayk Khachatryan".to_owned()), ..Default::default() };
+        let clues = [("hello", "Greeting".to_owned())].into();
+
+        assert_eq!(hello_local().to_xd(meta, &clues).unwrap(), [
+            "Title: Hello",
+            "Author: Hayk Khachatryan",
+            "",
+            "",
+            "HELLO",
+            "##O##",
+            "##C##",
+            "##A##",
+            "##L##",
+            "",
+            "",
+            "A1. Greeting ~ HELLO",
+            "",
+            "D2. local ~ LOCAL",
+            "",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_formats_exolve()
+    {
+        let meta = PuzzleMetadata { title: Some("Hello".to_owned()), author: Some("Hayk Khachatryan".to_owned()), ..Default::default() };
+        let clues = [("hello", "Greeting".to_owned()), ("local", "Nearby".to_owned())].into();
+
+        assert_eq!(hello_local().to_exolve(meta, &clues).unwrap(), [
+            "exolve-begin",
+            "  exolve-title: Hello",
+            "  exolve-setter: Hayk Khachatryan",
+            "  exolve-width: 5",
+            "  exolve-height: 5",
+            "  exolve-grid:",
+            "    HELLO",
+            "    ..O..",
+            "    ..C..",
+            "    ..A..",
+            "    ..L..",
+            "  exolve-across:",
+            "    1 Greeting (5)",
+            "  exolve-down:",
+            "    2 Nearby (5)",
+            "exolve-end",
+            "",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_formats_reversed_words()
+    {
+        for dir in [Direction::Left, Direction::Up]
+        {
+            let mut cw = Crossword::default();
+            cw.add_word(PlacedWord::<u8, &str>::new("tac", Position { x: 2, y: 2 }, dir.clone())).unwrap();
+            let error = ExportError::UnsupportedDirection(dir);
+
+            assert_eq!(cw.to_ipuz(IpuzMetadata::default()).unwrap_err(), error);
+            assert_eq!(cw.to_acrosslite_text(PuzzleMetadata::default(), &BTreeMap::new()).unwrap_err(), error);
+            assert_eq!(cw.to_xd(PuzzleMetadata::default(), &BTreeMap::new()).unwrap_err(), error);
+            assert_eq!(cw.to_exolve(PuzzleMetadata::default(), &BTreeMap::new()).unwrap_err(), error);
+        }
+    }
+
+    #[test]
+    fn test_formats_word_clues()
+    {
+        let mut cw = Crossword::default();
+        cw.add_word(Word::<u8, &str>::from("hello").with_clue("Greeting").place(Position { x: 0, y: 0 }, Direction::Right).unwrap()).unwrap();
+        cw.add_word(Word::<u8, &str>::from("local").with_clue("Nearby").place(Position { x: 2, y: 0 }, Direction::Down).unwrap()).unwrap();
+
+        // the clues of the map come first
+        let clues = [("local", "Close".to_owned())].into();
+        let xd = cw.to_xd(PuzzleMetadata::default(), &clues).unwrap();
+        assert!(xd.contains("A1. Greeting ~ HELLO"));
+        assert!(xd.contains("D2. Close ~ LOCAL"));
+
+        let ipuz = cw.to_ipuz(IpuzMetadata::default()).unwrap();
+        assert_eq!(ipuz["clues"]["Across"], json!([[1, "Greeting"]]));
+        assert_eq!(ipuz["clues"]["Down"], json!([[2, "Nearby"]]));
+    }
+}
@@ -1,318 +1,5614 @@
-use std::{collections::BTreeSet, future::Future, pin::Pin, sync::Arc, task::{Context, Poll}};
+use std::{cmp::Reverse, collections::{BTreeMap, BTreeSet, HashMap, HashSet}, hash::{Hash, Hasher}, marker::PhantomData, sync::{atomic::{AtomicU64, AtomicUsize, Ordering}, Arc, OnceLock}, task::Poll, time::{Duration, Instant}};
+#[cfg(feature = "async")]
+use std::{collections::VecDeque, future::Future, pin::Pin, sync::atomic::{AtomicBool, AtomicU8}, task::{ready, Context}};
+#[cfg(feature = "tokio")]
+use std::{collections::hash_map::RandomState, hash::BuildHasher};
 
-use async_recursion::async_recursion;
-use futures::{stream::FuturesUnordered, StreamExt};
+#[cfg(feature = "async")]
+use futures::{channel::{mpsc::{self, Receiver, Sender}, oneshot}, future::{self, BoxFuture, Either}, lock::Mutex, stream::{FusedStream, FuturesUnordered}, SinkExt, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tokio::{sync::{mpsc::{self, Receiver, Sender}, Mutex}, task};
-use tokio_stream::Stream;
 use itertools::Itertools;
+use thiserror::Error;
 
-use crate::{crossword::{Crossword, CrosswordSettings, WordCompatibilitySettings}, traits::{CrosswordChar, CrosswordString}, word::Word};
+use crate::{crossword::{ConstraintView, Crossword, CrosswordConstraint, CrosswordSettings, WordCompatibilitySettings}, placed_word::PlacedWord, scoring::{BestCrosswords, CrosswordScorer}, traits::{CrosswordChar, CrosswordString}, word::{Direction, Word, WordError}};
+#[cfg(feature = "async")]
+use crate::word::Position;
 
-const MAX_CONCURRENT_TASK_COUNT: usize = 10;
+const DEFAULT_MAX_CONCURRENT_TASKS: usize = 10;
+const DEFAULT_BUFFER_SIZE: usize = 100;
 
-/// Represents all settings for a [generator](CrosswordGenerator).
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
-pub struct CrosswordGeneratorSettings
+// the streams let the other tasks run after searching for this long (the sorted one) or this many steps (the tasks of the randomized one), 
+// so a stop request is seen even on a single threaded executor
+#[cfg(feature = "async")]
+const YIELD_INTERVAL: Duration = Duration::from_millis(1);
+#[cfg(feature = "async")]
+const YIELD_STEPS: u32 = 64;
+
+/// The spawner of the streams without a custom spawner (check [CrosswordStream::new_with_spawner]).
+#[cfg(feature = "tokio")]
+fn tokio_spawner(future: BoxFuture<'static, ()>)
 {
-    pub crossword_settings: CrosswordSettings,
-    pub word_compatibility_settings: WordCompatibilitySettings
+    tokio::spawn(future);
 }
 
-/// Represents a crossword generator, runs in an async runtime.
-/// 
-/// # Example
-/// ```
-/// use crossword_generator::generator::{CrosswordGenerator, CrosswordGeneratorSettings, CrosswordGenerationRequest};
-/// use crossword_generator::crossword::Crossword;
-/// use crossword_generator::placed_word::PlacedWord;
-/// use crossword_generator::word::{Direction, Position, Word};
-/// 
-/// use tokio_stream::StreamExt;
-/// 
-/// #[tokio::main]
-/// async fn main() 
-/// {
-/// 
-///     let mut generator = CrosswordGenerator::<u8, String>::default();
-///     generator.settings = CrosswordGeneratorSettings::default();
-///     generator.words = vec!["Hello", "world"].into_iter().map(|s| Word::new(s.to_lowercase(), None)).collect();
-///      
-///     let str = generator.crossword_stream(|w| String::from_utf8(w.to_owned()).unwrap());
-///     str.request_crossword(CrosswordGenerationRequest::Count(2)).await;
-///     str.request_crossword(CrosswordGenerationRequest::Stop).await;
-///     let crosswords: Vec<Crossword<u8, String>> = str.collect().await;
-///     
-///     let mut cw1 = Crossword::default();
-///     let mut cw2 = Crossword::default();
-/// 
-///     cw1.add_words([PlacedWord::new("hello".to_owned(), Position{ x: 0, y: 3 }, Direction::Right),
-///                    PlacedWord::new("world".to_owned(), Position{ x: 2, y: 0 }, Direction::Down)].into_iter()).unwrap();
-///     
-///     cw2.add_words([PlacedWord::new("hello".to_owned(), Position{ x: 0, y: 3 }, Direction::Right),
-///                    PlacedWord::new("world".to_owned(), Position{ x: 3, y: 0 }, Direction::Down)].into_iter()).unwrap();
-/// 
-///     assert_eq!(crosswords, vec![cw1, cw2])
-/// }
-/// ```
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
-pub struct CrosswordGenerator<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+// returns Pending once, so the executor can run the other tasks (for example the one requesting a stop)
+#[cfg(feature = "async")]
+async fn yield_now()
 {
-    pub words: BTreeSet<Word<CharT, StrT>>,
-    pub settings: CrosswordGeneratorSettings,
+    let mut yielded = false;
+    future::poll_fn(|cx| 
+    {
+        if yielded { return Poll::Ready(()); }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }).await
 }
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordGenerator<CharT, StrT>
+#[cfg(feature = "async")]
+enum Yielder
 {
-    /// Takes a function to convert from &\[CharT\] to StrT, because the generator generates crosswords with words with type &\[CharT\] to prevent unnecessary copying
-    /// Slow, but crosswords are pretty much random.
-    /// If you need fast generation, check [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted).
+    // yields after every YIELD_INTERVAL of searching
+    Timed(Instant),
+    // yields after every YIELD_STEPS steps, so the interleaving of the tasks of the randomized stream doesn't depend on the timing, only on the seed
+    Counted(u32),
+}
 
-    pub fn crossword_stream_randomized<F>(&self, convert_f: F) -> CrosswordStream<CharT, StrT> where
-        F: Fn(&[CharT]) -> StrT,
-        F: Clone + Send + Sync + 'static
-    {  
+#[cfg(feature = "async")]
+impl Yielder
+{
+    fn timed() -> Yielder
+    {
+        Yielder::Timed(Instant::now())
+    }
 
-        let gen = self.clone();
-        
-        let gen_func = move |rr: Receiver<CrosswordGenerationRequest>, cs: Sender<Crossword<CharT, StrT>>| async move
-        {
-            // creating separate tasks for each word permutation
-            let rr = Arc::new(Mutex::new(rr));
-            let current_request = Arc::new(Mutex::new(CrosswordGenerationRequest::Count(0)));
-            let created_crosswords = Arc::<Mutex<BTreeSet<_>>>::new(Mutex::new(BTreeSet::new()));
+    fn counted() -> Yielder
+    {
+        Yielder::Counted(0)
+    }
 
-            let mut tasks = FuturesUnordered::new();
-            
-            for mut ws in gen.words.iter().enumerate().permutations(gen.words.len())
+    async fn maybe_yield(&mut self)
+    {
+        let should_yield = match self
+        {
+            Yielder::Timed(last_yield) => last_yield.elapsed() >= YIELD_INTERVAL,
+            Yielder::Counted(steps) => 
             {
-                //for some randomness
-                ws.rotate_right(2);
-
-                //maintaining the number of currently running tasks under MAX_CONCURRENT_TASK_COUNT
-                if tasks.len() >= MAX_CONCURRENT_TASK_COUNT
-                {
-                    tasks.next().await;
-                }
-                
-                let settings = gen.settings.clone();
-                let receiver = rr.clone(); 
-                let cs = cs.clone();
-                let cr = current_request.clone();
-                let ws = ws.into_iter().map(|(_, w)| w.clone()).collect::<Vec<_>>();
-                let ccs = created_crosswords.clone();
-                let cfr = convert_f.clone();
-
-                //creating and spawning the task
-                tasks.push(tokio::spawn(async move 
-                {
-                    let mut cc = Crossword::new(settings.word_compatibility_settings.clone());
-                    let ws = ws.iter().map(|w| Word::<CharT, Arc<[CharT]>>::new(w.value.as_ref().to_owned().into(), w.dir.clone())).collect::<Vec<_>>();
-                    CrosswordGenerator::<CharT, StrT>::randomized_generator_impl(&settings, receiver, &cs, cr, &mut cc, &ws, &mut 0, ccs, &cfr).await; 
-                }));
-
-                if let CrosswordGenerationRequest::Stop = *current_request.lock().await { break; }
-            };
-
-            while let Some(_) = tasks.next().await {}       
+                *steps += 1;
+                *steps >= YIELD_STEPS
+            }
         };
+        if should_yield
+        {
+            yield_now().await;
+            *self = match self { Yielder::Timed(_) => Yielder::timed(), Yielder::Counted(_) => Yielder::counted() };
+        }
+    }
+}
 
-        CrosswordStream::new(gen_func)
+// the state shared by a stream and its generation
+#[cfg(feature = "async")]
+pub(crate) struct StreamControl<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    // set when a stop is requested, so the generators can see it without waiting for the request (check StreamControl::is_stop_requested)
+    stop: AtomicBool,
+    // set when a pause is requested, and cleared when the generation is resumed (check CrosswordGenerationRequest::Pause)
+    paused: AtomicBool,
+    // the GeneratorState of the generation
+    state: AtomicU8,
+    // set by the generators of the crate at the start of the generation
+    tracker: OnceLock<Arc<GenerationTracker>>,
+    // set by the generators of the crate at the end of the generation
+    summary: OnceLock<GenerationSummary>,
+    // set by the generators of the crate at the end of a generation without crosswords, if the failure report is turned on
+    failure: OnceLock<GenerationFailure<CharT, StrT>>,
+    // set by the generators of the crate at the end of the generation
+    finish_reason: OnceLock<FinishReason>,
+    // the sum of the counts of the requests sent by the stream and not taken by the generators yet (usize::MAX after a request for all the crosswords)
+    queued_requested: AtomicUsize,
+    // the count of the crosswords left of the request that the generators handle (usize::MAX for all the crosswords)
+    current_requested: AtomicUsize,
+    // the sum of the counts of the requests for some count of crosswords that are sent before the stop and not taken by the generators yet
+    queued_counts: AtomicUsize,
+    // set by the sorted stream at its creation (the hash of the words and the settings of its checkpoints), only the streams with it can be checkpointed
+    checkpoint_base: OnceLock<(u64, CrosswordGeneratorSettings<CharT>)>,
+    // set by the stream together with the sender of the snapshot, when it takes a checkpoint
+    checkpoint_requested: AtomicBool,
+    checkpoint_sender: std::sync::Mutex<Option<oneshot::Sender<CheckpointSnapshot<CharT, StrT>>>>,
+    // set by the sorted stream at the end of the generation, for the checkpoints taken after it
+    final_checkpoint: OnceLock<CheckpointSnapshot<CharT, StrT>>,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Default for StreamControl<CharT, StrT>
+{
+    fn default() -> Self
+    {
+        StreamControl 
+        { 
+            stop: AtomicBool::new(false), 
+            paused: AtomicBool::new(false), 
+            state: AtomicU8::new(GeneratorState::Generating as u8), 
+            tracker: OnceLock::new(), 
+            summary: OnceLock::new(), 
+            failure: OnceLock::new(), 
+            finish_reason: OnceLock::new(), 
+            queued_requested: AtomicUsize::new(0), 
+            current_requested: AtomicUsize::new(0), 
+            queued_counts: AtomicUsize::new(0), 
+            checkpoint_base: OnceLock::new(), 
+            checkpoint_requested: AtomicBool::new(false), 
+            checkpoint_sender: std::sync::Mutex::new(None), 
+            final_checkpoint: OnceLock::new() 
+        }
     }
+}
 
-    #[async_recursion]
-    async fn randomized_generator_impl<F>(gen_settings: &CrosswordGeneratorSettings, rr: Arc<Mutex<Receiver<CrosswordGenerationRequest>>>, cs: &Sender<Crossword<CharT, StrT>>, current_request: Arc<Mutex<CrosswordGenerationRequest>>, current_crossword: &mut Crossword<CharT, Arc<[CharT]>>, words: &Vec<Word<CharT, Arc<[CharT]>>>, current_word_ind: &mut usize, created_crosswords: Arc<Mutex<BTreeSet<Crossword<CharT, Arc<[CharT]>>>>>, convert_f: &F) where  
-        F: Fn(&[CharT]) -> StrT,
-        F: Send + Sync + 'static
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> StreamControl<CharT, StrT>
+{
+    fn state(&self) -> GeneratorState
     {
-        if !gen_settings.crossword_settings.check_nonrecoverables_constraints(current_crossword) 
+        match self.state.load(Ordering::Relaxed)
         {
-            return; 
+            state if state == GeneratorState::Idle as u8 => GeneratorState::Idle,
+            state if state == GeneratorState::Paused as u8 => GeneratorState::Paused,
+            state if state == GeneratorState::Finished as u8 => GeneratorState::Finished,
+            _ => GeneratorState::Generating,
         }
-        
-        if *current_word_ind == words.len()
-        {
-            if gen_settings.crossword_settings.check_recoverable_constraints(current_crossword) 
-            {
-                if created_crosswords.lock().await.insert(current_crossword.clone())
-                {
-                    let mut current_request = current_request.lock().await;
-                    while let CrosswordGenerationRequest::Count(0) = *current_request
-                    {
-                        match rr.lock().await.recv().await
-                        {
-                            None => { *current_request = CrosswordGenerationRequest::Stop; },
-                            Some(req) => *current_request = req
-                        }
-                    }
-        
-                    if let CrosswordGenerationRequest::Stop = *current_request { return; }
+    }
 
-                    cs.send(current_crossword.clone().convert_to(|w| convert_f(w.as_ref()))).await.unwrap();
-                    if let CrosswordGenerationRequest::Count(count) = *current_request { *current_request = CrosswordGenerationRequest::Count(count - 1) }
-                }
-            }
-            return;
+    fn progress(&self) -> GenerationProgress
+    {
+        match (self.tracker.get(), self.summary.get())
+        {
+            (Some(tracker), summary) => GenerationProgress { elapsed: summary.map_or_else(|| tracker.start.elapsed(), |summary| summary.duration), ..tracker.progress() },
+            (None, _) => GenerationProgress::default(),
         }
-        let current_word = &words[*current_word_ind];
+    }
 
-        *current_word_ind += 1;
+    fn pending_requested(&self) -> usize
+    {
+        self.queued_requested.load(Ordering::Relaxed).saturating_add(self.current_requested.load(Ordering::Relaxed))
+    }
 
-        for step in current_crossword.calculate_possible_ways_to_add_word(current_word).iter()
-        {
-            current_crossword.add_word(step.clone()).unwrap();
+    fn set_state(&self, state: GeneratorState)
+    {
+        self.state.store(state as u8, Ordering::Relaxed);
+    }
 
-            CrosswordGenerator::randomized_generator_impl(gen_settings, rr.clone(), cs, current_request.clone(), current_crossword, words, current_word_ind, created_crosswords.clone(), convert_f).await;
+    fn is_paused(&self) -> bool
+    {
+        self.paused.load(Ordering::Relaxed)
+    }
 
-            if let CrosswordGenerationRequest::Stop = *current_request.lock().await { return; }
-            
-            //let to_remove: Vec<Crossword<CharT, &[CharT]>> = full_created_crossword_bases.iter().filter_map(|cw| cw.contains_crossword(current_crossword).then_some(cw.clone())).collect();
-            //to_remove.into_iter().for_each(|cw| {full_created_crossword_bases.remove(&cw);});
-            
-            //full_created_crossword_bases.insert(current_crossword.clone());
+    fn requested_count(req: &CrosswordGenerationRequest) -> usize
+    {
+        match req
+        {
+            CrosswordGenerationRequest::Count(count) => *count,
+            CrosswordGenerationRequest::All => usize::MAX,
+            _ => 0,
+        }
+    }
 
-            current_crossword.remove_word(&step.value);
+    // the generation must stop without waiting for the request, there are no crosswords requested before the stop left to generate
+    fn is_stop_requested(&self) -> bool
+    {
+        self.stop.load(Ordering::Relaxed) && 
+        self.queued_counts.load(Ordering::Relaxed) == 0 && 
+        !(1..usize::MAX).contains(&self.current_requested.load(Ordering::Relaxed))
+    }
 
+    // called by the stream before sending the request, a stop and a pause are honored right away, without waiting for the request to be taken
+    fn add_requested(&self, req: &CrosswordGenerationRequest)
+    {
+        // the counts requested after the stop are never taken
+        if let (CrosswordGenerationRequest::Count(count), false) = (req, self.stop.load(Ordering::Relaxed))
+        {
+            let _ = self.queued_counts.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| Some(queued.saturating_add(*count)));
         }
-        
-        *current_word_ind -= 1;
+        match req
+        {
+            CrosswordGenerationRequest::Stop => self.stop.store(true, Ordering::Relaxed),
+            CrosswordGenerationRequest::Pause => self.paused.store(true, Ordering::Relaxed),
+            CrosswordGenerationRequest::Count(_) | CrosswordGenerationRequest::All | CrosswordGenerationRequest::Resume => self.paused.store(false, Ordering::Relaxed),
+        }
+        let count = Self::requested_count(req);
+        let _ = self.queued_requested.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| Some(queued.saturating_add(count)));
+    }
 
+    // called by the generators for every request they take from the stream, and by the stream for the requests it failed to send
+    fn take_requested(&self, req: &CrosswordGenerationRequest)
+    {
+        let CrosswordGenerationRequest::Count(count) = req else { return; };
+        let _ = self.queued_requested.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| Some(if queued == usize::MAX { queued } else { queued.saturating_sub(*count) }));
+        let _ = self.queued_counts.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| Some(queued.saturating_sub(*count)));
     }
 
+    // called by the generators every time their current request changes
+    fn set_current_requested(&self, current_request: &CrosswordGenerationRequest)
+    {
+        self.current_requested.store(Self::requested_count(current_request), Ordering::Relaxed);
+    }
 
-    /// Takes a function to convert from &\[CharT\] to StrT, because the generator generates crosswords with words with type &\[CharT\] to prevent unnecessary copying
-    /// Fast, but crosswords in a non random order, consecutive crosswords are pretty similar.
-    /// If you need randomized results, check [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized).
-    pub fn crossword_stream_sorted<F>(&self, convert_f: F) -> CrosswordStream<CharT, StrT> where
-        F: Fn(&[CharT]) -> StrT,
-        F: Send + Sync + 'static
-    {  
-        let gen = self.clone();
-        
-        let gen_func = move |mut rr: Receiver<CrosswordGenerationRequest>, cs: Sender<Crossword<CharT, StrT>>| async move
+    // sets the reason of the end of the generation, a drop of the stream (without a requested stop) goes before a stop, and a stop before a reached limit
+    fn finish(&self, stopped: bool, dropped: bool, tracker: &GenerationTracker)
+    {
+        let reason = match tracker.limit_reached.get()
         {
-
-            let mut current_request = CrosswordGenerationRequest::Count(0);
-            let mut current_crossword = Crossword::new(gen.settings.word_compatibility_settings.clone());
-            let mut full_created_crossword_bases = BTreeSet::new();
-            let remaine_words = gen.words.iter().map(|w| Word::<CharT, &[CharT]>::new(w.value.as_ref(), w.dir.clone())).collect();
-            CrosswordGenerator::<CharT, StrT>::sorted_generator_impl(&gen.settings, &mut rr, &cs, &mut current_request, &mut current_crossword, &remaine_words, &mut full_created_crossword_bases, &convert_f).await
-               
+            _ if dropped && !self.stop.load(Ordering::Relaxed) => FinishReason::Dropped,
+            _ if stopped || self.is_stop_requested() => FinishReason::Stopped,
+            Some(limit) => FinishReason::LimitReached(*limit),
+            None => FinishReason::Exhausted,
         };
+        let _ = self.finish_reason.set(reason);
+    }
+
+    // sends the snapshot to the stream, if it's taking a checkpoint
+    fn answer_checkpoint(&self, snapshot: impl FnOnce() -> CheckpointSnapshot<CharT, StrT>)
+    {
+        if !self.checkpoint_requested.swap(false, Ordering::Relaxed) { return; }
+        if let Some(sender) = self.checkpoint_sender.lock().unwrap().take() { let _ = sender.send(snapshot()); }
+    }
 
-        CrosswordStream::new(gen_func)
+    // keeps the snapshot of the end of the generation for the later checkpoints, and sends it to the stream, if it's taking a checkpoint
+    fn finish_checkpoints(&self, snapshot: CheckpointSnapshot<CharT, StrT>)
+    {
+        let _ = self.final_checkpoint.set(snapshot.clone());
+        if let Some(sender) = self.checkpoint_sender.lock().unwrap().take() { let _ = sender.send(snapshot); }
     }
+}
 
-    #[async_recursion]
-    async fn sorted_generator_impl<'a, F>(gen_settings: &CrosswordGeneratorSettings, rr: &mut Receiver<CrosswordGenerationRequest>, cs: &Sender<Crossword<CharT, StrT>>, current_request: &mut CrosswordGenerationRequest, current_crossword: &mut Crossword<CharT, &'a [CharT]>, remained_words: &BTreeSet<Word<CharT, &'a [CharT]>>, full_created_crossword_bases: &mut BTreeSet<Crossword<CharT, &'a [CharT]>>, convert_f: &F) where  
-        F: Fn(&'a [CharT]) -> StrT,
-        F: Send + Sync + 'static
+// the state of the sorted stream at a checkpoint (check CrosswordStream::checkpoint)
+#[cfg(feature = "async")]
+#[derive(Clone)]
+struct CheckpointSnapshot<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    state: SortedGenerationState,
+    // the count of the crosswords sent to the stream before the snapshot
+    sent: usize,
+    // the found crossword that waits for a request
+    held: Option<Crossword<CharT, StrT>>,
+}
+
+
+// takes the requests while the generation is paused, and if for_crossword is set, while the current request is for no crosswords,
+// the current request is set to stop if the stream is dropped, before_wait is called before waiting for every request
+#[cfg(feature = "async")]
+async fn wait_for_requests<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(rr: &mut Receiver<CrosswordGenerationRequest>, current_request: &mut CrosswordGenerationRequest, control: &StreamControl<CharT, StrT>, for_crossword: bool, before_wait: impl Fn())
+{
+    loop
     {
-        if !gen_settings.crossword_settings.check_nonrecoverables_constraints(current_crossword) 
-        {
-            return; 
-        }
+        let idle = for_crossword && *current_request == CrosswordGenerationRequest::Count(0);
+        if *current_request == CrosswordGenerationRequest::Stop || !(idle || control.is_paused()) { break; }
 
-        if full_created_crossword_bases.iter().any(|cw| current_crossword.contains_crossword(cw))
+        control.set_state(if control.is_paused() { GeneratorState::Paused } else { GeneratorState::Idle });
+        before_wait();
+        match rr.next().await
         {
-            return;
-        }
-        
-        if remained_words.is_empty()
-        {
-            if gen_settings.crossword_settings.check_recoverable_constraints(current_crossword) 
+            None | Some(CrosswordGenerationRequest::Stop) => *current_request = CrosswordGenerationRequest::Stop,
+            // the pause flag is already set or cleared by the stream
+            Some(CrosswordGenerationRequest::Pause | CrosswordGenerationRequest::Resume) => {},
+            // the current request is set before the request is taken, so a stop after it is never honored between them
+            Some(req) => 
             {
-                while let CrosswordGenerationRequest::Count(0) = current_request
-                {
-                    match rr.recv().await
-                    {
-                        None | Some(CrosswordGenerationRequest::Stop) => { *current_request = CrosswordGenerationRequest::Stop; return },
-                        Some(req) => *current_request = req
-                    }
-                }
-
-                cs.send(current_crossword.clone().convert_to(|w| convert_f(w))).await.unwrap();
-                if let CrosswordGenerationRequest::Count(count) = *current_request { *current_request = CrosswordGenerationRequest::Count(count - 1) }
+                control.set_current_requested(&req);
+                control.take_requested(&req);
+                *current_request = req;
             }
-            return;
         }
-        for current_word in remained_words.iter()
-        {
-            let mut new_remained_words = remained_words.clone();
-            new_remained_words.remove(current_word);
-            for step in current_crossword.calculate_possible_ways_to_add_word(current_word).iter()
-            {
-                current_crossword.add_word(step.clone()).unwrap();
+    }
+    control.set_current_requested(current_request);
+    control.set_state(GeneratorState::Generating);
+}
 
-                CrosswordGenerator::sorted_generator_impl(gen_settings, rr, cs, current_request, current_crossword, &new_remained_words, full_created_crossword_bases, convert_f).await;
+// the stream is dropped, shut down or a stop is requested
+#[cfg(feature = "async")]
+fn is_stopped<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(cs: &Sender<Vec<Crossword<CharT, StrT>>>, control: &StreamControl<CharT, StrT>) -> bool
+{
+    cs.is_closed() || control.is_stop_requested()
+}
 
-                if let CrosswordGenerationRequest::Stop = current_request { return; }
-                
-                let to_remove: Vec<Crossword<CharT, &[CharT]>> = full_created_crossword_bases.iter().filter_map(|cw| cw.contains_crossword(current_crossword).then_some(cw.clone())).collect();
-                to_remove.into_iter().for_each(|cw| {full_created_crossword_bases.remove(&cw);});
-                
-                full_created_crossword_bases.insert(current_crossword.clone());
+// collects the generated crosswords, and sends them to the stream in batches (check CrosswordGeneratorSettings::emit_batch_size)
+#[cfg(feature = "async")]
+struct CrosswordBatch<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    crosswords: Vec<Crossword<CharT, StrT>>,
+    size: usize,
+}
 
-                current_crossword.remove_word(&step.value);
-            }
-        }
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordBatch<CharT, StrT>
+{
+    fn new(size: usize) -> CrosswordBatch<CharT, StrT>
+    {
+        CrosswordBatch { crosswords: vec![], size: size.max(1) }
+    }
 
- 
+    // adds the crossword, and sends the batch if it's full, returns false if the stream is dropped
+    async fn push(&mut self, cs: &mut Sender<Vec<Crossword<CharT, StrT>>>, crossword: Crossword<CharT, StrT>) -> bool
+    {
+        self.crosswords.push(crossword);
+        self.crosswords.len() < self.size || self.flush(cs).await
     }
 
+    // sends the collected crosswords, returns false if the stream is dropped
+    async fn flush(&mut self, cs: &mut Sender<Vec<Crossword<CharT, StrT>>>) -> bool
+    {
+        if self.crosswords.is_empty() { return true; }
+        cs.send(std::mem::take(&mut self.crosswords)).await.is_ok()
+    }
 }
 
-
-/// Represents a request to [CrosswordStream] for generating crosswords.
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
-pub enum CrosswordGenerationRequest
+// the generation waits for requests, so the collected crosswords must be sent before
+#[cfg(feature = "async")]
+fn waits_for_requests<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(current_request: &CrosswordGenerationRequest, control: &StreamControl<CharT, StrT>) -> bool
 {
-    /// Request to stop the crossword generation.
-    #[default]
-    Stop,
-    /// Request for some count of crosswords to generate.
-    Count(usize),
-    /// Request for generating all possible crosswords.
-    All
+    *current_request == CrosswordGenerationRequest::Count(0) || control.is_paused()
 }
 
-pub struct CrosswordStream<CharT: CrosswordChar + 'static, StrT: CrosswordString<CharT> + 'static>
+// counts the progress of a generation (relaxed atomics, so counting doesn't slow it down), and checks it against the limits
+struct GenerationTracker
 {
-    request_sender: Sender<CrosswordGenerationRequest>,
-    crossword_reciever: Receiver<Crossword<CharT, StrT>>
+    limits: GenerationLimits,
+    start: Instant,
+    crosswords: AtomicUsize,
+    placements_tried: AtomicU64,
+    backtracks: AtomicU64,
+    // only the randomized stream has permutations
+    #[cfg(feature = "async")]
+    permutations_started: AtomicUsize,
+    #[cfg(feature = "async")]
+    permutations_completed: AtomicUsize,
+    limit_reached: OnceLock<GenerationLimit>,
 }
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordStream<CharT, StrT>
+impl GenerationTracker
 {
-    
-    pub fn new<F,Fut>(gen_func: F) -> CrosswordStream<CharT, StrT>
-    where
-        F: FnOnce(Receiver<CrosswordGenerationRequest>, Sender<Crossword<CharT, StrT>>) -> Fut,
-        Fut: Future<Output=()> + Send + 'static
+    fn new(limits: GenerationLimits) -> GenerationTracker
     {
-        let (rs, rr) = mpsc::channel(100);
-        let (cs, cr) = mpsc::channel(100);
+        GenerationTracker 
+        { 
+            limits, 
+            start: Instant::now(), 
+            crosswords: AtomicUsize::new(0), 
+            placements_tried: AtomicU64::new(0), 
+            backtracks: AtomicU64::new(0), 
+            #[cfg(feature = "async")]
+            permutations_started: AtomicUsize::new(0), 
+            #[cfg(feature = "async")]
+            permutations_completed: AtomicUsize::new(0), 
+            limit_reached: OnceLock::new() 
+        }
+    }
 
-        task::spawn(gen_func(rr, cs));
-        
-        CrosswordStream { request_sender: rs, crossword_reciever: cr }
+    fn add_crossword(&self)
+    {
+        self.crosswords.fetch_add(1, Ordering::Relaxed);
     }
 
-    /// Requests crosswords to generate with function like next or take.
-    /// 
-    /// After requesting some count of crosswords (with [CrosswordGenerationRequest::Count]) and generating the crosswords the stream will start to wait for other requests, so if you want to only generate for example 10 crosswords, you need to request that, and then request a [CrosswordGenerationRequest::Stop] to stop the generator.
-    pub async fn request_crossword(&self, req: CrosswordGenerationRequest)
+    fn add_placement(&self)
     {
-        self.request_sender.send(req).await.unwrap();
+        self.placements_tried.fetch_add(1, Ordering::Relaxed);
     }
-}  
 
-impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Stream for CrosswordStream<CharT, StrT>
+    fn add_backtrack(&self)
+    {
+        self.backtracks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "async")]
+    fn progress(&self) -> GenerationProgress
+    {
+        GenerationProgress
+        {
+            permutations_started: self.permutations_started.load(Ordering::Relaxed),
+            permutations_completed: self.permutations_completed.load(Ordering::Relaxed),
+            placements_tried: self.placements_tried.load(Ordering::Relaxed),
+            backtracks: self.backtracks.load(Ordering::Relaxed),
+            crosswords: self.crosswords.load(Ordering::Relaxed),
+            elapsed: self.start.elapsed(),
+        }
+    }
+
+    fn is_reached(&self) -> bool
+    {
+        if self.limit_reached.get().is_some() { return true; }
+
+        let reached = 
+            if self.limits.max_crosswords.is_some_and(|max| self.crosswords.load(Ordering::Relaxed) >= max) { GenerationLimit::Crosswords }
+            else if self.limits.max_placements_tried.is_some_and(|max| self.placements_tried.load(Ordering::Relaxed) >= max) { GenerationLimit::PlacementsTried }
+            else if self.limits.max_duration.is_some_and(|max| self.start.elapsed() >= max) { GenerationLimit::Duration }
+            else { return false; };
+        let _ = self.limit_reached.set(reached);
+        true
+    }
+
+    #[cfg(feature = "async")]
+    fn summary(&self) -> GenerationSummary
+    {
+        GenerationSummary
+        {
+            crosswords: self.crosswords.load(Ordering::Relaxed),
+            placements_tried: self.placements_tried.load(Ordering::Relaxed),
+            duration: self.start.elapsed(),
+            limit_reached: self.limit_reached.get().cloned(),
+        }
+    }
+
+    fn has_crosswords(&self) -> bool
+    {
+        self.crosswords.load(Ordering::Relaxed) > 0
+    }
+}
+
+// collects what a failure report needs while searching (check CrosswordGeneratorSettings::failure_report), the generations keep it only if the report is turned on
+struct FailureDiagnostics<CharT: CrosswordChar, S: CrosswordString<CharT>>
 {
-    type Item = Crossword<CharT, StrT>;
+    largest_crossword: Crossword<CharT, S>,
+    word_placements: BTreeMap<S, u64>,
+    constraint_rejections: BTreeMap<CrosswordConstraint<CharT>, u64>,
+}
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>>
+impl<CharT: CrosswordChar, S: CrosswordString<CharT>> FailureDiagnostics<CharT, S>
+{
+    fn new(words: impl Iterator<Item = S>, word_compatibility_settings: WordCompatibilitySettings<CharT>) -> FailureDiagnostics<CharT, S>
+    {
+        FailureDiagnostics { largest_crossword: Crossword::new(word_compatibility_settings), word_placements: words.map(|w| (w, 0)).collect(), constraint_rejections: BTreeMap::new() }
+    }
+
+    // called after the word is added to the crossword, a word is counted only if it's placed next to other words (the first word together with the second one)
+    fn add_placement(&mut self, crossword: &Crossword<CharT, S>, placed: &S)
+    {
+        if crossword.len() < 2 { return; }
+        for w in crossword.iter().filter(|w| crossword.len() == 2 || w.value == *placed)
+        {
+            if let Some(count) = self.word_placements.get_mut(&w.value) { *count += 1; }
+        }
+    }
+
+    // called for the crosswords that are not pruned
+    fn add_reached(&mut self, crossword: &Crossword<CharT, S>)
+    {
+        if crossword.len() > self.largest_crossword.len() { self.largest_crossword = crossword.normalized(); }
+    }
+
+    fn add_rejection(&mut self, constraint: Option<&CrosswordConstraint<CharT>>)
+    {
+        if let Some(constraint) = constraint { *self.constraint_rejections.entry(constraint.clone()).or_default() += 1; }
+    }
+
+    // merges the diagnostics of a task of the randomized stream
+    #[cfg(feature = "async")]
+    fn merge(&mut self, other: FailureDiagnostics<CharT, S>)
+    {
+        if other.largest_crossword.len() > self.largest_crossword.len() { self.largest_crossword = other.largest_crossword; }
+        for (word, count) in other.word_placements { *self.word_placements.entry(word).or_default() += count; }
+        for (constraint, count) in other.constraint_rejections { *self.constraint_rejections.entry(constraint).or_default() += count; }
+    }
+
+    fn into_report<StrT: CrosswordString<CharT>>(self, convert_f: impl Fn(&[CharT]) -> StrT) -> GenerationFailure<CharT, StrT>
     {
-        self.crossword_reciever.poll_recv(cx)
+        // a single word has no other words to be placed next to
+        let unplaceable_words = if self.word_placements.len() < 2 { vec![] } else { self.word_placements.iter().filter(|(_, count)| **count == 0).map(|(w, _)| convert_f(w.as_ref())).collect() };
+        // the sort is stable, so constraints with the same count stay in their order
+        let constraint_rejections = self.constraint_rejections.into_iter().sorted_by_key(|(_, count)| Reverse(*count)).collect();
+        GenerationFailure
+        {
+            largest_crossword: self.largest_crossword.convert_to(|w| convert_f(w.as_ref())),
+            unplaceable_words,
+            word_placements: self.word_placements.into_iter().map(|(w, count)| (convert_f(w.as_ref()), count)).collect(),
+            constraint_rejections,
+        }
     }
-}
\ No newline at end of file
+}
+
+// the generated crosswords, for the filter of the crosswords that differ too little from them (check CrosswordGeneratorSettings::min_difference)
+struct EmittedCrosswords<CharT: CrosswordChar, S: CrosswordString<CharT>>
+{
+    min_difference: usize,
+    // kept only with the filter
+    crosswords: Vec<Crossword<CharT, S>>,
+}
+
+impl<CharT: CrosswordChar, S: CrosswordString<CharT>> EmittedCrosswords<CharT, S>
+{
+    fn new(min_difference: usize) -> EmittedCrosswords<CharT, S>
+    {
+        EmittedCrosswords { min_difference, crosswords: vec![] }
+    }
+
+    // keeps the crossword and returns true, if it differs enough from the kept ones
+    fn offer(&mut self, crossword: &Crossword<CharT, S>) -> bool
+    {
+        if self.min_difference == 0 { return true; }
+
+        let difference = |other: &Crossword<CharT, S>| crossword.len().max(other.len()) - crossword.shared_placements(other);
+        if self.crosswords.iter().any(|other| difference(other) < self.min_difference) { return false; }
+        self.crosswords.push(crossword.normalized());
+        true
+    }
+}
+
+// a hasher with a fixed algorithm (128 bit FNV-1a), so the same values have the same hash in every run (the hashes are kept in the checkpoints)
+struct StableHasher(u128);
+
+impl StableHasher
+{
+    fn finish_u128(&self) -> u128
+    {
+        self.0
+    }
+}
+
+impl Default for StableHasher
+{
+    fn default() -> Self
+    {
+        StableHasher(0x6c62272e07bb014262b821756295c58d)
+    }
+}
+
+impl Hasher for StableHasher
+{
+    fn finish(&self) -> u64
+    {
+        self.0 as u64
+    }
+
+    fn write(&mut self, bytes: &[u8])
+    {
+        for byte in bytes
+        {
+            self.0 = (self.0 ^ *byte as u128).wrapping_mul(0x0000000001000000000000000000013b);
+        }
+    }
+}
+
+// the address and the length of a value, equal for the values sharing their memory
+fn value_address<CharT>(value: &[CharT]) -> (usize, usize)
+{
+    (value.as_ptr() as usize, value.len())
+}
+
+// the layout of a crossword, the positions of its words relative to its minimum corner with their directions and ids (check WordIds), in the order of the words, 
+// so the layouts of the same crosswords are the same, and they are compared and hashed without the values of the words
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+struct LayoutFingerprint(Box<[(i16, i16, Direction, u32)]>);
+
+// the ids of the words of a generation, the words with the same value have the same id (the index of the last of them), whatever their metadata, like the placed words are compared, 
+// the words of the crosswords are found by the addresses of their values (the crosswords of a generation share the values of its words)
+struct WordIds<CharT: CrosswordChar, S: CrosswordString<CharT>>
+{
+    words: Vec<S>,
+    by_address: HashMap<(usize, usize), u32>,
+    character_type: PhantomData<CharT>,
+}
+
+impl<CharT: CrosswordChar, S: CrosswordString<CharT>> WordIds<CharT, S>
+{
+    fn new(words: impl IntoIterator<Item = S>) -> WordIds<CharT, S>
+    {
+        let words = words.into_iter().collect::<Vec<_>>();
+        let ids = words.iter().enumerate().map(|(id, value)| (value.as_ref(), id as u32)).collect::<BTreeMap<_, _>>();
+        let by_address = words.iter().map(|value| (value_address(value.as_ref()), ids[value.as_ref()])).collect();
+        WordIds { words, by_address, character_type: PhantomData }
+    }
+
+    fn id(&self, word: &PlacedWord<CharT, S>) -> u32
+    {
+        self.value_id(word.value.as_ref())
+    }
+
+    // the values in other places (not shared with the words of the generation) are found by comparing them
+    fn value_id(&self, value: &[CharT]) -> u32
+    {
+        self.by_address.get(&value_address(value)).copied()
+            .or_else(|| (0..self.words.len() as u32).rev().find(|id| self.words[*id as usize].as_ref() == value))
+            .expect("the words of the crossword are the words of the generation")
+    }
+
+    fn fingerprint(&self, crossword: &Crossword<CharT, S>) -> LayoutFingerprint
+    {
+        let (x, y) = crossword.min_corner();
+        LayoutFingerprint(crossword.iter().map(|w| (w.position.x - x, w.position.y - y, w.direction.clone(), self.id(w))).collect())
+    }
+}
+
+// the distinct values of the words of a generation run, shared as one Arc for every equal value, and converted to the string type of the generator once, when they are first needed, 
+// so the crosswords made of the same words don't convert them again
+#[cfg(feature = "async")]
+struct WordInterner<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    values: Vec<(Arc<[CharT]>, OnceLock<StrT>)>,
+    // the indices of the values by the addresses of the interned values and of the values they are interned from
+    by_address: HashMap<(usize, usize), usize>,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> WordInterner<CharT, StrT>
+{
+    fn new<'v>(values: impl IntoIterator<Item = &'v [CharT]>) -> WordInterner<CharT, StrT> where CharT: 'v
+    {
+        let mut interned = WordInterner { values: vec![], by_address: HashMap::new() };
+        let mut inds = BTreeMap::new();
+        for value in values
+        {
+            let ind = *inds.entry(value).or_insert_with(|| 
+            {
+                interned.values.push((Arc::from(value), OnceLock::new()));
+                interned.values.len() - 1
+            });
+            interned.by_address.insert(value_address(value), ind);
+        }
+        for (ind, (value, _)) in interned.values.iter().enumerate() { interned.by_address.insert(value_address(value), ind); }
+        interned
+    }
+
+    // the values are compared too, so a value in the memory of a dropped one isn't taken for it
+    fn find(&self, value: &[CharT]) -> Option<&(Arc<[CharT]>, OnceLock<StrT>)>
+    {
+        self.by_address.get(&value_address(value)).map(|ind| &self.values[*ind]).filter(|(interned, _)| **interned == *value)
+            .or_else(|| self.values.iter().find(|(interned, _)| **interned == *value))
+    }
+
+    // the shared value, or a new one for the values that aren't interned
+    fn intern(&self, value: &[CharT]) -> Arc<[CharT]>
+    {
+        self.find(value).map_or_else(|| Arc::from(value), |(interned, _)| interned.clone())
+    }
+
+    fn convert(&self, value: &[CharT], convert_f: impl Fn(&[CharT]) -> StrT) -> StrT
+    {
+        match self.find(value)
+        {
+            Some((_, converted)) => converted.get_or_init(|| convert_f(value)).clone(),
+            None => convert_f(value),
+        }
+    }
+}
+
+// the layouts of the generated crosswords, for skipping the duplicates, kept as the limit says (check CrosswordGeneratorSettings::dedup_memory_limit)
+enum CreatedCrosswords
+{
+    All(HashSet<LayoutFingerprint>),
+    // the layouts with the tick of their last insertion, and the layouts by those ticks
+    Bounded
+    {
+        limit: usize,
+        tick: u64,
+        crosswords: HashMap<LayoutFingerprint, u64>,
+        insertions: BTreeMap<u64, LayoutFingerprint>,
+    },
+    Hashes(HashSet<u128>),
+}
+
+impl CreatedCrosswords
+{
+    fn new(limit: DedupMemoryLimit) -> CreatedCrosswords
+    {
+        match limit
+        {
+            DedupMemoryLimit::KeepAll => CreatedCrosswords::All(HashSet::new()),
+            DedupMemoryLimit::Bounded(limit) => CreatedCrosswords::Bounded { limit, tick: 0, crosswords: HashMap::new(), insertions: BTreeMap::new() },
+            DedupMemoryLimit::HashOnly => CreatedCrosswords::Hashes(HashSet::new()),
+        }
+    }
+
+    // the hash of the layout kept with DedupMemoryLimit::HashOnly
+    fn hash(layout: &LayoutFingerprint) -> u128
+    {
+        let mut hasher = StableHasher::default();
+        layout.hash(&mut hasher);
+        hasher.finish_u128()
+    }
+
+    // doesn't count as an insertion for DedupMemoryLimit::Bounded
+    fn contains(&self, layout: &LayoutFingerprint) -> bool
+    {
+        match self
+        {
+            CreatedCrosswords::All(crosswords) => crosswords.contains(layout),
+            CreatedCrosswords::Bounded { crosswords, .. } => crosswords.contains_key(layout),
+            CreatedCrosswords::Hashes(hashes) => hashes.contains(&CreatedCrosswords::hash(layout)),
+        }
+    }
+
+    // returns true if the layout is not created yet (or it's dropped already)
+    fn insert(&mut self, layout: LayoutFingerprint) -> bool
+    {
+        match self
+        {
+            CreatedCrosswords::All(crosswords) => crosswords.insert(layout),
+            CreatedCrosswords::Bounded { limit, tick, crosswords, insertions } =>
+            {
+                *tick += 1;
+                let created = match crosswords.get_mut(&layout)
+                {
+                    Some(last_tick) =>
+                    {
+                        insertions.remove(last_tick);
+                        *last_tick = *tick;
+                        false
+                    }
+                    None =>
+                    {
+                        crosswords.insert(layout.clone(), *tick);
+                        true
+                    }
+                };
+                insertions.insert(*tick, layout);
+                while crosswords.len() > *limit
+                {
+                    let Some((_, oldest)) = insertions.pop_first() else { break; };
+                    crosswords.remove(&oldest);
+                }
+                created
+            }
+            CreatedCrosswords::Hashes(hashes) => hashes.insert(CreatedCrosswords::hash(&layout)),
+        }
+    }
+}
+
+/// A small portable random number generator (PCG32), so the same seed gives the same crosswords on every platform.
+#[derive(Clone, Debug)]
+struct GeneratorRng
+{
+    state: u64,
+}
+
+impl GeneratorRng
+{
+    const MULTIPLIER: u64 = 6364136223846793005;
+    const INCREMENT: u64 = 1442695040888963407;
+
+    fn new(seed: u64) -> GeneratorRng
+    {
+        let mut rng = GeneratorRng { state: 0 };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    /// Returns a seed from the randomness of the standard library.
+    #[cfg(feature = "tokio")]
+    fn entropy_seed() -> u64
+    {
+        RandomState::new().build_hasher().finish()
+    }
+
+    fn next_u32(&mut self) -> u32
+    {
+        let old = self.state;
+        self.state = old.wrapping_mul(Self::MULTIPLIER).wrapping_add(Self::INCREMENT);
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+
+    fn next_u64(&mut self) -> u64
+    {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    /// Returns a random number in 0..bound (bound must be positive).
+    fn below(&mut self, bound: usize) -> usize
+    {
+        // rejecting the values that would make the distribution uneven
+        let bound = bound as u64;
+        let zone = u64::MAX - u64::MAX % bound;
+        loop
+        {
+            let value = self.next_u64();
+            if value < zone { return (value % bound) as usize; }
+        }
+    }
+
+    fn shuffle<T>(&mut self, values: &mut [T])
+    {
+        for ind in (1..values.len()).rev()
+        {
+            values.swap(ind, self.below(ind + 1));
+        }
+    }
+
+    /// Returns an independent generator, seeded from this one.
+    #[cfg(feature = "async")]
+    fn fork(&mut self) -> GeneratorRng
+    {
+        GeneratorRng::new(self.next_u64())
+    }
+}
+
+// draws the orders of the words for the tasks of the randomized stream (words with bigger weights are always placed first), 
+// without repeating an order while their count fits in u128, and the generator of the placements of each order
+#[cfg(feature = "async")]
+struct PermutationSampler<T>
+{
+    words: Vec<T>,
+    // the sizes of the groups of the words with the same weight, in the order of the words
+    groups: Vec<usize>,
+    // the count of the orders, None if it doesn't fit in u128 (then the orders are shuffled, so they can repeat)
+    count: Option<u128>,
+    remaining: Option<u128>,
+    drawn: u128,
+    // the keys of the random permutation of 0..count, the orders are drawn in its order
+    keys: [u64; 4],
+    rng: GeneratorRng,
+    placement_rng: Option<GeneratorRng>,
+}
+
+#[cfg(feature = "async")]
+impl<T: Clone> PermutationSampler<T>
+{
+    // the words are grouped by their weights, the order of the words with the same weight doesn't matter
+    fn new<W: Ord>(words: Vec<T>, weight: impl Fn(&T) -> W, max_permutations: Option<usize>, mut rng: GeneratorRng, placement_rng: Option<GeneratorRng>) -> PermutationSampler<T>
+    {
+        let groups = words.iter().chunk_by(|w| weight(w)).into_iter().map(|(_, group)| group.count()).collect::<Vec<_>>();
+        let count = groups.iter().try_fold(1u128, |count, size| (1..=*size as u128).try_fold(count, |count, k| count.checked_mul(k)));
+        let remaining = match (count, max_permutations)
+        {
+            (Some(count), Some(max)) => Some(count.min(max as u128)),
+            (count, max) => count.or(max.map(|max| max as u128)),
+        };
+        let keys = [rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()];
+        PermutationSampler { words, groups, count, remaining, drawn: 0, keys, rng, placement_rng }
+    }
+
+    fn next(&mut self) -> Option<(Vec<T>, GeneratorRng)>
+    {
+        if let Some(remaining) = self.remaining.as_mut()
+        {
+            if *remaining == 0 { return None; }
+            *remaining -= 1;
+        }
+
+        let mut order = Vec::with_capacity(self.words.len());
+        let mut start = 0;
+        let mut index = self.count.map(|count| self.permuted(self.drawn, count));
+        for &size in &self.groups
+        {
+            let mut group = self.words[start..start + size].to_vec();
+            start += size;
+            match index.as_mut()
+            {
+                // the index of the order of the group, its digits in the factorial number system choose the words
+                Some(index) => 
+                {
+                    let factorial = (1..=size as u128).product::<u128>();
+                    let mut group_index = *index % factorial;
+                    *index /= factorial;
+                    for k in (1..=size as u128).rev()
+                    {
+                        let factorial = (1..k).product::<u128>();
+                        order.push(group.remove((group_index / factorial) as usize));
+                        group_index %= factorial;
+                    }
+                }
+                None => 
+                {
+                    self.rng.shuffle(&mut group);
+                    order.extend(group);
+                }
+            }
+        }
+        self.drawn += 1;
+        Some((order, self.placement_rng.as_mut().unwrap_or(&mut self.rng).fork()))
+    }
+
+    // a random permutation of 0..count (a feistel network on the smallest even power of two that is not less than the count, 
+    // repeated while the value is out of the range, at most four times on average)
+    fn permuted(&self, mut value: u128, count: u128) -> u128
+    {
+        let bits = (128 - count.saturating_sub(1).leading_zeros()).div_ceil(2);
+        let mask = if bits == 0 { 0 } else { u64::MAX >> (64 - bits) };
+        loop
+        {
+            let (mut left, mut right) = (((value >> bits) as u64) & mask, (value as u64) & mask);
+            for key in self.keys
+            {
+                // the finalizer of splitmix64
+                let mut mixed = right ^ key;
+                mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d049bb133111eb);
+                (left, right) = (right, left ^ ((mixed ^ (mixed >> 31)) & mask));
+            }
+            value = ((left as u128) << bits) | right as u128;
+            if value < count { return value; }
+        }
+    }
+}
+
+/// A function that decides whether a crossword found by a [generator](CrosswordGenerator) is generated, for the conditions that the [constraints](CrosswordConstraint) can't express.
+/// 
+/// It's called with every crossword that passes all the other checks right before it's generated, the crosswords it rejects are not generated 
+/// and don't count for the requests and the limits, so a stream still generates as many crosswords as requested (if there are enough of them).
+/// 
+/// All filters are considered equal, so [settings](CrosswordGeneratorSettings) only compare the presence of the filter.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::sync::Arc;
+/// # use crossword_generator::generator::{CrosswordGenerator, EmitFilter};
+/// # use crossword_generator::word::Word;
+/// let mut generator = CrosswordGenerator::<u8, &str>::default();
+/// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+/// generator.settings.emit_filter = Some(EmitFilter(Arc::new(|cw| cw.get_size().0 <= 5)));
+/// 
+/// assert!(generator.generate_iter().all(|cw| cw.get_size().0 <= 5));
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct EmitFilter<CharT: CrosswordChar>(pub Arc<dyn Fn(&Crossword<CharT, Arc<[CharT]>>) -> bool + Send + Sync>);
+
+impl<CharT: CrosswordChar> std::fmt::Debug for EmitFilter<CharT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        f.write_str("EmitFilter")
+    }
+}
+
+impl<CharT: CrosswordChar> PartialEq for EmitFilter<CharT>
+{
+    fn eq(&self, _other: &Self) -> bool 
+    {
+        true
+    }
+}
+
+impl<CharT: CrosswordChar> Eq for EmitFilter<CharT> {}
+
+impl<CharT: CrosswordChar> PartialOrd for EmitFilter<CharT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar> Ord for EmitFilter<CharT>
+{
+    fn cmp(&self, _other: &Self) -> std::cmp::Ordering 
+    {
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl<CharT: CrosswordChar> Hash for EmitFilter<CharT>
+{
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Trait for the way a [generator](CrosswordGenerator) finds the placements of a word it tries to add to a crossword (check [CrosswordGeneratorSettings::placement_strategy]).
+/// 
+/// The [placement order](CrosswordGeneratorSettings::placement_order) of the generator is applied to the placements afterwards, 
+/// [PlacementOrder::Sorted] keeps the order of the strategy for all streams.
+pub trait PlacementStrategy<CharT: CrosswordChar, StrT: CrosswordString<CharT>>: Send + Sync
+{
+    /// Returns the placements of the word to try, the generator panics if a placement is not one of the [possible ways to add the word](Crossword::calculate_possible_ways_to_add_word) to the crossword.
+    /// 
+    /// The generator doesn't normalize the crosswords while searching, so the crossword can be shifted from its normalized position (check [Crossword]).
+    fn candidate_placements(&self, crossword: &Crossword<CharT, StrT>, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>;
+}
+
+/// The default [placement strategy](PlacementStrategy), all the possible ways to add the word to the crossword, sorted by their positions.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct PossiblePlacements;
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacementStrategy<CharT, StrT> for PossiblePlacements
+{
+    fn candidate_placements(&self, crossword: &Crossword<CharT, StrT>, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>
+    {
+        crossword.possible_placements(word)
+    }
+}
+
+/// A [placement strategy](PlacementStrategy) that tries the possible placements with the centers nearest to the center of the crossword first, so the crosswords grow evenly around their first words.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::word::{Direction, Position, Word};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::Crossword;
+/// # use crossword_generator::generator::{CenterBiased, PlacementStrategy};
+/// let mut cw = Crossword::<u8, &str>::default();
+/// cw.add_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+/// 
+/// // "local" crossing the middle "l" of "hello"
+/// let placements = CenterBiased.candidate_placements(&cw, &Word::new("local", None));
+/// assert_eq!(placements[0].position.x, 2);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct CenterBiased;
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacementStrategy<CharT, StrT> for CenterBiased
+{
+    fn candidate_placements(&self, crossword: &Crossword<CharT, StrT>, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>
+    {
+        // the doubled centers (sums of the ends), so they are integers
+        let ends = |w: &PlacedWord<CharT, StrT>| [w.position.clone(), w.end_position()];
+        let (mut min, mut max) = ((i32::MAX, i32::MAX), (i32::MIN, i32::MIN));
+        for position in crossword.iter().flat_map(ends)
+        {
+            min = (min.0.min(position.x as i32), min.1.min(position.y as i32));
+            max = (max.0.max(position.x as i32), max.1.max(position.y as i32));
+        }
+        let center = (min.0 as i64 + max.0 as i64, min.1 as i64 + max.1 as i64);
+        let distance = |w: &PlacedWord<CharT, StrT>|
+        {
+            let [start, end] = ends(w);
+            (start.x as i64 + end.x as i64 - center.0).abs() + (start.y as i64 + end.y as i64 - center.1).abs()
+        };
+
+        let mut placements = PossiblePlacements.candidate_placements(crossword, word);
+        if !crossword.is_empty() { placements.sort_by_cached_key(distance); }
+        placements
+    }
+}
+
+/// A [placement strategy](PlacementStrategy) for the [settings](CrosswordGeneratorSettings) of a generator, it's used for the crosswords of all the streams.
+/// 
+/// Strategies are compared by their [Arc]s, so the clones of a strategy are equal, but separately created strategies aren't (even of the same type).
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::sync::Arc;
+/// # use crossword_generator::generator::{CenterBiased, CrosswordGenerator, SharedPlacementStrategy};
+/// # use crossword_generator::word::Word;
+/// let mut generator = CrosswordGenerator::<u8, &str>::default();
+/// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+/// let mut all = generator.generate_iter().collect::<Vec<_>>();
+/// all.sort();
+/// 
+/// // the same crosswords in another order
+/// generator.settings.placement_strategy = Some(SharedPlacementStrategy(Arc::new(CenterBiased)));
+/// let mut center_biased = generator.generate_iter().collect::<Vec<_>>();
+/// center_biased.sort();
+/// assert_eq!(center_biased, all);
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct SharedPlacementStrategy<CharT: CrosswordChar>(pub Arc<dyn for<'a> PlacementStrategy<CharT, &'a [CharT]>>);
+
+impl<CharT: CrosswordChar> std::fmt::Debug for SharedPlacementStrategy<CharT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        f.write_str("SharedPlacementStrategy")
+    }
+}
+
+impl<CharT: CrosswordChar> SharedPlacementStrategy<CharT>
+{
+    // the address of the strategy, shared by all its clones
+    fn key(&self) -> *const ()
+    {
+        Arc::as_ptr(&self.0) as *const ()
+    }
+}
+
+impl<CharT: CrosswordChar> PartialEq for SharedPlacementStrategy<CharT>
+{
+    fn eq(&self, other: &Self) -> bool 
+    {
+        self.key() == other.key()
+    }
+}
+
+impl<CharT: CrosswordChar> Eq for SharedPlacementStrategy<CharT> {}
+
+impl<CharT: CrosswordChar> PartialOrd for SharedPlacementStrategy<CharT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar> Ord for SharedPlacementStrategy<CharT>
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering 
+    {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<CharT: CrosswordChar> Hash for SharedPlacementStrategy<CharT>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) 
+    {
+        self.key().hash(state);
+    }
+}
+
+/// Represents all settings for a [generator](CrosswordGenerator).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+// the skipped fields are None by default, so the characters don't need Default
+#[serde(bound(deserialize = "CharT: Deserialize<'de>"))]
+pub struct CrosswordGeneratorSettings<CharT: CrosswordChar>
+{
+    pub crossword_settings: CrosswordSettings<CharT>,
+    pub word_compatibility_settings: WordCompatibilitySettings<CharT>,
+    /// If set, crosswords that are transposed or mirrored versions of already generated crosswords are skipped (check [Crossword::canonicalize]).
+    pub dedup_symmetric: bool,
+    /// If set, crosswords without some [optional](Word::required) words are generated even if the words can be added to them, 
+    /// otherwise only the crosswords to which none of the left out optional words can be added are generated.
+    #[serde(default)]
+    pub emit_subsets: bool,
+    /// If set, [required](Word::required) words that can't be added to a crossword can be left out of it like optional words, 
+    /// otherwise a word without possible placements makes all the crosswords with the other words fail (check [CrosswordGenerator::generate_iter_with_unused_words] for the left out words).
+    #[serde(default)]
+    pub allow_unplaced_words: bool,
+    /// The minimum count of different placements of a generated crossword from every earlier generated crossword (the count of the words of the larger crossword 
+    /// minus the [shared placements](Crossword::shared_placements)), so the generated crosswords are not near copies of each other, 0 (no filter) by default.
+    /// 
+    /// The filtered out crosswords are still searched further, but they are not generated, so they don't count for the requests and the limits.
+    #[serde(default)]
+    pub min_difference: usize,
+    /// If set, only the crosswords with the 180 degrees rotational symmetry of the filled cells are generated, 
+    /// the same as adding [CrosswordConstraint::RotationalSymmetry] to the [constraints](CrosswordSettings::constraints).
+    #[serde(default)]
+    pub require_rotational_symmetry: bool,
+    /// If set, only the crosswords in which the counts of the horizontal and the vertical words differ by at most this value are generated.
+    /// 
+    /// The crosswords that can't get balanced with the remaining words are pruned while searching, 
+    /// the remaining words with a fixed [direction](Word::dir) are counted for their direction.
+    #[serde(default)]
+    pub max_direction_skew: Option<usize>,
+    /// Decides whether a found crossword is generated (check [EmitFilter]).
+    #[serde(skip)]
+    pub emit_filter: Option<EmitFilter<CharT>>,
+    /// Finds the placements of the words while generating (check [PlacementStrategy]), all the possible placements ([PossiblePlacements]) by default.
+    #[serde(skip)]
+    pub placement_strategy: Option<SharedPlacementStrategy<CharT>>,
+    /// The order in which the possible placements of a word are tried while generating.
+    #[serde(default)]
+    pub placement_order: PlacementOrder,
+    /// How the generated crosswords are kept for skipping the duplicates, only their hashes by default.
+    #[serde(default)]
+    pub dedup_memory_limit: DedupMemoryLimit,
+    /// If set, the sorted generation skips the crosswords that contain a fully searched crossword, not only the fully searched crosswords (check [DedupMemoryLimit]).
+    /// 
+    /// Then it keeps only the fully searched crosswords that don't contain other ones, so the memory doesn't grow with the search, 
+    /// but every crossword of the search is compared with all of them (instead of a lookup of its layout).
+    /// A crossword that contains a fully searched one is usually found in its search too, so the generated crosswords are the same, 
+    /// except the ones that can't be reached by adding their other words to the fully searched one, for example if a [placement strategy](CrosswordGeneratorSettings::placement_strategy) 
+    /// doesn't offer those placements, or the [adjacent sequence validator](WordCompatibilitySettings::adjacent_sequence_validator) rejects a crossword between them, those are skipped.
+    /// The same happens with the [placement constraints](Word::placement_constraint), which depend on the crossword the word is added to.
+    /// 
+    /// Before this setting was added, the sorted generation always skipped the containing crosswords, set it to get the same crosswords as then.
+    #[serde(default)]
+    pub prune_containing_crosswords: bool,
+    /// The count of the generation tasks of [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized), each of them searches orders of the words until there are no more, 10 by default.
+    #[serde(default = "default_max_concurrent_tasks")]
+    pub max_concurrent_tasks: usize,
+    /// The maximum count of the orders of the words searched by [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized), all of them by default.
+    /// 
+    /// The tasks draw the orders at random, without repeating an order (unless the words are too many to count their orders, more than 34 with the same weight), 
+    /// so with many words the stream can search only a part of them, and with too many words it never ends on its own without this limit.
+    #[serde(default)]
+    pub max_permutations: Option<usize>,
+    /// The count of generated crosswords a [stream](CrosswordStream) can hold before the generation waits for them to be taken, 100 by default.
+    /// 
+    /// The stream holds whole [batches](CrosswordGeneratorSettings::emit_batch_size), so the count is rounded up to a multiple of the batch size, 
+    /// and every task of [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized) can send one more batch (check [max_concurrent_tasks](CrosswordGeneratorSettings::max_concurrent_tasks)).
+    #[serde(default = "default_buffer_size")]
+    pub crossword_buffer: usize,
+    /// The count of requests a [stream](CrosswordStream) can hold before [request_crossword](CrosswordStream::request_crossword) waits for them to be handled, 100 by default.
+    #[serde(default = "default_buffer_size")]
+    pub request_buffer: usize,
+    /// The count of the crosswords the generation of a [stream](CrosswordStream) collects before sending them to the stream together, 
+    /// that is faster for many small crosswords, 1 by default (the buffer of the stream holds [crossword_buffer](CrosswordGeneratorSettings::crossword_buffer) crosswords in batches of this size).
+    /// 
+    /// The collected crosswords are sent before the generation waits for requests and at its end, so the requested crosswords are never held back.
+    #[serde(default = "default_emit_batch_size")]
+    pub emit_batch_size: usize,
+    /// The limits of the generation, for the streams and [generate_iter](CrosswordGenerator::generate_iter).
+    #[serde(default)]
+    pub limits: GenerationLimits,
+    /// If set, the streams collect a [report](CrosswordStream::failure_report) of a generation that generates no crosswords, that slows the generation a bit.
+    #[serde(default)]
+    pub failure_report: bool,
+}
+
+fn default_max_concurrent_tasks() -> usize
+{
+    DEFAULT_MAX_CONCURRENT_TASKS
+}
+
+fn default_buffer_size() -> usize
+{
+    DEFAULT_BUFFER_SIZE
+}
+
+fn default_emit_batch_size() -> usize
+{
+    1
+}
+
+impl<CharT: CrosswordChar> Default for CrosswordGeneratorSettings<CharT>
+{
+    fn default() -> Self 
+    {
+        CrosswordGeneratorSettings
+        {
+            crossword_settings: CrosswordSettings::default(),
+            word_compatibility_settings: WordCompatibilitySettings::default(),
+            dedup_symmetric: false,
+            emit_subsets: false,
+            allow_unplaced_words: false,
+            min_difference: 0,
+            require_rotational_symmetry: false,
+            max_direction_skew: None,
+            emit_filter: None,
+            placement_strategy: None,
+            placement_order: PlacementOrder::default(),
+            dedup_memory_limit: DedupMemoryLimit::default(),
+            prune_containing_crosswords: false,
+            max_concurrent_tasks: DEFAULT_MAX_CONCURRENT_TASKS,
+            max_permutations: None,
+            crossword_buffer: DEFAULT_BUFFER_SIZE,
+            request_buffer: DEFAULT_BUFFER_SIZE,
+            emit_batch_size: 1,
+            limits: GenerationLimits::default(),
+            failure_report: false,
+        }
+    }
+}
+
+impl<CharT: CrosswordChar> CrosswordGeneratorSettings<CharT>
+{
+    /// Checks the settings, the streams treat the invalid zero values as one.
+    /// 
+    /// # Errors
+    /// 
+    /// [GeneratorSettingsError::ZeroMaxConcurrentTasks] - [max_concurrent_tasks](CrosswordGeneratorSettings::max_concurrent_tasks) is zero.
+    /// 
+    /// [GeneratorSettingsError::ZeroCrosswordBuffer] - [crossword_buffer](CrosswordGeneratorSettings::crossword_buffer) is zero.
+    /// 
+    /// [GeneratorSettingsError::ZeroRequestBuffer] - [request_buffer](CrosswordGeneratorSettings::request_buffer) is zero.
+    /// 
+    /// [GeneratorSettingsError::ZeroEmitBatchSize] - [emit_batch_size](CrosswordGeneratorSettings::emit_batch_size) is zero.
+    pub fn validate(&self) -> Result<(), GeneratorSettingsError>
+    {
+        if self.max_concurrent_tasks == 0 { return Err(GeneratorSettingsError::ZeroMaxConcurrentTasks); }
+        if self.crossword_buffer == 0 { return Err(GeneratorSettingsError::ZeroCrosswordBuffer); }
+        if self.request_buffer == 0 { return Err(GeneratorSettingsError::ZeroRequestBuffer); }
+        if self.emit_batch_size == 0 { return Err(GeneratorSettingsError::ZeroEmitBatchSize); }
+        Ok(())
+    }
+
+    // the settings that change the generated crosswords are the same (the adjacent sequence validator is not serialized, so it's not compared)
+    #[cfg(feature = "async")]
+    fn same_search(&self, other: &CrosswordGeneratorSettings<CharT>) -> bool
+    {
+        let compatibility = |settings: &CrosswordGeneratorSettings<CharT>| WordCompatibilitySettings { adjacent_sequence_validator: None, ..settings.word_compatibility_settings.clone() };
+        self.crossword_settings == other.crossword_settings &&
+        compatibility(self) == compatibility(other) &&
+        self.dedup_symmetric == other.dedup_symmetric &&
+        self.emit_subsets == other.emit_subsets &&
+        self.allow_unplaced_words == other.allow_unplaced_words &&
+        self.min_difference == other.min_difference &&
+        self.require_rotational_symmetry == other.require_rotational_symmetry &&
+        self.max_direction_skew == other.max_direction_skew &&
+        self.placement_order == other.placement_order &&
+        self.dedup_memory_limit == other.dedup_memory_limit &&
+        self.prune_containing_crosswords == other.prune_containing_crosswords
+    }
+}
+
+/// Error type for invalid [generator settings](CrosswordGeneratorSettings) (check [CrosswordGeneratorSettings::validate]).
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum GeneratorSettingsError
+{
+    #[error("The maximum count of concurrent tasks is zero")]
+    ZeroMaxConcurrentTasks,
+    #[error("The size of the crossword buffer is zero")]
+    ZeroCrosswordBuffer,
+    #[error("The size of the request buffer is zero")]
+    ZeroRequestBuffer,
+    #[error("The size of the batches of the crosswords is zero")]
+    ZeroEmitBatchSize,
+}
+
+/// Represents the limits of a generation, the generation ends when any of them is reached, there are no limits by default.
+/// 
+/// The streams end as if all the crosswords were generated, check [CrosswordStream::summary] for the reached limit.
+/// The limits of the randomized stream are shared by all of its tasks, so the counts can be a bit more than the limits (except the count of the crosswords).
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::generator::{CrosswordGenerator, GenerationLimits};
+/// # use crossword_generator::word::Word;
+/// let mut generator = CrosswordGenerator::<u8, &str>::default();
+/// generator.words = ["hello", "local", "cat", "toy"].into_iter().map(|s| Word::new(s, None)).collect();
+/// generator.settings.limits = GenerationLimits { max_crosswords: Some(2), ..Default::default() };
+/// 
+/// assert_eq!(generator.generate_iter().count(), 2);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct GenerationLimits
+{
+    /// The maximum duration of the generation, from the creation of the stream (or the iterator).
+    pub max_duration: Option<Duration>,
+    /// The maximum count of generated crosswords.
+    pub max_crosswords: Option<usize>,
+    /// The maximum count of word placements tried while searching.
+    pub max_placements_tried: Option<u64>,
+}
+
+/// Represents a limit of [GenerationLimits].
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum GenerationLimit
+{
+    Duration,
+    Crosswords,
+    PlacementsTried,
+}
+
+/// Represents the summary of a finished generation (check [CrosswordStream::summary]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct GenerationSummary
+{
+    /// The count of the generated crosswords.
+    pub crosswords: usize,
+    /// The count of the word placements tried while searching.
+    pub placements_tried: u64,
+    pub duration: Duration,
+    /// The limit that ended the generation, none if it ended otherwise (all the crosswords are generated or it was stopped).
+    pub limit_reached: Option<GenerationLimit>,
+}
+
+/// Represents the progress of a generation (check [CrosswordStream::progress]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct GenerationProgress
+{
+    /// The count of the orders of the words whose search [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized) started (check [max_permutations](CrosswordGeneratorSettings::max_permutations)), 0 for the other streams.
+    pub permutations_started: usize,
+    /// The count of the orders of the words whose search [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized) finished, 0 for the other streams.
+    pub permutations_completed: usize,
+    /// The count of the word placements tried while searching.
+    pub placements_tried: u64,
+    /// The count of the removed placements after searching all the crosswords with them.
+    pub backtracks: u64,
+    /// The count of the generated crosswords.
+    pub crosswords: usize,
+    /// The time since the start of the generation (till its end for a finished generation).
+    pub elapsed: Duration,
+}
+
+/// Represents the report of a generation that generated no crosswords, with the best partial result of the search (check [CrosswordStream::failure_report] and [CrosswordGenerator::failure_report]).
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::generator::CrosswordGenerator;
+/// # use crossword_generator::word::Word;
+/// let mut generator = CrosswordGenerator::<u8, &str>::default();
+/// generator.words = ["hello", "local", "xyz"].into_iter().map(|s| Word::new(s, None)).collect();
+/// 
+/// let report = generator.failure_report().unwrap();
+/// assert_eq!(report.unplaceable_words, vec!["xyz"]);
+/// assert_eq!(report.largest_crossword.len(), 2);
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct GenerationFailure<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    /// The crossword with the most words reached while searching (of the crosswords that can still meet the constraints).
+    pub largest_crossword: Crossword<CharT, StrT>,
+    /// The words that were never placed next to other words, empty if there is only one word.
+    pub unplaceable_words: Vec<StrT>,
+    /// The count of the placements of each word next to other words, ordered by the words.
+    pub word_placements: Vec<(StrT, u64)>,
+    /// The count of the crosswords rejected by each constraint (pruned while searching or not emitted after it), the most rejecting first.
+    pub constraint_rejections: Vec<(CrosswordConstraint<CharT>, u64)>,
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> GenerationFailure<CharT, StrT>
+{
+    /// Returns the constraint that rejected the most crosswords, none if no crossword was rejected by a constraint.
+    pub fn most_rejecting_constraint(&self) -> Option<&CrosswordConstraint<CharT>>
+    {
+        self.constraint_rejections.first().map(|(constraint, _)| constraint)
+    }
+}
+
+/// Represents a generated crossword with the words of the generator that were left out of it (check [CrosswordGenerator::generate_iter_with_unused_words] and [CrosswordGenerator::with_unused_words]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct GeneratedCrossword<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    pub crossword: Crossword<CharT, StrT>,
+    /// The words in the order of the words of the generator.
+    pub unused_words: Vec<Word<CharT, StrT>>,
+}
+
+/// Represents the order in which a [generator](CrosswordGenerator) tries the possible placements of a word, the first crosswords of a stream mostly differ in the first tried placements.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub enum PlacementOrder
+{
+    /// The order of the stream, sorted for [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted), shuffled with the seed of the stream for [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized).
+    #[default]
+    ByStream,
+    /// The order of the positions (top left placements first), or the order of the [placement strategy](CrosswordGeneratorSettings::placement_strategy).
+    Sorted,
+    /// A random order determined by the seed.
+    Shuffled(u64),
+    /// The placements with more intersections with the already placed words first.
+    Scored,
+}
+
+/// Represents the kind of stream created by [crossword_stream](CrosswordGenerator::crossword_stream).
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub enum GenerationStrategy
+{
+    /// The stream of [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted).
+    #[default]
+    Sorted,
+    /// The stream of [crossword_stream_randomized_seeded](CrosswordGenerator::crossword_stream_randomized_seeded) with the seed.
+    Randomized { seed: u64 },
+}
+
+/// Represents how the [generators](CrosswordGenerator) keep the generated crosswords for skipping the duplicates 
+/// (the crosswords of the randomized stream and the [canonical](Crossword::canonicalize) crosswords with [dedup_symmetric](CrosswordGeneratorSettings::dedup_symmetric)).
+/// 
+/// The crosswords are kept as their layouts, the positions and the directions of their words with the ids of the words, not as copies of the crosswords.
+/// The sorted generation also keeps the layouts of the crosswords it searched fully the same way, so it doesn't search them again with other orders of the words 
+/// (or, with [prune_containing_crosswords](CrosswordGeneratorSettings::prune_containing_crosswords), the smallest of those crosswords, which only [DedupMemoryLimit::Bounded] limits).
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub enum DedupMemoryLimit
+{
+    /// All the crosswords are kept, there are no duplicates, but the memory grows with every generated crossword.
+    KeepAll,
+    /// At most this many crosswords are kept, the least recently generated ones are dropped, so the duplicates of the dropped crosswords can be generated again.
+    /// 
+    /// The sorted generation keeps at most this many fully searched crosswords too, dropping the least recently searched ones 
+    /// (or the ones with the most words with [prune_containing_crosswords](CrosswordGeneratorSettings::prune_containing_crosswords), they skip the least of the search).
+    Bounded(usize),
+    /// Only 128 bit hashes of the crosswords are kept, so a crossword with the same hash as an earlier one is skipped (practically never happens).
+    #[default]
+    HashOnly,
+}
+
+/// Represents the limits of the search of [best_crosswords](CrosswordGenerator::best_crosswords), the search ends when any of them is reached, there are no limits by default.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use std::time::Duration;
+/// # use crossword_generator::generator::GenerationBudget;
+/// let budget = GenerationBudget::new().max_candidates(1000).max_duration(Duration::from_secs(1));
+/// assert_eq!(budget.max_candidates, Some(1000));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct GenerationBudget
+{
+    /// The maximum count of generated crosswords to choose from.
+    pub max_candidates: Option<usize>,
+    /// The maximum duration of the search.
+    pub max_duration: Option<Duration>,
+}
+
+impl GenerationBudget
+{
+    /// Creates a budget without limits.
+    pub fn new() -> GenerationBudget
+    {
+        GenerationBudget::default()
+    }
+
+    /// Returns the budget with the limit of the count of the generated crosswords.
+    pub fn max_candidates(mut self, max_candidates: usize) -> GenerationBudget
+    {
+        self.max_candidates = Some(max_candidates);
+        self
+    }
+
+    /// Returns the budget with the limit of the duration of the search.
+    pub fn max_duration(mut self, max_duration: Duration) -> GenerationBudget
+    {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    fn is_exhausted(&self, start: Instant, candidates: usize) -> bool
+    {
+        self.max_candidates.is_some_and(|max| candidates >= max) || self.max_duration.is_some_and(|max| start.elapsed() >= max)
+    }
+}
+
+/// Represents a crossword generator.
+/// 
+/// Generates the crosswords synchronously (check [generate_iter](CrosswordGenerator::generate_iter)), 
+/// or in async streams with the "async" feature (check [crossword_stream_sorted_with_spawner](CrosswordGenerator::crossword_stream_sorted_with_spawner)), 
+/// the streams are spawned on tokio with the default "tokio" feature (check [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted)).
+/// 
+/// # Example
+/// ```
+/// use crossword_generator::generator::{CrosswordGenerator, CrosswordGeneratorSettings, CrosswordGenerationRequest};
+/// use crossword_generator::crossword::Crossword;
+/// use crossword_generator::placed_word::PlacedWord;
+/// use crossword_generator::word::{Direction, Position, Word};
+/// 
+/// use tokio_stream::StreamExt;
+/// 
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() 
+/// {
+/// 
+///     let mut generator = CrosswordGenerator::<u8, String>::default();
+///     generator.settings = CrosswordGeneratorSettings::default();
+///     generator.words = vec!["Hello", "world"].into_iter().map(|s| Word::new(s.to_lowercase(), None)).collect();
+///      
+///     let str = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap());
+///     str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+///     str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+///     let crosswords: Vec<Crossword<u8, String>> = str.collect().await;
+///     
+///     let mut cw1 = Crossword::default();
+///     let mut cw2 = Crossword::default();
+/// 
+///     cw1.add_words([PlacedWord::new("hello".to_owned(), Position{ x: 0, y: 3 }, Direction::Right),
+///                    PlacedWord::new("world".to_owned(), Position{ x: 2, y: 0 }, Direction::Down)].into_iter()).unwrap();
+///     
+///     cw2.add_words([PlacedWord::new("hello".to_owned(), Position{ x: 0, y: 3 }, Direction::Right),
+///                    PlacedWord::new("world".to_owned(), Position{ x: 3, y: 0 }, Direction::Down)].into_iter()).unwrap();
+/// 
+///     assert_eq!(crosswords, vec![cw1, cw2])
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct CrosswordGenerator<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    pub words: BTreeSet<Word<CharT, StrT>>,
+    pub settings: CrosswordGeneratorSettings<CharT>,
+    /// The crossword the generation starts from (check [with_seed_crossword](CrosswordGenerator::with_seed_crossword)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<Crossword<CharT, StrT>>,
+}
+
+// not derived, because strings don't need to implement Default (check CrosswordString)
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Default for CrosswordGenerator<CharT, StrT>
+{
+    fn default() -> Self 
+    {
+        CrosswordGenerator { words: BTreeSet::new(), settings: CrosswordGeneratorSettings::default(), seed: None }
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordGenerator<CharT, StrT>
+{
+    /// Returns the words that can't be placed in a crossword (check [Word::validate]) with the reasons, the generator skips them.
+    pub fn invalid_words(&self) -> Vec<(&Word<CharT, StrT>, WordError)>
+    {
+        self.words.iter().filter_map(|w| w.validate().err().map(|err| (w, err))).collect()
+    }
+
+    fn valid_words(&self) -> impl Iterator<Item = &Word<CharT, StrT>>
+    {
+        self.words.iter().filter(|w| w.validate().is_ok())
+    }
+
+    /// Returns the generator with the seed crossword, the generated crosswords are the crosswords that contain all its words, at the same positions relative to each other.
+    /// 
+    /// The words of the seed are never moved or removed, the words of the generator they contain are not placed again (so they are [required](Word::required)), 
+    /// and the other words are placed around them by the settings of the generator. The seed crossword is checked with the settings too, 
+    /// if its words are not compatible by them, no crosswords are generated. The [template](crate::template::GridTemplate) of the seed is ignored.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position, Word};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// let mut seed = Crossword::default();
+    /// seed.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// 
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default().with_seed_crossword(seed.clone());
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// 
+    /// assert!(generator.generate_iter().all(|cw| cw.contains_crossword(&seed)));
+    /// ```
+    pub fn with_seed_crossword(mut self, seed: Crossword<CharT, StrT>) -> CrosswordGenerator<CharT, StrT>
+    {
+        self.seed = Some(seed);
+        self
+    }
+
+    // the words the search places, the valid words that are not in the seed crossword
+    fn search_words(&self) -> impl Iterator<Item = &Word<CharT, StrT>>
+    {
+        self.valid_words().filter(|w| self.seed.as_ref().is_none_or(|seed| seed.find_word(&w.value).is_none()))
+    }
+
+    // the crossword the search starts from, the seed crossword with the settings of the generator (none if its words are not compatible by them)
+    fn seed_crossword<'a, S: CrosswordString<CharT>>(&'a self, convert_f: impl Fn(&'a StrT) -> S) -> Option<Crossword<CharT, S>>
+    {
+        let words = self.seed.iter().flat_map(|seed| seed.iter()).map(|w| PlacedWord::new(convert_f(&w.value), w.position.clone(), w.direction.clone()).with_meta(w.meta.clone()));
+        Crossword::from_placed_words(words, self.settings.word_compatibility_settings.clone()).ok()
+    }
+
+    // the words that the placements of a checkpoint refer to, the search words and the words of the seed crossword
+    fn checkpoint_words(&self) -> Vec<Word<CharT, &[CharT]>>
+    {
+        let seed_words = self.seed.iter().flat_map(|seed| seed.iter()).map(|w| Word::new(w.value.as_ref(), None).with_meta(w.meta.clone()));
+        self.search_words().map(|w| w.with_value(w.value.as_ref())).chain(seed_words).collect()
+    }
+
+    // a crossword can be emitted without the remaining words, if all of them are optional (or can't be added to the crossword, if unplaced words are allowed), 
+    // and either subsets are emitted or none of them can be added to the crossword (an empty crossword is emitted only if there are no words at all)
+    fn can_be_emitted_without<'b, S: CrosswordString<CharT> + 'b>(gen_settings: &CrosswordGeneratorSettings<CharT>, crossword: &Crossword<CharT, S>, view: Option<&Crossword<CharT, &[CharT]>>, mut remaining_words: impl Iterator<Item = &'b Word<CharT, S>> + Clone) -> bool
+    where
+        CharT: 'b
+    {
+        let Some(_) = remaining_words.clone().next() else { return true; };
+        let converted = if view.is_none() { Self::strategy_view(gen_settings, crossword) } else { None };
+        let view = view.or(converted.as_ref());
+        let unplaceable = |w: &Word<CharT, S>| Self::candidate_placements(gen_settings, crossword, view, w).is_empty();
+        !crossword.is_empty() && 
+        remaining_words.clone().all(|w| !w.required || (gen_settings.allow_unplaced_words && unplaceable(w))) && 
+        (gen_settings.emit_subsets || remaining_words.all(unplaceable))
+    }
+
+    // the counts of the horizontal and the vertical words of the crossword can differ by at most max_direction_skew after adding some of the remaining words 
+    // (all the required ones, unless unplaced words are allowed), every remaining word can make the difference one bigger or smaller, or only one of them if its direction is fixed
+    fn can_be_balanced<'b, S: CrosswordString<CharT> + 'b>(gen_settings: &CrosswordGeneratorSettings<CharT>, crossword: &Crossword<CharT, S>, remaining_words: impl Iterator<Item = &'b Word<CharT, S>>) -> bool
+    where
+        CharT: 'b
+    {
+        let Some(skew) = gen_settings.max_direction_skew else { return true; };
+        let difference = crossword.iter().map(|w| if w.direction.is_horizontal() { 1 } else { -1 }).sum::<isize>();
+        let (min, max) = remaining_words.fold((difference, difference), |(min, max), w|
+        {
+            let (low, high) = match &w.dir { Some(dir) if dir.is_horizontal() => (1, 1), Some(_) => (-1, -1), None => (-1, 1) };
+            if w.required && !gen_settings.allow_unplaced_words { (min + low, max + high) } else { (min + low.min(0), max + high.max(0)) }
+        });
+        min <= skew as isize && max >= -(skew as isize)
+    }
+
+    /// Returns the crossword with the valid words of the generator that are not in it, for the crosswords generated with optional words or with [allow_unplaced_words](CrosswordGeneratorSettings::allow_unplaced_words).
+    /// 
+    /// The left out words are determined by the words of the crossword, so crosswords with the same words have the same left out words (and are duplicates for the generators), 
+    /// every word of the crossword is matched with one word of the generator with its value (the one with its direction, if there are more), the other ones are left out.
+    /// The iterator of [generate_iter_with_unused_words](CrosswordGenerator::generate_iter_with_unused_words) gives the words that the search left out instead.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "xyz"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// assert_eq!(generator.generate_iter().count(), 0);
+    /// 
+    /// // xyz has no common characters with the other words
+    /// generator.settings.allow_unplaced_words = true;
+    /// let generated = generator.with_unused_words(generator.generate_iter().next().unwrap());
+    /// assert_eq!(generated.crossword.len(), 2);
+    /// assert_eq!(generated.unused_words, vec![Word::new("xyz", None)]);
+    /// ```
+    pub fn with_unused_words(&self, crossword: Crossword<CharT, StrT>) -> GeneratedCrossword<CharT, StrT>
+    {
+        let mut placed = crossword.iter().collect::<Vec<_>>();
+        let mut used = vec![false; self.words.len()];
+        // the words with the directions of their placements are matched first
+        for same_dir in [true, false]
+        {
+            for (ind, word) in self.valid_words().enumerate()
+            {
+                let matches = |p: &&PlacedWord<CharT, StrT>| p.value == word.value && (!same_dir || word.dir.as_ref() == Some(&p.direction));
+                if used[ind] { continue; }
+                if let Some(pos) = placed.iter().position(matches)
+                {
+                    placed.swap_remove(pos);
+                    used[ind] = true;
+                }
+            }
+        }
+        let unused_words = self.valid_words().zip(used).filter(|(_, used)| !used).map(|(w, _)| w.clone()).collect();
+        GeneratedCrossword { crossword, unused_words }
+    }
+
+    // the crossword with slices for the placement strategy of the settings (if there is one), so it's converted once for all the words tried on it
+    fn strategy_view<'b, S: CrosswordString<CharT>>(gen_settings: &CrosswordGeneratorSettings<CharT>, crossword: &'b Crossword<CharT, S>) -> Option<Crossword<CharT, &'b [CharT]>>
+    {
+        gen_settings.placement_strategy.as_ref().map(|_| crossword.as_slices())
+    }
+
+    // the placements of the placement strategy of the settings, the strategy gets the view of the crossword with slices (the crossword is converted if there is no view), 
+    // and the values of the placements are the value of the word
+    fn candidate_placements<S: CrosswordString<CharT>>(gen_settings: &CrosswordGeneratorSettings<CharT>, crossword: &Crossword<CharT, S>, view: Option<&Crossword<CharT, &[CharT]>>, word: &Word<CharT, S>) -> Vec<PlacedWord<CharT, S>>
+    {
+        let Some(strategy) = &gen_settings.placement_strategy else { return PossiblePlacements.candidate_placements(crossword, word); };
+        let converted;
+        let view = match view
+        {
+            Some(view) => view,
+            None => { converted = crossword.as_slices(); &converted }
+        };
+        strategy.0.candidate_placements(view, &word.with_value(word.value.as_ref())).into_iter()
+            .map(|placement| PlacedWord::new(word.value.clone(), placement.position, placement.direction).with_meta(placement.meta))
+            .collect()
+    }
+
+    // returns the possible placements of the word in the order of the settings
+    fn ordered_placements<S: CrosswordString<CharT>>(gen_settings: &CrosswordGeneratorSettings<CharT>, crossword: &Crossword<CharT, S>, view: Option<&Crossword<CharT, &[CharT]>>, word: &Word<CharT, S>, shuffled_by_stream: bool, rng: &mut GeneratorRng) -> Vec<PlacedWord<CharT, S>>
+    {
+        let mut placements = Self::candidate_placements(gen_settings, crossword, view, word);
+        match gen_settings.placement_order
+        {
+            PlacementOrder::ByStream if shuffled_by_stream => rng.shuffle(&mut placements),
+            PlacementOrder::ByStream | PlacementOrder::Sorted => {},
+            PlacementOrder::Shuffled(_) => rng.shuffle(&mut placements),
+            PlacementOrder::Scored => placements.sort_by_cached_key(|placement| Reverse(crossword.intersection_count(placement))),
+        }
+        placements
+    }
+
+    /// Returns the stream of the strategy, the words of the crosswords are converted from &\[CharT\] with [From] (check [crossword_stream_with](CrosswordGenerator::crossword_stream_with) for other conversions).
+    /// 
+    /// Runs on tokio, the streams of the strategies have variants for other executors.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerationRequest, CrosswordGenerator, GenerationStrategy};
+    /// # use crossword_generator::word::Word;
+    /// # use tokio_stream::StreamExt;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main()
+    /// # {
+    /// let mut generator = CrosswordGenerator::<u8, Vec<u8>>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s.as_bytes().to_vec(), None)).collect();
+    /// 
+    /// for strategy in [GenerationStrategy::Sorted, GenerationStrategy::Randomized { seed: 42 }]
+    /// {
+    ///     let mut str = generator.crossword_stream(strategy);
+    ///     str.request_crossword(CrosswordGenerationRequest::Count(1)).await.unwrap();
+    ///     assert_eq!(str.next().await.unwrap().len(), 3);
+    /// 
+    ///     // the same without the request
+    ///     let mut str = generator.crossword_stream(strategy).into_auto();
+    ///     assert_eq!(str.next().await.unwrap().len(), 3);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn crossword_stream(&self, strategy: GenerationStrategy) -> CrosswordStream<CharT, StrT> where
+        StrT: for<'a> From<&'a [CharT]>
+    {
+        self.crossword_stream_with(strategy, |w: &[CharT]| StrT::from(w))
+    }
+
+    /// Same as [crossword_stream](CrosswordGenerator::crossword_stream), but the words are converted with the function (check [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted)).
+    #[cfg(feature = "tokio")]
+    pub fn crossword_stream_with<F>(&self, strategy: GenerationStrategy, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {
+        match strategy
+        {
+            GenerationStrategy::Sorted => self.crossword_stream_sorted(convert_f),
+            GenerationStrategy::Randomized { seed } => self.crossword_stream_randomized_seeded(seed, convert_f),
+        }
+    }
+
+    /// Takes a function to convert from &\[CharT\] to StrT, because the generator generates crosswords with words with type &\[CharT\] to prevent unnecessary copying
+    /// (it's called at most once for every word, the generated crosswords get clones of the converted values).
+    /// Invalid words are skipped (check [invalid_words](CrosswordGenerator::invalid_words)).
+    /// Words with bigger [weights](Word::weight) are placed first.
+    /// Slow, but crosswords are in a random order, with a different order on every call (check [crossword_stream_randomized_seeded](CrosswordGenerator::crossword_stream_randomized_seeded) for a reproducible one).
+    /// If you need fast generation, check [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted).
+    /// 
+    /// Runs on tokio, check [crossword_stream_randomized_with_spawner](CrosswordGenerator::crossword_stream_randomized_with_spawner) for other executors.
+    #[cfg(feature = "tokio")]
+    pub fn crossword_stream_randomized<F>(&self, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {  
+        self.crossword_stream_randomized_seeded(GeneratorRng::entropy_seed(), convert_f)
+    }
+
+    /// Same as [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized), but the order of the crosswords is determined by the seed.
+    /// 
+    /// The same seed with the same words and settings gives the same sequence of crosswords on every run and platform, 
+    /// if the stream runs on a single threaded runtime (on a multi threaded one the generation tasks can interleave differently).
+    #[cfg(feature = "tokio")]
+    pub fn crossword_stream_randomized_seeded<F>(&self, seed: u64, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {  
+        self.crossword_stream_randomized_with_spawner(seed, tokio_spawner, convert_f)
+    }
+
+    /// Same as [crossword_stream_randomized_seeded](CrosswordGenerator::crossword_stream_randomized_seeded), but the stream and the generation tasks (check [max_concurrent_tasks](CrosswordGeneratorSettings::max_concurrent_tasks)) are spawned with the spawner, so any executor can be used.
+    #[cfg(feature = "async")]
+    pub fn crossword_stream_randomized_with_spawner<S, F>(&self, seed: u64, spawner: S, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        S: Fn(BoxFuture<'static, ()>) + Send + Sync + 'static,
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {  
+        let gen = self.clone();
+        let spawner = Arc::new(spawner);
+        let task_spawner = spawner.clone();
+        
+        let gen_func = move |rr: Receiver<CrosswordGenerationRequest>, mut cs: Sender<Vec<Crossword<CharT, StrT>>>, control: Arc<StreamControl<CharT, StrT>>| async move
+        {
+            let tracker = Arc::new(GenerationTracker::new(gen.settings.limits));
+            let _ = control.tracker.set(tracker.clone());
+            // the tasks collect their diagnostics separately, and merge them when they finish
+            let diagnostics = Arc::new(Mutex::new(None::<FailureDiagnostics<CharT, Arc<[CharT]>>>));
+            let rr = Arc::new(Mutex::new(rr));
+            let current_request = Arc::new(Mutex::new(CrosswordGenerationRequest::Count(0)));
+            // shared by the tasks, so the last requested crosswords are sent by the task that finds the last one
+            let batch = Arc::new(Mutex::new(CrosswordBatch::new(gen.settings.emit_batch_size)));
+            let created_crosswords = Arc::new(Mutex::new(CreatedCrosswords::new(gen.settings.dedup_memory_limit)));
+            let emitted_crosswords = Arc::new(Mutex::new(EmittedCrosswords::new(gen.settings.min_difference)));
+
+            let mut rng = GeneratorRng::new(seed);
+            // with a seeded placement order the placements are shuffled with it, but the orders of the words are still drawn with the seed of the stream
+            let placement_rng = match gen.settings.placement_order { PlacementOrder::Shuffled(seed) => Some(GeneratorRng::new(seed)), _ => None };
+            let interner = Arc::new(WordInterner::new(gen.checkpoint_words().into_iter().map(|w| w.value)));
+            let seed_crossword = Arc::new(gen.seed_crossword(|w| interner.intern(w.as_ref())));
+            let mut words = gen.search_words().map(|w| w.with_value(interner.intern(w.value.as_ref()))).collect::<Vec<_>>();
+            rng.shuffle(&mut words);
+            
+            // the sort is stable, so words with the same weight stay shuffled
+            let words = words.into_iter().sorted_by_key(|w| Reverse(w.weight)).collect::<Vec<_>>();
+            // the orders of the words share their values, so the ids are found by the addresses
+            let seed_words = seed_crossword.iter().flat_map(|seed| seed.iter()).map(|w| w.value.clone());
+            let word_ids = Arc::new(WordIds::new(words.iter().map(|w| w.value.clone()).chain(seed_words)));
+            let sampler = Arc::new(Mutex::new(PermutationSampler::new(words, |w| Reverse(w.weight), gen.settings.max_permutations, rng, placement_rng)));
+
+            // creating max_concurrent_tasks tasks, each of them searches the orders of the words drawn by the sampler until there are no more or the generation stops
+            // (there are no crosswords with an invalid seed crossword)
+            let mut tasks = FuturesUnordered::new();
+            for _ in 0..if seed_crossword.is_some() { gen.settings.max_concurrent_tasks.max(1) } else { 0 }
+            {
+                let settings = gen.settings.clone();
+                let receiver = rr.clone(); 
+                let mut task_cs = cs.clone();
+                let cr = current_request.clone();
+                let task_batch = batch.clone();
+                let task_sampler = sampler.clone();
+                let task_seed = seed_crossword.clone();
+                let ccs = created_crosswords.clone();
+                let task_word_ids = word_ids.clone();
+                let task_interner = interner.clone();
+                let ecs = emitted_crosswords.clone();
+                let cfr = convert_f.clone();
+                let task_control = control.clone();
+                let task_tracker = tracker.clone();
+                let task_diagnostics = diagnostics.clone();
+
+                //creating and spawning the task, the receiver completes when the task is finished
+                let (finished_sender, finished) = oneshot::channel();
+                task_spawner(Box::pin(async move 
+                {
+                    loop
+                    {
+                        if is_stopped(&task_cs, &task_control) || task_tracker.is_reached() { break; }
+                        if let CrosswordGenerationRequest::Stop = *cr.lock().await { break; }
+                        let Some((ws, mut task_rng)) = task_sampler.lock().await.next() else { break; };
+
+                        task_tracker.permutations_started.fetch_add(1, Ordering::Relaxed);
+                        let mut diagnostics = settings.failure_report.then(|| FailureDiagnostics::new(ws.iter().map(|w| w.value.clone()), settings.word_compatibility_settings.clone()));
+                        CrosswordGenerator::<CharT, StrT>::randomized_generator_impl(&settings, &receiver, &mut task_cs, &cr, &task_batch, task_seed.as_ref().as_ref().unwrap(), &ws, &task_word_ids, &ccs, &ecs, &mut task_rng, &task_control, &task_tracker, &mut diagnostics, &task_interner, &cfr).await; 
+                        if let Some(diagnostics) = diagnostics
+                        {
+                            let mut merged = task_diagnostics.lock().await;
+                            match merged.as_mut()
+                            {
+                                Some(merged) => merged.merge(diagnostics),
+                                None => *merged = Some(diagnostics),
+                            }
+                        }
+                        task_tracker.permutations_completed.fetch_add(1, Ordering::Relaxed);
+                    }
+                    let _ = finished_sender.send(());
+                }));
+                tasks.push(finished);
+            }
+
+            while tasks.next().await.is_some() {}
+            batch.lock().await.flush(&mut cs).await;
+            if !tracker.has_crosswords()
+            {
+                if let Some(diagnostics) = diagnostics.lock().await.take() { let _ = control.failure.set(diagnostics.into_report(|w| interner.convert(w, &convert_f))); }
+            }
+            let stopped = *current_request.lock().await == CrosswordGenerationRequest::Stop;
+            control.finish(stopped, cs.is_closed(), &tracker);
+            let _ = control.summary.set(tracker.summary());
+        };
+
+        let (crossword_buffer, request_buffer) = (self.settings.crossword_buffer.div_ceil(self.settings.emit_batch_size.max(1)), self.settings.request_buffer);
+        CrosswordStream::new_stoppable(|future| spawner(future), crossword_buffer, request_buffer, gen_func)
+    }
+
+    // the search of a task of the randomized stream with an explicit stack (the node of the word with the index i is in the stack at i), so the words can be as many as needed
+    #[cfg(feature = "async")]
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    async fn randomized_generator_impl<F>(gen_settings: &CrosswordGeneratorSettings<CharT>, rr: &Mutex<Receiver<CrosswordGenerationRequest>>, cs: &mut Sender<Vec<Crossword<CharT, StrT>>>, current_request: &Mutex<CrosswordGenerationRequest>, batch: &Mutex<CrosswordBatch<CharT, StrT>>, seed_crossword: &Crossword<CharT, Arc<[CharT]>>, words: &[Word<CharT, Arc<[CharT]>>], word_ids: &WordIds<CharT, Arc<[CharT]>>, created_crosswords: &Mutex<CreatedCrosswords>, emitted_crosswords: &Mutex<EmittedCrosswords<CharT, Arc<[CharT]>>>, rng: &mut GeneratorRng, control: &StreamControl<CharT, StrT>, tracker: &GenerationTracker, diagnostics: &mut Option<FailureDiagnostics<CharT, Arc<[CharT]>>>, interner: &WordInterner<CharT, StrT>, convert_f: &F) where  
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {
+        let mut current_crossword = seed_crossword.clone();
+        let mut stack: Vec<RandomizedGenerationFrame<CharT>> = vec![];
+        let mut yielder = Yielder::counted();
+        // the node of the current crossword must be entered
+        let mut enter = true;
+        loop
+        {
+            if enter
+            {
+                enter = false;
+                yielder.maybe_yield().await;
+                if is_stopped(cs, control) || tracker.is_reached() { return; }
+                if control.is_paused()
+                {
+                    let mut current_request = current_request.lock().await;
+                    if !batch.lock().await.flush(cs).await { return; }
+                    wait_for_requests(&mut *rr.lock().await, &mut current_request, control, false, || {}).await;
+                    if let CrosswordGenerationRequest::Stop = *current_request { return; }
+                }
+
+                let current_word_ind = stack.len();
+                let remaining_words = words[current_word_ind..].iter().map(|w| w.value.as_ref());
+                let view = ConstraintView::new();
+                if gen_settings.crossword_settings.failed_nonrecoverable_constraint_in(&current_crossword, &view).is_some() ||
+                   !gen_settings.crossword_settings.check_required_words(&current_crossword, remaining_words.clone()) ||
+                   !CrosswordGenerator::<CharT, StrT>::can_be_balanced(gen_settings, &current_crossword, words[current_word_ind..].iter())
+                {
+                    if let Some(diagnostics) = diagnostics
+                    {
+                        let settings = &gen_settings.crossword_settings;
+                        diagnostics.add_rejection(settings.failed_nonrecoverable_constraint_in(&current_crossword, &view).or_else(|| settings.failed_required_word(&current_crossword, remaining_words)));
+                    }
+                }
+                else if current_word_ind < words.len()
+                {
+                    if let Some(diagnostics) = diagnostics { diagnostics.add_reached(&current_crossword); }
+                    let word = &words[current_word_ind];
+                    let mut placements = CrosswordGenerator::<CharT, StrT>::ordered_placements(gen_settings, &current_crossword, None, word, true, rng);
+                    placements.reverse();
+                    // a required word without placements can be left out only if unplaced words are allowed
+                    let skippable = !word.required || (gen_settings.allow_unplaced_words && placements.is_empty());
+                    stack.push(RandomizedGenerationFrame { placements, placed: None, skippable });
+                }
+                else
+                {
+                    if let Some(diagnostics) = diagnostics { diagnostics.add_reached(&current_crossword); }
+                    let skipped_words = words.iter().filter(|w| current_crossword.find_word(&w.value).is_none());
+                    if CrosswordGenerator::<CharT, StrT>::can_be_emitted_without(gen_settings, &current_crossword, None, skipped_words)
+                    {
+                        if let Some(failed) = gen_settings.crossword_settings.failed_recoverable_constraint_in(&current_crossword, &view)
+                        {
+                            if let Some(diagnostics) = diagnostics { diagnostics.add_rejection(Some(failed)); }
+                        }
+                        else if gen_settings.require_rotational_symmetry && !current_crossword.is_rotationally_symmetric()
+                        {
+                            if let Some(diagnostics) = diagnostics { diagnostics.add_rejection(Some(&CrosswordConstraint::RotationalSymmetry)); }
+                        }
+                        else if !CrosswordGenerator::<CharT, StrT>::can_be_balanced(gen_settings, &current_crossword, std::iter::empty()) ||
+                                gen_settings.emit_filter.as_ref().is_some_and(|filter| !(filter.0)(&current_crossword.normalized())) {}
+                        else if created_crosswords.lock().await.insert(if gen_settings.dedup_symmetric { word_ids.fingerprint(&current_crossword.canonicalize()) } else { word_ids.fingerprint(&current_crossword) }) &&
+                                emitted_crosswords.lock().await.offer(&current_crossword)
+                        {
+                            let mut current_request = current_request.lock().await;
+                            let mut batch = batch.lock().await;
+                            if waits_for_requests(&current_request, control) && !batch.flush(cs).await { return; }
+                            wait_for_requests(&mut *rr.lock().await, &mut current_request, control, true, || {}).await;
+                
+                            // all the sends are under the lock of the request, so no more crosswords than the limit are sent
+                            if let CrosswordGenerationRequest::Stop = *current_request { return; }
+                            if tracker.is_reached() { return; }
+
+                            // the stream is dropped
+                            if !batch.push(cs, current_crossword.normalized().convert_to(|w| interner.convert(&w, convert_f))).await
+                            {
+                                *current_request = CrosswordGenerationRequest::Stop;
+                                return;
+                            }
+                            if let CrosswordGenerationRequest::Count(count) = *current_request { *current_request = CrosswordGenerationRequest::Count(count - 1) }
+                            control.set_current_requested(&current_request);
+                            tracker.add_crossword();
+                            if waits_for_requests(&current_request, control) && !batch.flush(cs).await 
+                            { 
+                                *current_request = CrosswordGenerationRequest::Stop;
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+
+            let Some(frame) = stack.last_mut() else { return; };
+
+            // the subtree of the placement is searched
+            if let Some(placed) = frame.placed.take()
+            {
+                if is_stopped(cs, control) || tracker.is_reached() { return; }
+                if let CrosswordGenerationRequest::Stop = *current_request.lock().await { return; }
+
+                current_crossword.remove_word_unnormalized(&placed);
+                tracker.add_backtrack();
+            }
+
+            if let Some(step) = frame.placements.pop()
+            {
+                tracker.add_placement();
+                let placed = step.value.clone();
+                current_crossword.add_word_unnormalized(step).unwrap();
+                if let Some(diagnostics) = diagnostics { diagnostics.add_placement(&current_crossword, &placed); }
+                frame.placed = Some(placed);
+                enter = true;
+            }
+            else if frame.skippable
+            {
+                frame.skippable = false;
+                enter = true;
+            }
+            else 
+            {
+                stack.pop();
+            }
+        }
+    }
+
+
+    /// Takes a function to convert from &\[CharT\] to StrT, because the generator generates crosswords with words with type &\[CharT\] to prevent unnecessary copying
+    /// (it's called at most once for every word, the generated crosswords get clones of the converted values).
+    /// Invalid words are skipped (check [invalid_words](CrosswordGenerator::invalid_words)).
+    /// Words with bigger [weights](Word::weight) are placed first.
+    /// Fast, but crosswords in a non random order, consecutive crosswords are pretty similar.
+    /// If you need randomized results, check [crossword_stream_randomized](CrosswordGenerator::crossword_stream_randomized).
+    /// 
+    /// Generates the same crosswords in the same order as [generate_iter](CrosswordGenerator::generate_iter).
+    /// 
+    /// Runs on tokio, check [crossword_stream_sorted_with_spawner](CrosswordGenerator::crossword_stream_sorted_with_spawner) for other executors.
+    #[cfg(feature = "tokio")]
+    pub fn crossword_stream_sorted<F>(&self, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {  
+        self.crossword_stream_sorted_with_spawner(tokio_spawner, convert_f)
+    }
+
+    /// Same as [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted), but the stream is spawned with the spawner, so any executor can be used.
+    #[cfg(feature = "async")]
+    pub fn crossword_stream_sorted_with_spawner<F>(&self, spawner: impl FnOnce(BoxFuture<'static, ()>), convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {  
+        self.sorted_stream(spawner, None, convert_f)
+    }
+
+    /// Returns a stream that continues the generation of the checkpoint (check [CrosswordStream::checkpoint]), 
+    /// it gives the crosswords of the checkpoint that were not taken from the checkpointed stream first, and then the crosswords that come after them.
+    /// 
+    /// The words of the generator must be the words of the checkpoint, and the settings that change the generated crosswords must be the same 
+    /// ([crossword_settings](CrosswordGeneratorSettings::crossword_settings), [word_compatibility_settings](CrosswordGeneratorSettings::word_compatibility_settings), 
+    /// [dedup_symmetric](CrosswordGeneratorSettings::dedup_symmetric), [emit_subsets](CrosswordGeneratorSettings::emit_subsets) and [placement_order](CrosswordGeneratorSettings::placement_order)), 
+    /// the other settings can differ, the limits of the resumed stream are counted from the resume.
+    /// 
+    /// Runs on tokio, check [resume_stream_with_spawner](CrosswordGenerator::resume_stream_with_spawner) for other executors.
+    /// 
+    /// # Errors
+    /// 
+    /// [ResumeError::WordsMismatch] - The words of the generator are not the words of the checkpoint.
+    /// 
+    /// [ResumeError::SettingsMismatch] - The settings of the generator differ from the settings of the checkpoint.
+    /// 
+    /// [ResumeError::InvalidCheckpoint] - The state of the search in the checkpoint is not valid (for example it's modified).
+    #[cfg(feature = "tokio")]
+    pub fn resume_stream<F>(&self, checkpoint: GeneratorCheckpoint<CharT, StrT>, convert_f: F) -> Result<CrosswordStream<CharT, StrT>, ResumeError> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {
+        self.resume_stream_with_spawner(checkpoint, tokio_spawner, convert_f)
+    }
+
+    /// Same as [resume_stream](CrosswordGenerator::resume_stream), but the stream is spawned with the spawner, so any executor can be used.
+    #[cfg(feature = "async")]
+    pub fn resume_stream_with_spawner<F>(&self, checkpoint: GeneratorCheckpoint<CharT, StrT>, spawner: impl FnOnce(BoxFuture<'static, ()>), convert_f: F) -> Result<CrosswordStream<CharT, StrT>, ResumeError> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {
+        if checkpoint.words_hash != self.words_hash() { return Err(ResumeError::WordsMismatch); }
+        if !checkpoint.settings.same_search(&self.settings) { return Err(ResumeError::SettingsMismatch); }
+        SortedGeneration::from_state(self, &checkpoint.state)?;
+
+        let mut stream = self.sorted_stream(spawner, Some(checkpoint.state), convert_f);
+        stream.queue = checkpoint.pending.into();
+        Ok(stream)
+    }
+
+    // the hash of the valid words, the same for the same words in every run
+    #[cfg(feature = "async")]
+    fn words_hash(&self) -> u64
+    {
+        let mut hasher = StableHasher::default();
+        self.valid_words().for_each(|w| w.with_value(w.value.as_ref()).hash(&mut hasher));
+        if let Some(seed) = &self.seed { seed.as_slices().hash(&mut hasher); }
+        hasher.finish()
+    }
+
+    // the sorted stream, it continues the search from the state, if there is one (it must be a valid state for the generator)
+    #[cfg(feature = "async")]
+    fn sorted_stream<F>(&self, spawner: impl FnOnce(BoxFuture<'static, ()>), state: Option<SortedGenerationState>, convert_f: F) -> CrosswordStream<CharT, StrT> where
+        F: Fn(&[CharT]) -> StrT,
+        F: Send + Sync + 'static
+    {  
+        let gen = self.clone();
+        let checkpoint_base = (self.words_hash(), self.settings.clone());
+        
+        let gen_func = move |mut rr: Receiver<CrosswordGenerationRequest>, mut cs: Sender<Vec<Crossword<CharT, StrT>>>, control: Arc<StreamControl<CharT, StrT>>| 
+        {
+            // set before the stream is returned, so it can be checkpointed right away
+            let _ = control.checkpoint_base.set(checkpoint_base);
+            async move
+            {
+                let mut current_request = CrosswordGenerationRequest::Count(0);
+                let (closed, cancellation_control) = (cs.clone(), control.clone());
+                let generation = match &state
+                {
+                    Some(state) => SortedGeneration::from_state(&gen, state).expect("The state is checked before the stream is created"),
+                    None => SortedGeneration::new(&gen),
+                };
+                let mut generation = generation.with_cancellation(move || is_stopped(&closed, &cancellation_control));
+                if gen.settings.failure_report { generation = generation.with_diagnostics(); }
+                let _ = control.tracker.set(generation.tracker.clone());
+
+                let words = gen.checkpoint_words();
+                let interner = WordInterner::new(words.iter().map(|w| w.value));
+                let convert = |w: &[CharT]| interner.convert(w, &convert_f);
+                let snapshot = |generation: &SortedGeneration<CharT>, sent: usize, held: Option<&Crossword<CharT, &[CharT]>>| CheckpointSnapshot
+                { 
+                    state: generation.state(&words), 
+                    sent, 
+                    held: held.map(|crossword| crossword.clone().convert_to(convert)) 
+                };
+                // the count of the sent crosswords (the collected ones are sent before every snapshot), and the found crossword that wasn't sent because of a stop
+                let (mut sent, mut held) = (0, None);
+                let mut batch = CrosswordBatch::new(gen.settings.emit_batch_size);
+                let mut yielder = Yielder::timed();
+                loop
+                {
+                    if control.checkpoint_requested.load(Ordering::Relaxed) && !batch.flush(&mut cs).await { break; }
+                    control.answer_checkpoint(|| snapshot(&generation, sent, None));
+                    if control.is_paused()
+                    {
+                        if !batch.flush(&mut cs).await { break; }
+                        wait_for_requests(&mut rr, &mut current_request, &control, false, || control.answer_checkpoint(|| snapshot(&generation, sent, None))).await;
+                        if let CrosswordGenerationRequest::Stop = current_request { break; }
+                    }
+
+                    let crossword = match generation.step()
+                    {
+                        Poll::Ready(Some(crossword)) => crossword,
+                        Poll::Ready(None) => break,
+                        Poll::Pending => 
+                        {
+                            yielder.maybe_yield().await;
+                            continue;
+                        }
+                    };
+
+                    if waits_for_requests(&current_request, &control) && !batch.flush(&mut cs).await { break; }
+                    wait_for_requests(&mut rr, &mut current_request, &control, true, || control.answer_checkpoint(|| snapshot(&generation, sent, Some(&crossword)))).await;
+                    if let CrosswordGenerationRequest::Stop = current_request 
+                    { 
+                        held = Some(crossword);
+                        break; 
+                    }
+
+                    // the stream is dropped
+                    if !batch.push(&mut cs, crossword.convert_to(convert)).await { break; }
+                    sent += 1;
+                    if let CrosswordGenerationRequest::Count(count) = current_request { current_request = CrosswordGenerationRequest::Count(count - 1) }
+                    control.set_current_requested(&current_request);
+                    if waits_for_requests(&current_request, &control) && !batch.flush(&mut cs).await { break; }
+                }
+                batch.flush(&mut cs).await;
+                // set before the sender is dropped, so they are set when the stream ends
+                if let Some(failure) = generation.failure_report(convert) { let _ = control.failure.set(failure); }
+                control.finish(current_request == CrosswordGenerationRequest::Stop, cs.is_closed(), &generation.tracker);
+                let _ = control.summary.set(generation.tracker.summary());
+                control.finish_checkpoints(snapshot(&generation, sent, held.as_ref()));
+            }
+        };
+
+        let (crossword_buffer, request_buffer) = (self.settings.crossword_buffer.div_ceil(self.settings.emit_batch_size.max(1)), self.settings.request_buffer);
+        CrosswordStream::new_stoppable(spawner, crossword_buffer, request_buffer, gen_func)
+    }
+
+    // the values of the words and of the seed words by their slices, for converting the crosswords of SortedGeneration
+    fn values(&self) -> BTreeMap<&[CharT], &StrT>
+    {
+        let seed_words = self.seed.iter().flat_map(|seed| seed.iter()).map(|w| &w.value);
+        self.words.iter().map(|w| &w.value).chain(seed_words).map(|value| (value.as_ref(), value)).collect()
+    }
+
+    /// Returns an iterator over the generated crosswords, without an async runtime.
+    /// 
+    /// The crosswords are generated lazily, in the same order as with [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted).
+    /// The values of the words of the crosswords are copied from the words of the generator (and of the seed crossword).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// 
+    /// for cw in generator.generate_iter().take(3)
+    /// {
+    ///     assert_eq!(cw.len(), 3);
+    /// }
+    /// ```
+    pub fn generate_iter(&self) -> impl Iterator<Item = Crossword<CharT, StrT>> + '_
+    {
+        let values = self.values();
+        SortedGeneration::new(self).map(move |crossword| crossword.convert_to(|w| values[w].clone()))
+    }
+
+    /// Same as [generate_iter](CrosswordGenerator::generate_iter), but every crossword comes with the words of the generator that were left out of it, 
+    /// for the crosswords generated with optional words or with [allow_unplaced_words](CrosswordGeneratorSettings::allow_unplaced_words).
+    /// 
+    /// The left out words are the words that the search didn't place, so of the words with the same value (and other directions) only the placed one is not left out 
+    /// (unlike [with_unused_words](CrosswordGenerator::with_unused_words), which finds them by the words of the crossword).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "xyz"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// generator.settings.allow_unplaced_words = true;
+    /// 
+    /// // xyz has no common characters with the other words
+    /// let generated = generator.generate_iter_with_unused_words().next().unwrap();
+    /// assert_eq!(generated.crossword.len(), 2);
+    /// assert_eq!(generated.unused_words, vec![Word::new("xyz", None)]);
+    /// ```
+    pub fn generate_iter_with_unused_words(&self) -> impl Iterator<Item = GeneratedCrossword<CharT, StrT>> + '_
+    {
+        let seed_words = self.seed.iter().flat_map(|seed| seed.iter()).map(|w| &w.value);
+        let values = self.words.iter().map(|w| &w.value).chain(seed_words).map(|value| (value.as_ref(), value)).collect::<BTreeMap<_, _>>();
+        let search_words = self.search_words().collect::<Vec<_>>();
+        let mut generation = SortedGeneration::new(self);
+        std::iter::from_fn(move || 
+        {
+            let crossword = generation.next()?.convert_to(|w| values[w].clone());
+            let unused_words = generation.unused_words().into_iter().map(|ind| search_words[ind].clone()).collect();
+            Some(GeneratedCrossword { crossword, unused_words })
+        })
+    }
+
+    /// Returns the first n generated crosswords (check [generate_iter](CrosswordGenerator::generate_iter)).
+    pub fn generate_n(&self, n: usize) -> Vec<Crossword<CharT, StrT>>
+    {
+        self.generate_iter().take(n).collect()
+    }
+
+    /// Returns the first generated crossword (check [generate_iter](CrosswordGenerator::generate_iter)), none if no crossword can be generated with the words and the settings before the [limits](CrosswordGeneratorSettings::limits).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// assert_eq!(generator.generate_one().unwrap().len(), 3);
+    /// 
+    /// generator.words = ["hello", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// assert_eq!(generator.generate_one(), None);
+    /// ```
+    pub fn generate_one(&self) -> Option<Crossword<CharT, StrT>>
+    {
+        self.generate_iter().next()
+    }
+
+    /// Returns the count of the crosswords of [generate_iter](CrosswordGenerator::generate_iter), without creating them (the search stops on the [limits](CrosswordGeneratorSettings::limits)).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// 
+    /// assert_eq!(generator.count_crosswords(), generator.generate_iter().count() as u64);
+    /// assert_eq!(generator.count_crosswords_up_to(1), 1);
+    /// ```
+    pub fn count_crosswords(&self) -> u64
+    {
+        self.count_crosswords_up_to(u64::MAX)
+    }
+
+    /// Same as [count_crosswords](CrosswordGenerator::count_crosswords), but the search stops when the count reaches the limit.
+    pub fn count_crosswords_up_to(&self, limit: u64) -> u64
+    {
+        let mut generation = SortedGeneration::new(self);
+        let mut count = 0;
+        while count < limit
+        {
+            match generation.advance()
+            {
+                Poll::Ready(true) => count += 1,
+                Poll::Ready(false) => break,
+                Poll::Pending => {},
+            }
+        }
+        count
+    }
+
+    /// Same as [count_crosswords](CrosswordGenerator::count_crosswords), but the search lets the other tasks of the executor run from time to time, so it can run in an async context.
+    #[cfg(feature = "async")]
+    pub async fn count_crosswords_async(&self) -> u64
+    {
+        let mut generation = SortedGeneration::new(self);
+        let mut yielder = Yielder::timed();
+        let mut count = 0;
+        loop
+        {
+            match generation.advance()
+            {
+                Poll::Ready(true) => count += 1,
+                Poll::Ready(false) => break,
+                Poll::Pending => yielder.maybe_yield().await,
+            }
+        }
+        count
+    }
+
+    /// Returns the n best crosswords by the score of the scorer (the best first), of the crosswords generated in the order of [generate_iter](CrosswordGenerator::generate_iter) until the budget is exhausted.
+    /// 
+    /// Of the crosswords with the [same layout](Crossword::same_layout) only one is returned, crosswords with the same score are in their order.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerator, GenerationBudget};
+    /// # use crossword_generator::scoring::CrosswordScorer;
+    /// # use crossword_generator::word::Word;
+    /// let mut generator = CrosswordGenerator::<u8, &str>::default();
+    /// generator.words = ["hello", "local", "cat", "toy"].into_iter().map(|s| Word::new(s, None)).collect();
+    /// 
+    /// let best = generator.best_crosswords(3, &CrosswordScorer::default(), GenerationBudget::new().max_candidates(100));
+    /// assert_eq!(best.len(), 3);
+    /// assert!(best.windows(2).all(|pair| CrosswordScorer::default().score(&pair[0]) >= CrosswordScorer::default().score(&pair[1])));
+    /// ```
+    pub fn best_crosswords(&self, n: usize, scorer: &CrosswordScorer, budget: GenerationBudget) -> Vec<Crossword<CharT, StrT>>
+    {
+        let start = Instant::now();
+        let mut best = BestCrosswords::new(n);
+        let mut generation = SortedGeneration::new(self).with_cancellation(move || budget.is_exhausted(start, 0));
+        let mut candidates = 0;
+        while !budget.is_exhausted(start, candidates)
+        {
+            let Some(crossword) = generation.next() else { break; };
+            best.offer(scorer, crossword);
+            candidates += 1;
+        }
+
+        let values = self.values();
+        best.into_sorted_vec().into_iter().map(|crossword| crossword.convert_to(|w| values[w].clone())).collect()
+    }
+
+    /// Searches for a crossword in the order of [generate_iter](CrosswordGenerator::generate_iter), and returns the report of the search if there is none (check [GenerationFailure]), 
+    /// so it can be used to find out why no crossword can be generated with the words and the settings.
+    /// 
+    /// Returns none if a crossword is found, the search stops on the [limits](CrosswordGeneratorSettings::limits).
+    pub fn failure_report(&self) -> Option<GenerationFailure<CharT, StrT>>
+    {
+        let mut generation = SortedGeneration::new(self).with_diagnostics();
+        if generation.next().is_some() { return None; }
+
+        let values = self.values();
+        generation.failure_report(|w| values[w].clone())
+    }
+
+    /// Returns the first n crosswords of [crossword_stream_sorted](CrosswordGenerator::crossword_stream_sorted) (the same as [generate_n](CrosswordGenerator::generate_n)), 
+    /// fewer if there are no more crosswords before the [limits](CrosswordGeneratorSettings::limits).
+    /// 
+    /// Only the n crosswords are requested from the stream, and the generation is stopped and waited for before returning, so nothing is searched after that. 
+    /// Dropping the future stops the generation too, but without waiting.
+    #[cfg(feature = "tokio")]
+    pub async fn generate_first_n<F>(&self, n: usize, convert_f: F) -> Vec<Crossword<CharT, StrT>> where
+        CharT: 'static,
+        StrT: 'static,
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {
+        let mut str = self.crossword_stream_sorted(convert_f);
+        // the request fails only if the generation is already finished, then the stream has all the crosswords it will have
+        let _ = str.request_crossword(CrosswordGenerationRequest::Count(n)).await;
+        let crosswords = (&mut str).take(n).collect().await;
+        str.shutdown().await;
+        crosswords
+    }
+
+    /// Same as [generate_one](CrosswordGenerator::generate_one), but with [generate_first_n](CrosswordGenerator::generate_first_n).
+    #[cfg(feature = "tokio")]
+    pub async fn generate_one_async<F>(&self, convert_f: F) -> Option<Crossword<CharT, StrT>> where
+        CharT: 'static,
+        StrT: 'static,
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {
+        self.generate_first_n(1, convert_f).await.pop()
+    }
+
+    /// Same as [best_crosswords](CrosswordGenerator::best_crosswords), but the crosswords are generated in the order of [crossword_stream_randomized_seeded](CrosswordGenerator::crossword_stream_randomized_seeded), 
+    /// so the budget is spent on more different crosswords.
+    #[cfg(feature = "tokio")]
+    pub async fn best_crosswords_async<F>(&self, n: usize, scorer: &CrosswordScorer, budget: GenerationBudget, seed: u64, convert_f: F) -> Vec<Crossword<CharT, StrT>> where
+        CharT: 'static,
+        StrT: 'static,
+        F: Fn(&[CharT]) -> StrT,
+        F: Clone + Send + Sync + 'static
+    {
+        let start = Instant::now();
+        let mut best = BestCrosswords::new(n);
+        // the duration limit ends the search even if no crossword is found for a while
+        let mut gen = self.clone();
+        gen.settings.limits.max_duration = gen.settings.limits.max_duration.into_iter().chain(budget.max_duration).min();
+        let mut str = gen.crossword_stream_randomized_seeded(seed, convert_f);
+        str.request_crossword(budget.max_candidates.map_or(CrosswordGenerationRequest::All, CrosswordGenerationRequest::Count)).await.unwrap();
+        let mut candidates = 0;
+        while !budget.is_exhausted(start, candidates)
+        {
+            let Some(crossword) = str.next().await else { break; };
+            best.offer(scorer, crossword);
+            candidates += 1;
+        }
+        str.shutdown().await;
+
+        best.into_sorted_vec()
+    }
+}
+
+// a node of the search of the sorted generation, the words are tried in order of their weights, and for each word all its placements
+struct SortedGenerationFrame<'a, CharT: CrosswordChar>
+{
+    // the index of the word whose placements are tried (in the words of the generation, it's used while it's tried), none before the first word
+    word: Option<usize>,
+    placements: Vec<PlacedWord<CharT, &'a [CharT]>>,
+    placement_ind: usize,
+    // the placement that is in the crossword while its subtree is searched
+    placed: Option<PlacedWord<CharT, &'a [CharT]>>,
+}
+
+// a node of the search of a task of the randomized stream, the placements of the word are tried in the order of the stream, and then the crosswords without it if it's optional
+#[cfg(feature = "async")]
+struct RandomizedGenerationFrame<CharT: CrosswordChar>
+{
+    // in the reverse order, so the next one can be popped
+    placements: Vec<PlacedWord<CharT, Arc<[CharT]>>>,
+    // the word that is in the crossword while the subtree of its placement is searched
+    placed: Option<Arc<[CharT]>>,
+    // the word can be left out, and it's not left out yet
+    skippable: bool,
+}
+
+// a placement of a word in the state of the sorted generation, with the index of the word in the valid words of the generator
+#[cfg(feature = "async")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+struct PlacementState
+{
+    word: usize,
+    position: Position,
+    direction: Direction,
+}
+
+// a SortedGenerationFrame with the words as indices
+#[cfg(feature = "async")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+struct SortedGenerationFrameState
+{
+    word: Option<usize>,
+    placements: Vec<PlacementState>,
+    placement_ind: usize,
+    placed: Option<PlacementState>,
+}
+
+// CreatedCrosswords with the ids of the words as the indices of the placements, the bounded layouts are in the order of their insertions
+#[cfg(feature = "async")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+enum CreatedCrosswordsState
+{
+    All(Vec<Vec<PlacementState>>),
+    Bounded(usize, Vec<Vec<PlacementState>>),
+    Hashes(Vec<u128>),
+}
+
+// the state of the search of a SortedGeneration with the words as indices, so it can be serialized (check SortedGeneration::state)
+#[cfg(feature = "async")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+struct SortedGenerationState
+{
+    current_crossword: Vec<PlacementState>,
+    stack: Vec<SortedGenerationFrameState>,
+    to_enter: bool,
+    // missing in the checkpoints written before the layouts of the searched crosswords were kept
+    #[serde(default)]
+    searched_crosswords: Option<CreatedCrosswordsState>,
+    full_created_crossword_bases: Vec<Vec<PlacementState>>,
+    created_canonical_crosswords: CreatedCrosswordsState,
+    emitted_crosswords: Vec<Vec<PlacementState>>,
+    rng: u64,
+}
+
+// the backtracking of the sorted generation with an explicit stack, so it can be paused after every crossword
+struct SortedGeneration<'a, CharT: CrosswordChar>
+{
+    settings: &'a CrosswordGeneratorSettings<CharT>,
+    current_crossword: Crossword<CharT, &'a [CharT]>,
+    stack: Vec<SortedGenerationFrame<'a, CharT>>,
+    // the search words in the order of their weights, the words used by the frames are marked, the remained words of a node are the ones that aren't
+    words: Vec<Word<CharT, &'a [CharT]>>,
+    used: Vec<bool>,
+    // the indices of the words in the search words of the generator (check CrosswordGenerator::search_words)
+    generator_inds: Vec<usize>,
+    // the node of the current crossword must be entered next
+    to_enter: bool,
+    // the ids of the checkpoint words (check CrosswordGenerator::checkpoint_words), so the ids are the indices of the words in the checkpoints
+    word_ids: WordIds<CharT, &'a [CharT]>,
+    // the values of the checkpoint words (by their ids) for the emit filter, converted once, empty without the filter
+    filter_values: Vec<Arc<[CharT]>>,
+    // the layouts of the crosswords with fully searched subtrees, they are not searched again, 
+    // so a crossword that can be reached with different orders of the words is generated only once, without keeping the generated crosswords
+    searched_crosswords: CreatedCrosswords,
+    // with prune_containing_crosswords instead of the searched crosswords, the crosswords with fully searched subtrees (without the ones containing other such crosswords), 
+    // the crosswords that contain them are not searched again, they are ordered by their counts of words, so the largest ones are evicted first (check DedupMemoryLimit::Bounded)
+    full_created_crossword_bases: BTreeSet<(usize, Crossword<CharT, &'a [CharT]>)>,
+    created_canonical_crosswords: CreatedCrosswords,
+    emitted_crosswords: EmittedCrosswords<CharT, &'a [CharT]>,
+    rng: GeneratorRng,
+    // checked on every step, the generation ends if it returns true
+    is_cancelled: Box<dyn Fn() -> bool + Send + Sync + 'a>,
+    tracker: Arc<GenerationTracker>,
+    diagnostics: Option<FailureDiagnostics<CharT, &'a [CharT]>>,
+}
+
+impl<'a, CharT: CrosswordChar> SortedGeneration<'a, CharT>
+{
+    fn new<StrT: CrosswordString<CharT>>(gen: &'a CrosswordGenerator<CharT, StrT>) -> SortedGeneration<'a, CharT>
+    {
+        let seed_crossword = gen.seed_crossword(|w| w.as_ref());
+        // the sort is stable, so the words with the same weight are tried in their order
+        let checkpoint_values = gen.checkpoint_words().into_iter().map(|w| w.value).collect::<Vec<_>>();
+        let (words, generator_inds): (Vec<_>, Vec<_>) = gen.search_words().enumerate().map(|(ind, w)| (w.with_value(w.value.as_ref()), ind)).collect::<BTreeMap<_, _>>()
+            .into_iter().sorted_by_key(|(w, _)| Reverse(w.weight)).unzip();
+        SortedGeneration
+        {
+            settings: &gen.settings,
+            current_crossword: seed_crossword.clone().unwrap_or_default(),
+            stack: vec![],
+            used: vec![false; words.len()],
+            words,
+            generator_inds,
+            // there are no crosswords with an invalid seed crossword
+            to_enter: seed_crossword.is_some(),
+            word_ids: WordIds::new(checkpoint_values.iter().copied()),
+            filter_values: if gen.settings.emit_filter.is_some() { checkpoint_values.iter().map(|value| Arc::from(*value)).collect() } else { vec![] },
+            searched_crosswords: CreatedCrosswords::new(gen.settings.dedup_memory_limit),
+            full_created_crossword_bases: BTreeSet::new(),
+            created_canonical_crosswords: CreatedCrosswords::new(gen.settings.dedup_memory_limit),
+            emitted_crosswords: EmittedCrosswords::new(gen.settings.min_difference),
+            rng: GeneratorRng::new(match gen.settings.placement_order { PlacementOrder::Shuffled(seed) => seed, _ => 0 }),
+            is_cancelled: Box::new(|| false),
+            tracker: Arc::new(GenerationTracker::new(gen.settings.limits)),
+            diagnostics: None,
+        }
+    }
+
+    fn with_cancellation(mut self, is_cancelled: impl Fn() -> bool + Send + Sync + 'a) -> SortedGeneration<'a, CharT>
+    {
+        self.is_cancelled = Box::new(is_cancelled);
+        self
+    }
+
+    // collects the diagnostics for the failure report while searching
+    fn with_diagnostics(mut self) -> SortedGeneration<'a, CharT>
+    {
+        let words = self.words.iter().filter(|_| self.to_enter).map(|w| w.value);
+        self.diagnostics = Some(FailureDiagnostics::new(words, self.current_crossword.word_compatibility_settings.clone()));
+        self
+    }
+
+    // the failure report, if the diagnostics are collected and no crossword is generated
+    fn failure_report<StrT: CrosswordString<CharT>>(&mut self, convert_f: impl Fn(&[CharT]) -> StrT) -> Option<GenerationFailure<CharT, StrT>>
+    {
+        if self.tracker.has_crosswords() { return None; }
+        self.diagnostics.take().map(|diagnostics| diagnostics.into_report(convert_f))
+    }
+
+    // checks the current crossword, and pushes its node if it needs to be searched, returns whether the crossword must be emitted
+    fn enter(&mut self) -> bool
+    {
+        let settings = self.settings;
+        let remained_words = || self.words.iter().zip(&self.used).filter(|(_, used)| !**used).map(|(w, _)| w);
+        let view = ConstraintView::new();
+        if settings.crossword_settings.failed_nonrecoverable_constraint_in(&self.current_crossword, &view).is_some() ||
+           !settings.crossword_settings.check_required_words(&self.current_crossword, remained_words().map(|w| w.value)) ||
+           !CrosswordGenerator::<CharT, &[CharT]>::can_be_balanced(settings, &self.current_crossword, remained_words())
+        {
+            if let Some(diagnostics) = &mut self.diagnostics
+            {
+                let (settings, crossword) = (&settings.crossword_settings, &self.current_crossword);
+                diagnostics.add_rejection(settings.failed_nonrecoverable_constraint_in(crossword, &view).or_else(|| settings.failed_required_word(crossword, remained_words().map(|w| w.value))));
+            }
+            return false; 
+        }
+        if let Some(diagnostics) = &mut self.diagnostics { diagnostics.add_reached(&self.current_crossword); }
+
+        let searched = if settings.prune_containing_crosswords 
+        {
+            self.full_created_crossword_bases.iter().any(|(_, cw)| self.current_crossword.contains_crossword(cw))
+        }
+        else
+        {
+            self.searched_crosswords.contains(&self.word_ids.fingerprint(&self.current_crossword))
+        };
+        if searched { return false; }
+        
+        let mut emitted = false;
+        if CrosswordGenerator::<CharT, &[CharT]>::can_be_emitted_without(settings, &self.current_crossword, Some(&self.current_crossword), remained_words())
+        {
+            if let Some(failed) = settings.crossword_settings.failed_recoverable_constraint_in(&self.current_crossword, &view)
+            {
+                if let Some(diagnostics) = &mut self.diagnostics { diagnostics.add_rejection(Some(failed)); }
+            }
+            else if settings.require_rotational_symmetry && !self.current_crossword.is_rotationally_symmetric()
+            {
+                if let Some(diagnostics) = &mut self.diagnostics { diagnostics.add_rejection(Some(&CrosswordConstraint::RotationalSymmetry)); }
+            }
+            else if !CrosswordGenerator::<CharT, &[CharT]>::can_be_balanced(settings, &self.current_crossword, std::iter::empty()) ||
+                    settings.emit_filter.as_ref().is_some_and(|filter| !(filter.0)(&self.current_crossword.normalized().convert_to(|w| self.filter_values[self.word_ids.value_id(w) as usize].clone()))) {}
+            else if (!settings.dedup_symmetric || self.created_canonical_crosswords.insert(self.word_ids.fingerprint(&self.current_crossword.canonicalize()))) && 
+                    self.emitted_crosswords.offer(&self.current_crossword)
+            {
+                emitted = true;
+            }
+            if remained_words().next().is_none() { return emitted; }
+        }
+
+        self.stack.push(SortedGenerationFrame { word: None, placements: vec![], placement_ind: 0, placed: None });
+        emitted
+    }
+
+    // the indices of the search words of the generator that are not in the current crossword, in the order of the generator
+    fn unused_words(&self) -> Vec<usize>
+    {
+        self.generator_inds.iter().zip(&self.used).filter(|(_, used)| !**used).map(|(ind, _)| *ind).sorted().collect()
+    }
+
+    // does one step of the search, Ready with the emitted crossword, or with None if the search is finished
+    fn step(&mut self) -> Poll<Option<Crossword<CharT, &'a [CharT]>>>
+    {
+        self.advance().map(|emitted| emitted.then(|| self.current_crossword.normalized()))
+    }
+
+    // same as step, but Ready with true if the current crossword is emitted, so counting doesn't copy the crosswords
+    fn advance(&mut self) -> Poll<bool>
+    {
+        if (self.is_cancelled)() || self.tracker.is_reached() { return Poll::Ready(false); }
+
+        if std::mem::take(&mut self.to_enter) && self.enter()
+        {
+            self.tracker.add_crossword();
+            return Poll::Ready(true); 
+        }
+
+        let Some(frame) = self.stack.last_mut() else { return Poll::Ready(false); };
+
+        // the subtree of the placement is searched
+        if let Some(step) = frame.placed.take()
+        {
+            if !self.settings.prune_containing_crosswords
+            {
+                self.searched_crosswords.insert(self.word_ids.fingerprint(&self.current_crossword));
+            }
+            else
+            {
+                self.full_created_crossword_bases.retain(|(_, cw)| !cw.contains_crossword(&self.current_crossword));
+                
+                self.full_created_crossword_bases.insert((self.current_crossword.len(), self.current_crossword.normalized()));
+                if let DedupMemoryLimit::Bounded(limit) = self.settings.dedup_memory_limit
+                {
+                    while self.full_created_crossword_bases.len() > limit { self.full_created_crossword_bases.pop_last(); }
+                }
+            }
+
+            self.current_crossword.remove_word_unnormalized(&step.value);
+            self.tracker.add_backtrack();
+        }
+
+        if let Some(step) = frame.placements.get(frame.placement_ind).cloned()
+        {
+            frame.placement_ind += 1;
+            self.tracker.add_placement();
+            self.current_crossword.add_word_unnormalized(step.clone()).unwrap();
+            if let Some(diagnostics) = &mut self.diagnostics { diagnostics.add_placement(&self.current_crossword, &step.value); }
+            frame.placed = Some(step);
+            self.to_enter = true;
+        }
+        else
+        {
+            // the next word that isn't used by the frames below
+            if let Some(word) = frame.word { self.used[word] = false; }
+            match (frame.word.map_or(0, |word| word + 1)..self.words.len()).find(|ind| !self.used[*ind])
+            {
+                Some(word) =>
+                {
+                    self.used[word] = true;
+                    frame.word = Some(word);
+                    frame.placements = CrosswordGenerator::<CharT, &[CharT]>::ordered_placements(self.settings, &self.current_crossword, Some(&self.current_crossword), &self.words[word], false, &mut self.rng);
+                    frame.placement_ind = 0;
+                }
+                None => { self.stack.pop(); }
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<'a, CharT: CrosswordChar> Iterator for SortedGeneration<'a, CharT>
+{
+    type Item = Crossword<CharT, &'a [CharT]>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop
+        {
+            if let Poll::Ready(crossword) = self.step() { return crossword; }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, CharT: CrosswordChar> SortedGeneration<'a, CharT>
+{
+    // the minimum corners of the crosswords of the frames, the crossword of a frame is the current one without the words placed by the frame and the frames above it
+    fn frame_corners(mut crossword: Crossword<CharT, &'a [CharT]>, placed: impl DoubleEndedIterator<Item = Option<&'a [CharT]>>) -> Vec<(i16, i16)>
+    {
+        let mut corners = placed.rev().map(|placed| 
+        {
+            if let Some(placed) = placed { crossword.remove_word_unnormalized(&placed); }
+            crossword.min_corner()
+        }).collect::<Vec<_>>();
+        corners.reverse();
+        corners
+    }
+
+    // returns the state of the search, the words are the valid words of the generator (in their order)
+    // 
+    // the current crossword isn't normalized while searching, but the positions in the state are the ones in the normalized crosswords, 
+    // and the placements of every frame are relative to the normalized crossword of the frame (like with the crosswords normalized after every step)
+    fn state(&self, words: &[Word<CharT, &'a [CharT]>]) -> SortedGenerationState
+    {
+        let word_inds = words.iter().enumerate().map(|(ind, w)| (w, ind)).collect::<BTreeMap<_, _>>();
+        // the placements of the words with the same value and metadata are the same
+        let placement_inds = words.iter().enumerate().map(|(ind, w)| ((w.value, &w.meta), ind)).collect::<BTreeMap<_, _>>();
+        let word = |w: &Word<CharT, &'a [CharT]>| word_inds[w];
+        let placement = |p: &PlacedWord<CharT, &'a [CharT]>, corner: (i16, i16)| 
+            PlacementState { word: placement_inds[&(p.value, &p.meta)], position: Position { x: p.position.x - corner.0, y: p.position.y - corner.1 }, direction: p.direction.clone() };
+        let crossword = |cw: &Crossword<CharT, &'a [CharT]>| 
+        {
+            let corner = cw.min_corner();
+            cw.iter().map(|p| placement(p, corner)).collect::<Vec<_>>()
+        };
+        // the ids of the layouts are the indices of the placements
+        let layout = |layout: &LayoutFingerprint| 
+            layout.0.iter().map(|(x, y, direction, id)| PlacementState { word: *id as usize, position: Position { x: *x, y: *y }, direction: direction.clone() }).collect::<Vec<_>>();
+        let created = |created: &CreatedCrosswords| match created
+        {
+            CreatedCrosswords::All(crosswords) => CreatedCrosswordsState::All(crosswords.iter().map(layout).sorted().collect()),
+            CreatedCrosswords::Bounded { limit, insertions, .. } => CreatedCrosswordsState::Bounded(*limit, insertions.values().map(layout).collect()),
+            CreatedCrosswords::Hashes(hashes) => CreatedCrosswordsState::Hashes(hashes.iter().copied().sorted().collect()),
+        };
+        let corners = Self::frame_corners(self.current_crossword.clone(), self.stack.iter().map(|frame| frame.placed.as_ref().map(|p| p.value)));
+
+        SortedGenerationState
+        {
+            current_crossword: crossword(&self.current_crossword),
+            stack: self.stack.iter().zip(corners).map(|(frame, corner)| SortedGenerationFrameState
+            {
+                word: frame.word.map(|ind| word(&self.words[ind])),
+                placements: frame.placements.iter().map(|p| placement(p, corner)).collect(),
+                placement_ind: frame.placement_ind,
+                placed: frame.placed.as_ref().map(|p| placement(p, corner)),
+            }).collect(),
+            to_enter: self.to_enter,
+            searched_crosswords: Some(created(&self.searched_crosswords)),
+            full_created_crossword_bases: self.full_created_crossword_bases.iter().map(|(_, cw)| crossword(cw)).collect(),
+            created_canonical_crosswords: created(&self.created_canonical_crosswords),
+            emitted_crosswords: self.emitted_crosswords.crosswords.iter().map(crossword).collect(),
+            rng: self.rng.state,
+        }
+    }
+
+    // continues the search from the state (check state)
+    fn from_state<StrT: CrosswordString<CharT>>(gen: &'a CrosswordGenerator<CharT, StrT>, state: &SortedGenerationState) -> Result<SortedGeneration<'a, CharT>, ResumeError>
+    {
+        let words = gen.checkpoint_words();
+        let word = |ind: &usize| words.get(*ind).cloned().ok_or(ResumeError::InvalidCheckpoint);
+        // the placements of the frames are moved to the positions in the current crossword
+        let placement = |p: &PlacementState, corner: (i16, i16)| 
+        {
+            let w = word(&p.word)?;
+            let position = p.position.x.checked_add(corner.0).zip(p.position.y.checked_add(corner.1)).ok_or(ResumeError::InvalidCheckpoint)?;
+            PlacedWord::try_new(w.value, Position { x: position.0, y: position.1 }, p.direction.clone()).map(|placed| placed.with_meta(w.meta)).map_err(|_| ResumeError::InvalidCheckpoint)
+        };
+        let crossword = |placements: &Vec<PlacementState>| 
+        {
+            let placements = placements.iter().map(|p| placement(p, (0, 0))).collect::<Result<Vec<_>, _>>()?;
+            Crossword::from_placed_words(placements, gen.settings.word_compatibility_settings.clone()).map_err(|_| ResumeError::InvalidCheckpoint)
+        };
+        let layout = |placements: &Vec<PlacementState>| 
+        {
+            if placements.iter().any(|p| p.word >= words.len()) { return Err(ResumeError::InvalidCheckpoint); }
+            Ok(LayoutFingerprint(placements.iter().map(|p| (p.position.x, p.position.y, p.direction.clone(), p.word as u32)).collect()))
+        };
+        let created = |state: &CreatedCrosswordsState| -> Result<CreatedCrosswords, ResumeError>
+        {
+            Ok(match state
+            {
+                CreatedCrosswordsState::All(layouts) => CreatedCrosswords::All(layouts.iter().map(layout).collect::<Result<_, _>>()?),
+                CreatedCrosswordsState::Bounded(limit, layouts) => 
+                {
+                    let mut created = CreatedCrosswords::new(DedupMemoryLimit::Bounded(*limit));
+                    for l in layouts { created.insert(layout(l)?); }
+                    created
+                }
+                CreatedCrosswordsState::Hashes(hashes) => CreatedCrosswords::Hashes(hashes.iter().copied().collect()),
+            })
+        };
+
+        let mut generation = SortedGeneration::new(gen);
+        generation.current_crossword = crossword(&state.current_crossword)?;
+        let placed = state.stack.iter().map(|frame| frame.placed.as_ref().map(|p| word(&p.word).map(|w| w.value)).transpose()).collect::<Result<Vec<_>, _>>()?;
+        let corners = Self::frame_corners(generation.current_crossword.clone(), placed.into_iter());
+        // the words of the frames are marked as used, every word is used by at most one frame
+        let search_inds = generation.words.iter().enumerate().map(|(ind, w)| (w.clone(), ind)).collect::<BTreeMap<_, _>>();
+        let mut used = vec![false; generation.words.len()];
+        generation.stack = state.stack.iter().zip(corners).map(|(frame, corner)|
+        {
+            if frame.placement_ind > frame.placements.len() || (frame.word.is_none() && (!frame.placements.is_empty() || frame.placed.is_some())) { return Err(ResumeError::InvalidCheckpoint); }
+            let search_ind = frame.word.map(|ind| word(&ind).and_then(|w| search_inds.get(&w).copied().ok_or(ResumeError::InvalidCheckpoint))).transpose()?;
+            if let Some(ind) = search_ind
+            {
+                if std::mem::replace(&mut used[ind], true) { return Err(ResumeError::InvalidCheckpoint); }
+            }
+            Ok(SortedGenerationFrame
+            {
+                word: search_ind,
+                placements: frame.placements.iter().map(|p| placement(p, corner)).collect::<Result<_, _>>()?,
+                placement_ind: frame.placement_ind,
+                placed: frame.placed.as_ref().map(|p| placement(p, corner)).transpose()?,
+            })
+        }).collect::<Result<_, _>>()?;
+        generation.used = used;
+        generation.to_enter = state.to_enter;
+        generation.full_created_crossword_bases = state.full_created_crossword_bases.iter().map(|cw| crossword(cw).map(|cw| (cw.len(), cw))).collect::<Result<_, _>>()?;
+        match &state.searched_crosswords
+        {
+            Some(searched) => generation.searched_crosswords = created(searched)?,
+            // the crosswords with searched subtrees of an older checkpoint are only its bases
+            None => for (_, base) in &generation.full_created_crossword_bases { generation.searched_crosswords.insert(generation.word_ids.fingerprint(base)); },
+        }
+        generation.created_canonical_crosswords = created(&state.created_canonical_crosswords)?;
+        generation.emitted_crosswords.crosswords = state.emitted_crosswords.iter().map(crossword).collect::<Result<_, _>>()?;
+        generation.rng = GeneratorRng { state: state.rng };
+        Ok(generation)
+    }
+}
+
+
+/// Represents a request to [CrosswordStream] for generating crosswords.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub enum CrosswordGenerationRequest
+{
+    /// Request to stop the crossword generation.
+    /// 
+    /// The crosswords of the [counts](CrosswordGenerationRequest::Count) requested before the stop are generated first, 
+    /// then the generation stops as soon as possible, even in the middle of the search for a crossword (for example when [all](CrosswordGenerationRequest::All) the crosswords are requested).
+    #[default]
+    Stop,
+    /// Request for some count of crosswords to generate.
+    Count(usize),
+    /// Request for generating all possible crosswords.
+    All,
+    /// Request to pause the crossword generation, without ending it like [CrosswordGenerationRequest::Stop].
+    /// 
+    /// The generation pauses as soon as possible, even in the middle of the search for a crossword, and continues from the same point after a [CrosswordGenerationRequest::Resume], 
+    /// a [CrosswordGenerationRequest::Count] or a [CrosswordGenerationRequest::All] (the last two replace the current request, like without a pause).
+    Pause,
+    /// Request to continue a paused generation with the current request.
+    Resume,
+}
+
+/// Represents the state of the generation of a [CrosswordStream] (check [CrosswordStream::state]).
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum GeneratorState
+{
+    /// The requested crosswords are generated, the generation waits for new requests.
+    Idle,
+    /// The generation is searching for crosswords, the streams search for the next crossword even before it's requested.
+    Generating,
+    /// The generation is paused (check [CrosswordGenerationRequest::Pause]).
+    Paused,
+    /// The generation is finished, the already generated crosswords can still be taken from the stream.
+    Finished,
+}
+
+/// Represents the reason of the end of the generation of a [CrosswordStream] (check [CrosswordStream::finished_reason]).
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum FinishReason
+{
+    /// All the crosswords are searched (all the [orders of the words](CrosswordGeneratorSettings::max_permutations) for the randomized stream), 
+    /// there can be [pending](CrosswordStream::pending_requested) requested crosswords that were never found.
+    Exhausted,
+    /// The limit of the [limits](CrosswordGeneratorSettings::limits) is reached.
+    LimitReached(GenerationLimit),
+    /// A stop is requested (check [CrosswordGenerationRequest::Stop]), the stream is shut down (check [CrosswordStream::shutdown]), 
+    /// or the requesters of a [split](CrosswordStream::split) stream are dropped.
+    Stopped,
+    /// The stream (or the receiver of a [split](CrosswordStream::split) stream) is dropped without requesting a stop.
+    Dropped,
+}
+
+/// Error type for possible errors when requesting crosswords from a [CrosswordStream] (check [CrosswordStream::request_crossword]).
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum RequestError
+{
+    #[error("The generation is finished, no more crosswords can be requested")]
+    GenerationFinished,
+    #[error("The buffer of the requests is full")]
+    RequestBufferFull,
+}
+
+/// Represents a checkpoint of the generation of a [sorted stream](CrosswordGenerator::crossword_stream_sorted) (check [CrosswordStream::checkpoint]), 
+/// it can be serialized, and the generation can be continued from it later (check [CrosswordGenerator::resume_stream]).
+/// 
+/// The words are saved by their indices, so the checkpoint keeps a hash of the words instead of the words, and the generated crosswords that were not taken from the stream.
+#[cfg(feature = "async")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct GeneratorCheckpoint<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    words_hash: u64,
+    settings: CrosswordGeneratorSettings<CharT>,
+    pending: Vec<Crossword<CharT, StrT>>,
+    state: SortedGenerationState,
+}
+
+/// Error type for possible errors when resuming a generation from a [checkpoint](GeneratorCheckpoint) (check [CrosswordGenerator::resume_stream]).
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum ResumeError
+{
+    #[error("The words of the generator are not the words of the checkpoint")]
+    WordsMismatch,
+    #[error("The settings of the generator are not the settings of the checkpoint")]
+    SettingsMismatch,
+    #[error("The state of the generation in the checkpoint is invalid")]
+    InvalidCheckpoint,
+}
+
+// the receiver of the crosswords of a stream, the generators send them in batches (check CrosswordGeneratorSettings::emit_batch_size), 
+// the custom generation functions one by one, straight to the stream
+#[cfg(feature = "async")]
+enum CrosswordChannel<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    Batches(Receiver<Vec<Crossword<CharT, StrT>>>),
+    Crosswords(Receiver<Crossword<CharT, StrT>>),
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordChannel<CharT, StrT>
+{
+    // adds the received crosswords to the queue, Ready with their count, or with None if the generation is finished
+    fn poll_into(&mut self, cx: &mut Context, queue: &mut VecDeque<Crossword<CharT, StrT>>) -> Poll<Option<usize>>
+    {
+        match self
+        {
+            CrosswordChannel::Batches(receiver) => receiver.poll_next_unpin(cx).map(|batch| batch.map(|batch| 
+            {
+                let count = batch.len();
+                queue.extend(batch);
+                count
+            })),
+            CrosswordChannel::Crosswords(receiver) => receiver.poll_next_unpin(cx).map(|crossword| crossword.map(|crossword| 
+            {
+                queue.push_back(crossword);
+                1
+            })),
+        }
+    }
+
+    fn close(&mut self)
+    {
+        match self
+        {
+            CrosswordChannel::Batches(receiver) => receiver.close(),
+            CrosswordChannel::Crosswords(receiver) => receiver.close(),
+        }
+    }
+
+    fn is_terminated(&self) -> bool
+    {
+        match self
+        {
+            CrosswordChannel::Batches(receiver) => receiver.is_terminated(),
+            CrosswordChannel::Crosswords(receiver) => receiver.is_terminated(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub struct CrosswordStream<CharT: CrosswordChar + 'static, StrT: CrosswordString<CharT> + 'static>
+{
+    request_sender: Sender<CrosswordGenerationRequest>,
+    crossword_reciever: CrosswordChannel<CharT, StrT>,
+    // the crosswords of the received batches, the crosswords taken from the receiver while taking a checkpoint (and the pending crosswords of a resumed stream), they are given before the ones in the receiver
+    queue: VecDeque<Crossword<CharT, StrT>>,
+    // the count of the crosswords taken from the receiver
+    received: usize,
+    // completes when the generation function is finished
+    finished: oneshot::Receiver<()>,
+    control: Arc<StreamControl<CharT, StrT>>,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordStream<CharT, StrT>
+{
+    /// Creates the stream, with the generation function spawned on tokio.
+    #[cfg(feature = "tokio")]
+    pub fn new<F,Fut>(gen_func: F) -> CrosswordStream<CharT, StrT>
+    where
+        F: FnOnce(Receiver<CrosswordGenerationRequest>, Sender<Crossword<CharT, StrT>>) -> Fut,
+        Fut: Future<Output=()> + Send + 'static
+    {
+        CrosswordStream::new_with_spawner(tokio_spawner, gen_func)
+    }
+
+    /// Creates the stream, with the generation function spawned with the spawner (for example with the spawner of an async-std or a futures executor).
+    /// 
+    /// The stream can hold 100 crosswords and 100 requests (the defaults of [CrosswordGeneratorSettings]).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerationRequest, CrosswordStream};
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use futures::{executor::LocalPool, task::SpawnExt, SinkExt, StreamExt};
+    /// let mut pool = LocalPool::new();
+    /// let spawner = pool.spawner();
+    /// 
+    /// let mut str = CrosswordStream::<u8, &str>::new_with_spawner(|future| spawner.spawn(future).unwrap(), |_requests, mut crosswords| async move
+    /// {
+    ///     crosswords.send(Crossword::default()).await.unwrap();
+    /// });
+    /// 
+    /// assert_eq!(pool.run_until(str.next()), Some(Crossword::default()));
+    /// ```
+    pub fn new_with_spawner<S, F, Fut>(spawner: S, gen_func: F) -> CrosswordStream<CharT, StrT>
+    where
+        S: FnOnce(BoxFuture<'static, ()>),
+        F: FnOnce(Receiver<CrosswordGenerationRequest>, Sender<Crossword<CharT, StrT>>) -> Fut,
+        Fut: Future<Output=()> + Send + 'static
+    {
+        let (cs, cr) = mpsc::channel(DEFAULT_BUFFER_SIZE - 1);
+        CrosswordStream::new_spawned(spawner, DEFAULT_BUFFER_SIZE, CrosswordChannel::Crosswords(cr), |rr, _| gen_func(rr, cs))
+    }
+
+    // same as new_with_spawner, but with the sizes of the buffers, and the generation function gets the state shared with the stream
+    pub(crate) fn new_stoppable<S, F, Fut>(spawner: S, crossword_buffer: usize, request_buffer: usize, gen_func: F) -> CrosswordStream<CharT, StrT>
+    where
+        S: FnOnce(BoxFuture<'static, ()>),
+        F: FnOnce(Receiver<CrosswordGenerationRequest>, Sender<Vec<Crossword<CharT, StrT>>>, Arc<StreamControl<CharT, StrT>>) -> Fut,
+        Fut: Future<Output=()> + Send + 'static
+    {
+        // a sender can always add one message above the size of the buffer
+        let (cs, cr) = mpsc::channel(crossword_buffer.max(1) - 1);
+        CrosswordStream::new_spawned(spawner, request_buffer, CrosswordChannel::Batches(cr), |rr, control| gen_func(rr, cs, control))
+    }
+
+    // spawns the generation function, that sends the crosswords to the receiver
+    fn new_spawned<S, F, Fut>(spawner: S, request_buffer: usize, crossword_reciever: CrosswordChannel<CharT, StrT>, gen_func: F) -> CrosswordStream<CharT, StrT>
+    where
+        S: FnOnce(BoxFuture<'static, ()>),
+        F: FnOnce(Receiver<CrosswordGenerationRequest>, Arc<StreamControl<CharT, StrT>>) -> Fut,
+        Fut: Future<Output=()> + Send + 'static
+    {
+        let (rs, rr) = mpsc::channel(request_buffer.max(1) - 1);
+        let (finished_sender, finished) = oneshot::channel();
+        let control = Arc::new(StreamControl::default());
+
+        let generation = gen_func(rr, control.clone());
+        let finished_control = control.clone();
+        spawner(Box::pin(async move 
+        {
+            generation.await;
+            finished_control.set_state(GeneratorState::Finished);
+            let _ = finished_sender.send(());
+        }));
+        
+        CrosswordStream { request_sender: rs, crossword_reciever, queue: VecDeque::new(), received: 0, finished, control }
+    }
+
+    /// Stops the generation and waits until it's finished (with all the tasks it spawned).
+    /// 
+    /// Dropping the stream stops the generation too, but without waiting, the generators check whether the stream is dropped at every step, not only when a crossword is found.
+    pub async fn shutdown(mut self)
+    {
+        // the generation is stopped, not dropped (check FinishReason)
+        self.control.stop.store(true, Ordering::Relaxed);
+        self.close();
+        let _ = (&mut self.finished).await;
+    }
+
+    /// Returns the current progress of the generation, it's updated while searching, so it can be polled for example for a progress bar.
+    /// 
+    /// Streams with custom generation functions have no progress.
+    pub fn progress(&self) -> GenerationProgress
+    {
+        self.control.progress()
+    }
+
+    /// Returns the summary of the generation, after it's finished (so after the stream ends), none before that and for streams with custom generation functions.
+    pub fn summary(&self) -> Option<GenerationSummary>
+    {
+        self.control.summary.get().cloned()
+    }
+
+    /// Returns the count of the requested crosswords that are not generated yet (usize::MAX after a request for [all](CrosswordGenerationRequest::All) the crosswords), 
+    /// the crosswords already generated but not taken from the stream are not counted.
+    /// 
+    /// After the generation is finished the count stays as it was, so a finished stream with pending crosswords ran out of them (check [finished_reason](CrosswordStream::finished_reason)).
+    /// Streams with custom generation functions only count the requests.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerationRequest, CrosswordGenerator, FinishReason};
+    /// # use crossword_generator::word::Word;
+    /// # use futures::StreamExt;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main()
+    /// # {
+    /// let mut generator = CrosswordGenerator::<u8, String>::default();
+    /// generator.words = ["hello", "local"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect();
+    /// 
+    /// let mut str = generator.crossword_stream_sorted(|w| std::str::from_utf8(w).unwrap().to_owned());
+    /// str.request_crossword(CrosswordGenerationRequest::Count(10)).await.unwrap();
+    /// let crosswords = str.by_ref().collect::<Vec<_>>().await;
+    /// 
+    /// assert_eq!(str.pending_requested(), 10 - crosswords.len());
+    /// assert_eq!(str.finished_reason(), Some(FinishReason::Exhausted));
+    /// # }
+    /// ```
+    pub fn pending_requested(&self) -> usize
+    {
+        self.control.pending_requested()
+    }
+
+    /// Returns the reason of the end of the generation, after it's finished (so after the stream ends), none before that and for streams with custom generation functions.
+    pub fn finished_reason(&self) -> Option<FinishReason>
+    {
+        self.control.finish_reason.get().copied()
+    }
+
+    /// Returns the current state of the generation.
+    /// 
+    /// Streams with custom generation functions are [generating](GeneratorState::Generating) until the generation function is finished.
+    pub fn state(&self) -> GeneratorState
+    {
+        self.control.state()
+    }
+
+    /// Returns the report of the generation, if it's finished without generating any crossword (so after the stream ends) and [failure_report](CrosswordGeneratorSettings::failure_report) is set, none otherwise.
+    pub fn failure_report(&self) -> Option<GenerationFailure<CharT, StrT>>
+    {
+        self.control.failure.get().cloned()
+    }
+
+    /// Returns a checkpoint of the generation, from which [resume_stream](CrosswordGenerator::resume_stream) continues it, even in another process (the checkpoint can be serialized).
+    /// 
+    /// The crosswords that are generated but not taken from the stream yet are kept in the checkpoint, so a resumed stream gives them first, 
+    /// and then the crosswords that this stream would give after them, the crosswords already taken from this stream are not given again.
+    /// This stream continues the generation too.
+    /// 
+    /// Waits for the generation to get to the next point between two placements (it can take while the generation waits for space in the buffer, so the crosswords are taken from the buffer meanwhile).
+    /// 
+    /// Only the [sorted streams](CrosswordGenerator::crossword_stream_sorted) (and the streams resumed from their checkpoints) can be checkpointed, none is returned for the other streams 
+    /// (the tasks of the randomized stream search the permutations of the words in parallel, so their state is not a single point of a search).
+    pub async fn checkpoint(&mut self) -> Option<GeneratorCheckpoint<CharT, StrT>>
+    {
+        let (words_hash, settings) = self.control.checkpoint_base.get()?.clone();
+        let requested = 
+        {
+            let mut sender = self.control.checkpoint_sender.lock().unwrap();
+            match self.control.final_checkpoint.get()
+            {
+                Some(snapshot) => Err(snapshot.clone()),
+                None => 
+                {
+                    let (snapshot_sender, snapshot_receiver) = oneshot::channel();
+                    *sender = Some(snapshot_sender);
+                    self.control.checkpoint_requested.store(true, Ordering::Relaxed);
+                    Ok(snapshot_receiver)
+                }
+            }
+        };
+
+        let snapshot = match requested
+        {
+            Err(snapshot) => snapshot,
+            Ok(mut snapshot_receiver) => 
+            {
+                // wakes the generation, if it's waiting for requests (the current request doesn't change)
+                let _ = self.request_sender.try_send(if self.control.is_paused() { CrosswordGenerationRequest::Pause } else { CrosswordGenerationRequest::Resume });
+                loop
+                {
+                    match future::select(&mut snapshot_receiver, future::poll_fn(|cx| self.crossword_reciever.poll_into(cx, &mut self.queue))).await
+                    {
+                        Either::Left((snapshot, _)) => break snapshot.ok().or_else(|| self.control.final_checkpoint.get().cloned())?,
+                        Either::Right((Some(count), _)) => self.received += count,
+                        // the generation sends the snapshot before it's finished
+                        Either::Right((None, snapshot_receiver)) => break snapshot_receiver.await.ok()?,
+                    }
+                }
+            }
+        };
+
+        // the crosswords sent before the snapshot are pending, the ones sent after it are generated by the resumed stream again
+        while self.received < snapshot.sent
+        {
+            let Some(count) = future::poll_fn(|cx| self.crossword_reciever.poll_into(cx, &mut self.queue)).await else { break; };
+            self.received += count;
+        }
+        let pending_count = self.queue.len().saturating_sub(self.received.saturating_sub(snapshot.sent));
+        let pending = self.queue.iter().take(pending_count).cloned().chain(snapshot.held).collect();
+        Some(GeneratorCheckpoint { words_hash, settings, pending, state: snapshot.state })
+    }
+
+    fn close(&mut self)
+    {
+        self.crossword_reciever.close();
+        self.request_sender.close_channel();
+    }
+
+    /// Requests crosswords to generate with function like next or take.
+    /// 
+    /// After requesting some count of crosswords (with [CrosswordGenerationRequest::Count]) and generating the crosswords the stream will start to wait for other requests, so if you want to only generate for example 10 crosswords, you need to request that, and then request a [CrosswordGenerationRequest::Stop] to stop the generator.
+    /// 
+    /// A stop is honored right after the crosswords requested before it are generated, even if the search didn't find the next crossword yet, the generated crosswords can still be taken from the stream, and then it ends.
+    /// A pause is honored immediately too, but the stream doesn't end, the generation continues after a resume (check [CrosswordGenerationRequest::Pause]).
+    /// 
+    /// # Errors
+    /// 
+    /// [RequestError::GenerationFinished] - The generation is already finished (all crosswords are generated or it was stopped), stopping a finished generation is not an error.
+    pub async fn request_crossword(&self, req: CrosswordGenerationRequest) -> Result<(), RequestError>
+    {
+        send_request(&mut self.request_sender.clone(), &self.control, req).await
+    }
+
+    /// Splits the stream into a [requester](CrosswordRequester), that can be cloned and moved to other tasks, and a [receiver](CrosswordReceiver) of the crosswords.
+    /// 
+    /// The generation stops when the receiver is dropped, or when all the requesters are dropped and the requested crosswords are generated (then the receiver ends).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerationRequest, CrosswordGenerator};
+    /// # use crossword_generator::word::Word;
+    /// # use tokio_stream::StreamExt;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main()
+    /// # {
+    /// let mut generator = CrosswordGenerator::<u8, Vec<u8>>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s.as_bytes().to_vec(), None)).collect();
+    /// 
+    /// let (requester, receiver) = generator.crossword_stream_sorted(|w| w.to_vec()).split();
+    /// tokio::spawn(async move { requester.request(CrosswordGenerationRequest::Count(2)).await.unwrap(); });
+    /// 
+    /// assert_eq!(receiver.collect::<Vec<_>>().await.len(), 2);
+    /// # }
+    /// ```
+    pub fn split(self) -> (CrosswordRequester<CharT, StrT>, CrosswordReceiver<CharT, StrT>)
+    {
+        let CrosswordStream { request_sender, crossword_reciever, queue, received: _, finished: _, control } = self;
+        (CrosswordRequester { request_sender, control: control.clone() }, CrosswordReceiver { crossword_reciever, queue, control })
+    }
+
+    /// Returns the stream that requests the crosswords itself, one crossword every time the next one is polled, so it works like a lazy iterator, 
+    /// without the requests and the stop at the end (dropping it stops the generation).
+    /// 
+    /// The crosswords requested before are still generated.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::CrosswordGenerator;
+    /// # use crossword_generator::word::Word;
+    /// # use tokio_stream::StreamExt;
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main()
+    /// # {
+    /// let mut generator = CrosswordGenerator::<u8, Vec<u8>>::default();
+    /// generator.words = ["hello", "local", "cat"].into_iter().map(|s| Word::new(s.as_bytes().to_vec(), None)).collect();
+    /// 
+    /// let str = generator.crossword_stream_sorted(|w| w.to_vec()).into_auto();
+    /// assert_eq!(str.take(2).collect::<Vec<_>>().await.len(), 2);
+    /// # }
+    /// ```
+    pub fn into_auto(self) -> AutoCrosswordStream<CharT, StrT>
+    {
+        AutoCrosswordStream { stream: self, requested: false }
+    }
+
+}  
+
+// sends the request to the generation of the stream (check CrosswordStream::request_crossword)
+#[cfg(feature = "async")]
+async fn send_request<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(request_sender: &mut Sender<CrosswordGenerationRequest>, control: &StreamControl<CharT, StrT>, req: CrosswordGenerationRequest) -> Result<(), RequestError>
+{
+    // counted before sending, so the generation never takes an uncounted request
+    control.add_requested(&req);
+    match request_sender.send(req.clone()).await
+    {
+        Err(_) if req != CrosswordGenerationRequest::Stop => 
+        {
+            control.take_requested(&req);
+            Err(RequestError::GenerationFinished)
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Stream for CrosswordStream<CharT, StrT>
+{
+    type Item = Crossword<CharT, StrT>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>>
+    {
+        loop
+        {
+            if let Some(crossword) = self.queue.pop_front() { return Poll::Ready(Some(crossword)); }
+
+            let stream = &mut *self;
+            let Some(count) = ready!(stream.crossword_reciever.poll_into(cx, &mut stream.queue)) else { return Poll::Ready(None); };
+            stream.received += count;
+        }
+    }
+}
+
+/// A [CrosswordStream] that requests the crosswords itself (check [CrosswordStream::into_auto]).
+#[cfg(feature = "async")]
+pub struct AutoCrosswordStream<CharT: CrosswordChar + 'static, StrT: CrosswordString<CharT> + 'static>
+{
+    stream: CrosswordStream<CharT, StrT>,
+    // a crossword is requested and not received yet
+    requested: bool,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> AutoCrosswordStream<CharT, StrT>
+{
+    /// Returns the stream, for example for its [progress](CrosswordStream::progress).
+    pub fn get_ref(&self) -> &CrosswordStream<CharT, StrT>
+    {
+        &self.stream
+    }
+
+    /// Returns the stream, the crossword requested by this stream is still generated.
+    pub fn into_inner(self) -> CrosswordStream<CharT, StrT>
+    {
+        self.stream
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Stream for AutoCrosswordStream<CharT, StrT>
+{
+    type Item = Crossword<CharT, StrT>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>>
+    {
+        let auto = &mut *self;
+        if let Some(crossword) = auto.stream.queue.pop_front() { return Poll::Ready(Some(crossword)); }
+
+        // a full buffer of the requests wakes the stream when there is space in it, a finished generation ends the receiver
+        if !auto.requested && auto.stream.request_sender.poll_ready(cx).is_ready()
+        {
+            let req = CrosswordGenerationRequest::Count(1);
+            auto.stream.control.add_requested(&req);
+            auto.requested = auto.stream.request_sender.start_send(req.clone()).is_ok();
+            if !auto.requested { auto.stream.control.take_requested(&req); }
+        }
+
+        let polled = auto.stream.poll_next_unpin(cx);
+        if let Poll::Ready(Some(_)) = polled { auto.requested = false; }
+        polled
+    }
+}
+
+/// The cloneable half of a [CrosswordStream] that requests the crosswords (check [CrosswordStream::split]).
+#[cfg(feature = "async")]
+pub struct CrosswordRequester<CharT: CrosswordChar + 'static, StrT: CrosswordString<CharT> + 'static>
+{
+    request_sender: Sender<CrosswordGenerationRequest>,
+    control: Arc<StreamControl<CharT, StrT>>,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Clone for CrosswordRequester<CharT, StrT>
+{
+    fn clone(&self) -> Self
+    {
+        CrosswordRequester { request_sender: self.request_sender.clone(), control: self.control.clone() }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordRequester<CharT, StrT>
+{
+    /// Requests crosswords to generate, the same as [CrosswordStream::request_crossword].
+    /// 
+    /// # Errors
+    /// 
+    /// [RequestError::GenerationFinished] - The generation is already finished (all crosswords are generated or it was stopped), stopping a finished generation is not an error.
+    pub async fn request(&self, req: CrosswordGenerationRequest) -> Result<(), RequestError>
+    {
+        send_request(&mut self.request_sender.clone(), &self.control, req).await
+    }
+
+    /// Requests crosswords to generate without waiting for space in the buffer of the requests (check [request_buffer](CrosswordGeneratorSettings::request_buffer)).
+    /// 
+    /// Every requester can add one request above the size of the buffer, then its requests are rejected until the generation takes a request.
+    /// 
+    /// # Errors
+    /// 
+    /// [RequestError::GenerationFinished] - The generation is already finished (all crosswords are generated or it was stopped), stopping a finished generation is not an error.
+    /// 
+    /// [RequestError::RequestBufferFull] - The buffer of the requests is full, the request is not sent.
+    pub fn try_request(&mut self, req: CrosswordGenerationRequest) -> Result<(), RequestError>
+    {
+        self.control.add_requested(&req);
+        match self.request_sender.try_send(req.clone())
+        {
+            Err(error) if error.is_disconnected() && req == CrosswordGenerationRequest::Stop => Ok(()),
+            Err(error) =>
+            {
+                self.control.take_requested(&req);
+                Err(if error.is_full() { RequestError::RequestBufferFull } else { RequestError::GenerationFinished })
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// Returns the current state of the generation (check [CrosswordStream::state]).
+    pub fn state(&self) -> GeneratorState
+    {
+        self.control.state()
+    }
+
+    /// Returns the count of the requested crosswords that are not generated yet (check [CrosswordStream::pending_requested]).
+    pub fn pending_requested(&self) -> usize
+    {
+        self.control.pending_requested()
+    }
+}
+
+/// The half of a [CrosswordStream] that receives the generated crosswords (check [CrosswordStream::split]), it ends when the generation is finished.
+#[cfg(feature = "async")]
+pub struct CrosswordReceiver<CharT: CrosswordChar + 'static, StrT: CrosswordString<CharT> + 'static>
+{
+    crossword_reciever: CrosswordChannel<CharT, StrT>,
+    // the crosswords of the received batches (and the ones taken by the stream before the split), they are given before the ones in the receiver
+    queue: VecDeque<Crossword<CharT, StrT>>,
+    control: Arc<StreamControl<CharT, StrT>>,
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> CrosswordReceiver<CharT, StrT>
+{
+    /// Returns the current progress of the generation (check [CrosswordStream::progress]).
+    pub fn progress(&self) -> GenerationProgress
+    {
+        self.control.progress()
+    }
+
+    /// Returns the summary of the generation, after it's finished (check [CrosswordStream::summary]).
+    pub fn summary(&self) -> Option<GenerationSummary>
+    {
+        self.control.summary.get().cloned()
+    }
+
+    /// Returns the reason of the end of the generation, after it's finished (check [CrosswordStream::finished_reason]).
+    pub fn finished_reason(&self) -> Option<FinishReason>
+    {
+        self.control.finish_reason.get().copied()
+    }
+
+    /// Returns the report of a generation without crosswords (check [CrosswordStream::failure_report]).
+    pub fn failure_report(&self) -> Option<GenerationFailure<CharT, StrT>>
+    {
+        self.control.failure.get().cloned()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Stream for CrosswordReceiver<CharT, StrT>
+{
+    type Item = Crossword<CharT, StrT>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>>
+    {
+        loop
+        {
+            if let Some(crossword) = self.queue.pop_front() { return Poll::Ready(Some(crossword)); }
+
+            let receiver = &mut *self;
+            if ready!(receiver.crossword_reciever.poll_into(cx, &mut receiver.queue)).is_none() { return Poll::Ready(None); }
+        }
+    }
+
+    // the crosswords of the received batches are known, the ones in the buffer are not
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.queue.len(), self.crossword_reciever.is_terminated().then_some(self.queue.len()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> FusedStream for CrosswordReceiver<CharT, StrT>
+{
+    fn is_terminated(&self) -> bool
+    {
+        self.queue.is_empty() && self.crossword_reciever.is_terminated()
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod tests
+{
+    use crate::{crossword::CrosswordConstraint, word::{Direction, PlacementConstraint, WordMetadata}};
+
+    use super::*;
+
+    // the generation runs on its own thread, so the crosswords can be collected on any executor (and without the tokio feature)
+    async fn generate_all_sorted(generator: &CrosswordGenerator<u8, String>) -> Vec<Crossword<u8, String>>
+    {
+        let spawner = |future: BoxFuture<'static, ()>| { std::thread::spawn(move || futures::executor::block_on(future)); };
+        let str = generator.crossword_stream_sorted_with_spawner(spawner, |w| String::from_utf8(w.to_owned()).unwrap());
+        // without crosswords the generation can end before the request
+        let _ = str.request_crossword(CrosswordGenerationRequest::All).await;
+        str.collect().await
+    }
+
+    #[tokio::test]
+    async fn test_generator_exact_size_constraint()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "red"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        let all = generate_all_sorted(&generator).await;
+        let size = all[0].get_size();
+        let expected = all.iter().filter(|cw| cw.get_size() == size).cloned().collect::<BTreeSet<_>>();
+        assert!(expected.len() < all.len());
+
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::ExactSize(size.0, size.1)];
+        let constrained = generate_all_sorted(&generator).await;
+
+        assert!(constrained.iter().all(|cw| cw.get_size() == size));
+        assert_eq!(constrained.into_iter().collect::<BTreeSet<_>>(), expected);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_contains_word_constraint()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::ContainsWord(b"world".to_vec())];
+        let all = generate_all_sorted(&generator).await;
+        assert!(!all.is_empty());
+        assert!(all.iter().all(|cw| cw.find_word(&"world".to_owned()).is_some()));
+
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::ContainsWord(b"local".to_vec())];
+        assert!(generate_all_sorted(&generator).await.is_empty());
+
+        // the randomized stream leaves out the optional words in its orders, so the crosswords without the word are not searched further once it's left out
+        let search = |constraints: Vec<CrosswordConstraint<u8>>| 
+        {
+            let mut generator = CrosswordGenerator::<u8, String>
+            {
+                words: vec!["hello", "world", "local", "cat", "toy"].into_iter().map(|s| Word::new(s.to_owned(), None).with_required(false)).collect(),
+                ..Default::default()
+            };
+            generator.settings.emit_subsets = true;
+            generator.settings.max_concurrent_tasks = 1;
+            generator.settings.crossword_settings.constraints = constraints;
+            async move
+            {
+                let mut str = generator.crossword_stream_randomized(|w| String::from_utf8(w.to_owned()).unwrap());
+                str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+                let crosswords = str.by_ref().collect::<BTreeSet<_>>().await;
+                (crosswords, str.progress().placements_tried)
+            }
+        };
+        let (all, all_placements) = search(vec![]).await;
+        let (with_cat, with_cat_placements) = search(vec![CrosswordConstraint::ContainsWord(b"cat".to_vec())]).await;
+        assert!(!with_cat.is_empty() && with_cat.len() < all.len());
+        assert!(with_cat.iter().all(|cw| cw.find_word(&"cat".to_owned()).is_some()));
+        assert_eq!(with_cat, all.iter().filter(|cw| cw.find_word(&"cat".to_owned()).is_some()).cloned().collect());
+        assert!(with_cat_placements < all_placements);
+    }
+
+    #[tokio::test]
+    async fn test_generator_dedup_symmetric()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        let all = generate_all_sorted(&generator).await;
+
+        generator.settings.dedup_symmetric = true;
+        let deduped = generate_all_sorted(&generator).await;
+
+        assert!(deduped.len() < all.len());
+        assert!(deduped.iter().tuple_combinations().all(|(a, b)| !a.equivalent_under_symmetry(b)));
+        assert!(all.iter().all(|cw| deduped.iter().any(|d| d.equivalent_under_symmetry(cw))));
+    }
+
+    #[tokio::test]
+    async fn test_generator_hash_set()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        let all = generate_all_sorted(&generator).await;
+        let set = std::collections::HashSet::<Crossword<u8, String>>::from_iter(all.iter().cloned());
+        assert_eq!(set.len(), all.len());
+        assert!(all.iter().all(|cw| set.contains(cw)));
+
+        let generators = std::collections::HashSet::from([generator.clone(), generator]);
+        assert_eq!(generators.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_generator_allowed_directions()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.iter().flat_map(|cw| cw.iter()).all(|w| w.direction == Direction::Right || w.direction == Direction::Down));
+
+        generator.settings.word_compatibility_settings.allowed_directions = Direction::iter().collect();
+        let all_directions = generate_all_sorted(&generator).await;
+        assert!(all_directions.len() > all.len());
+        assert!(all.iter().all(|cw| all_directions.iter().any(|d| d.same_layout(cw))));
+        assert!(all_directions.iter().any(|cw| cw.iter().any(|w| w.direction == Direction::Left)));
+        assert!(all_directions.iter().any(|cw| cw.iter().any(|w| w.direction == Direction::Up)));
+        assert!(all_directions.iter().all(|cw| cw.validate().is_ok()));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_skips_invalid_words()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        assert!(generator.invalid_words().is_empty());
+        let expected = generate_all_sorted(&generator).await;
+
+        generator.words.insert(Word::default());
+        assert_eq!(generator.invalid_words(), vec![(&Word::default(), WordError::Empty)]);
+        assert_eq!(generate_all_sorted(&generator).await, expected);
+
+        let str = generator.crossword_stream_randomized(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        let randomized = str.collect::<BTreeSet<_>>().await;
+        assert_eq!(randomized, expected.into_iter().collect());
+    }
+
+    #[tokio::test]
+    async fn test_generator_word_metadata()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: [("hello", "Greeting"), ("world", "Earth"), ("low", "Not high")].into_iter()
+                .map(|(s, clue)| Word::from(s.to_owned()).with_clue(clue).with_tag("test"))
+                .collect(),
+            ..Default::default()
+        };
+        let clue = |value: &str| generator.words.iter().find(|w| w.value == value).and_then(|w| w.meta.clue.clone());
+
+        let all = generate_all_sorted(&generator).await;
+        assert!(!all.is_empty());
+        for cw in all
+        {
+            let entries = cw.numbered_entries();
+            assert_eq!(entries.len(), 3);
+            assert!(entries.iter().all(|e| e.word.meta.clue == clue(&e.word.value) && e.word.meta.tags.contains("test")));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_word_weight()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let first_horizontal = |cw: &Crossword<u8, String>| cw.iter().find(|w| w.direction == Direction::Right).unwrap().value.clone();
+
+        let all = generate_all_sorted(&generator).await;
+        assert_eq!(first_horizontal(&all[0]), "hello");
+
+        generator.words = generator.words.into_iter().map(|w| { let weight = if w.value == "world" { 1 } else { 0 }; w.with_weight(weight) }).collect();
+        let weighted = generate_all_sorted(&generator).await;
+        assert_eq!(first_horizontal(&weighted[0]), "world");
+        assert_eq!(weighted.iter().cloned().collect::<BTreeSet<_>>(), all.iter().cloned().collect());
+
+        let str = generator.crossword_stream_randomized(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::Count(1)).await.unwrap();
+        str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+        let randomized = str.collect::<Vec<_>>().await;
+        assert_eq!(first_horizontal(&randomized[0]), "world");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_optional_words()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let expected = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+
+        // an optional word that can't be placed doesn't break the generation
+        generator.words.insert(Word::new("xyz".to_owned(), None).with_required(false));
+        assert_eq!(generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>(), expected);
+
+        let str = generator.crossword_stream_randomized(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, expected);
+
+        // a required word that can't be placed does
+        generator.words = generator.words.into_iter().map(|w| w.with_required(true)).collect();
+        assert!(generate_all_sorted(&generator).await.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_emit_subsets()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let without_low = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        let low = Word::new("low".to_owned(), None).with_required(false);
+        generator.words.insert(low.clone());
+        
+        let maximal = generate_all_sorted(&generator).await;
+        assert!(maximal.iter().any(|cw| cw.len() == 3));
+        assert!(maximal.iter().filter(|cw| cw.len() == 2).all(|cw| cw.calculate_possible_ways_to_add_word(&low).is_empty()));
+        assert!(maximal.iter().tuple_combinations().all(|(a, b)| !a.contains_crossword(b) && !b.contains_crossword(a)));
+
+        generator.settings.emit_subsets = true;
+        let all = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        assert!(maximal.iter().all(|cw| all.contains(cw)));
+        assert_eq!(all.iter().filter(|cw| cw.len() == 2).cloned().collect::<BTreeSet<_>>(), without_low);
+
+        let str = generator.crossword_stream_randomized(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, all);
+    }
+
+    #[tokio::test]
+    async fn test_generator_category_constraints()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: [("hello", "greeting"), ("world", "greeting"), ("local", "place"), ("cat", "place")].into_iter()
+                .map(|(s, category)| Word::new(s.to_owned(), None).with_category(category).with_required(false)).collect(),
+            ..Default::default()
+        };
+        generator.settings.emit_subsets = true;
+        let all = generate_all_sorted(&generator).await;
+        let places = |cw: &Crossword<u8, String>| cw.iter().filter(|w| w.meta.has_tag("place")).count();
+
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::MaxWordsWithTag("place".to_owned(), 1)];
+        let at_most_one = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        assert!(!at_most_one.is_empty());
+        assert!(at_most_one.iter().all(|cw| places(cw) <= 1));
+        assert_eq!(at_most_one.len(), all.iter().filter(|cw| places(cw) <= 1).count());
+
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::MinWordsWithTag("place".to_owned(), 2)];
+        let at_least_two = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        assert!(!at_least_two.is_empty());
+        assert!(at_least_two.iter().all(|cw| places(cw) == 2));
+        assert_eq!(at_least_two.len(), all.iter().filter(|cw| places(cw) >= 2).count());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_randomized_seeded()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let generate = |seed| 
+        {
+            let str = generator.crossword_stream_randomized_seeded(seed, |w| String::from_utf8(w.to_owned()).unwrap());
+            async move
+            {
+                str.request_crossword(CrosswordGenerationRequest::Count(10)).await.unwrap();
+                str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+                str.collect::<Vec<_>>().await
+            }
+        };
+
+        let first = generate(42).await;
+        assert_eq!(first.len(), 10);
+        assert_eq!(generate(42).await, first);
+        // another seed starts with another crossword (the sampled orders of seed 7 start with the same one)
+        assert_ne!(generate(3).await[0], first[0]);
+
+        // the order changes, but not the crosswords
+        let all = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        for seed in [1, 2]
+        {
+            let str = generator.crossword_stream_randomized_seeded(seed, |w| String::from_utf8(w.to_owned()).unwrap());
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert_eq!(str.collect::<BTreeSet<_>>().await, all);
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_placement_order()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let first_five = |generator: &CrosswordGenerator<u8, String>| 
+        {
+            let str = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap());
+            async move
+            {
+                str.request_crossword(CrosswordGenerationRequest::Count(5)).await.unwrap();
+                str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+                str.collect::<Vec<_>>().await
+            }
+        };
+
+        let all = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        let by_stream = first_five(&generator).await;
+
+        generator.settings.placement_order = PlacementOrder::Sorted;
+        let sorted = first_five(&generator).await;
+        assert!(sorted.iter().zip(&by_stream).all(|(a, b)| a.same_layout(b)));
+
+        generator.settings.placement_order = PlacementOrder::Shuffled(3);
+        let shuffled = first_five(&generator).await;
+        assert!(!shuffled.iter().zip(&sorted).all(|(a, b)| a.same_layout(b)));
+        assert!(shuffled.iter().zip(first_five(&generator).await).all(|(a, b)| a.same_layout(&b)));
+
+        // the order changes, but not the crosswords
+        for order in [PlacementOrder::Shuffled(3), PlacementOrder::Scored]
+        {
+            generator.settings.placement_order = order;
+            assert_eq!(generate_all_sorted(&generator).await.iter().map(|cw| cw.iter().cloned().collect::<BTreeSet<_>>()).collect::<BTreeSet<_>>(), 
+                       all.iter().map(|cw| cw.iter().cloned().collect::<BTreeSet<_>>()).collect::<BTreeSet<_>>());
+        }
+    }
+
+    #[test]
+    fn test_generator_generate_iter()
+    {
+        let words = |words: Vec<&str>| words.into_iter().map(|s| Word::new(s.to_owned(), None)).collect::<BTreeSet<_>>();
+        let mut generators = vec![CrosswordGenerator::<u8, String> { words: words(vec!["hello", "world", "low", "lord", "do"]), ..Default::default() }];
+
+        let mut generator = CrosswordGenerator::<u8, String> { words: words(vec!["hello", "world", "local"]), ..Default::default() };
+        generator.words.insert(Word::new("low".to_owned(), None).with_required(false).with_weight(2));
+        generator.settings.emit_subsets = true;
+        generators.push(generator.clone());
+        generator.settings.dedup_symmetric = true;
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::MaxArea(40)];
+        generator.settings.placement_order = PlacementOrder::Shuffled(5);
+        generators.push(generator);
+
+        for generator in generators
+        {
+            let crosswords = futures::executor::block_on(generate_all_sorted(&generator));
+            assert!(!crosswords.is_empty());
+            assert_eq!(generator.generate_iter().collect::<Vec<_>>(), crosswords);
+            assert_eq!(generator.generate_n(3), crosswords[..3]);
+        }
+
+        assert_eq!(CrosswordGenerator::<u8, String>::default().generate_iter().collect::<Vec<_>>(), vec![Crossword::default()]);
+    }
+
+    #[test]
+    fn test_generator_custom_spawner()
+    {
+        use futures::{executor::{block_on, LocalPool}, task::SpawnExt};
+
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let expected = generator.generate_iter().collect::<Vec<_>>();
+
+        // a single threaded executor of futures, without tokio
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        let str = generator.crossword_stream_sorted_with_spawner(|future| spawner.spawn(future).unwrap(), |w| String::from_utf8(w.to_owned()).unwrap());
+        let sorted = pool.run_until(async move
+        {
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            str.collect::<Vec<_>>().await
+        });
+        assert_eq!(sorted, expected);
+
+        // the generation tasks of the randomized stream must be sendable, so they run in their own threads here
+        let str = generator.crossword_stream_randomized_with_spawner(0, |future| { std::thread::spawn(move || block_on(future)); }, |w| String::from_utf8(w.to_owned()).unwrap());
+        let randomized = block_on(async move
+        {
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            str.collect::<BTreeSet<_>>().await
+        });
+        assert_eq!(randomized, expected.into_iter().collect());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_stream_drop()
+    {
+        use std::{panic::AssertUnwindSafe, sync::atomic::{AtomicUsize, Ordering}};
+        use futures::FutureExt;
+
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        // counts the spawned, the finished and the panicked tasks
+        let counts = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0), AtomicUsize::new(0)]);
+        let spawner = 
+        {
+            let counts = counts.clone();
+            move |future: BoxFuture<'static, ()>|
+            {
+                let counts = counts.clone();
+                counts[0].fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move
+                {
+                    if AssertUnwindSafe(future).catch_unwind().await.is_err() { counts[2].fetch_add(1, Ordering::SeqCst); }
+                    counts[1].fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        };
+
+        let mut sorted = generator.crossword_stream_sorted_with_spawner(spawner.clone(), |w| String::from_utf8(w.to_owned()).unwrap());
+        sorted.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert!(sorted.next().await.is_some());
+        drop(sorted);
+
+        let mut randomized = generator.crossword_stream_randomized_with_spawner(0, spawner, |w| String::from_utf8(w.to_owned()).unwrap());
+        randomized.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert!(randomized.next().await.is_some());
+        drop(randomized);
+
+        for _ in 0..10000
+        {
+            if counts[1].load(Ordering::SeqCst) == counts[0].load(Ordering::SeqCst) { break; }
+            tokio::task::yield_now().await;
+        }
+        assert!(counts[0].load(Ordering::SeqCst) > 2);
+        assert_eq!(counts[1].load(Ordering::SeqCst), counts[0].load(Ordering::SeqCst));
+        assert_eq!(counts[2].load(Ordering::SeqCst), 0);
+
+        // a finished generation can't take requests, except stopping
+        let mut str = CrosswordGenerator::<u8, String>::default().crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        while str.next().await.is_some() {}
+        assert_eq!(str.request_crossword(CrosswordGenerationRequest::Count(1)).await, Err(RequestError::GenerationFinished));
+        assert_eq!(str.request_crossword(CrosswordGenerationRequest::Stop).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_generator_stream_cancellation()
+    {
+        use std::{sync::atomic::{AtomicUsize, Ordering}, time::{Duration, Instant}};
+
+        // too many crosswords to generate all of them in the test
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "yellow", "lord", "do", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        // counts the spawned and the finished tasks
+        let counts = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+        let spawner = 
+        {
+            let counts = counts.clone();
+            move |future: BoxFuture<'static, ()>|
+            {
+                let counts = counts.clone();
+                counts[0].fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move
+                {
+                    future.await;
+                    counts[1].fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        };
+        let all_finished = || async
+        {
+            let start = Instant::now();
+            while counts[1].load(Ordering::SeqCst) < counts[0].load(Ordering::SeqCst) && start.elapsed() < Duration::from_secs(5)
+            {
+                tokio::task::yield_now().await;
+            }
+            counts[1].load(Ordering::SeqCst) == counts[0].load(Ordering::SeqCst)
+        };
+
+        for shutdown in [false, true]
+        {
+            let mut sorted = generator.crossword_stream_sorted_with_spawner(spawner.clone(), |w| String::from_utf8(w.to_owned()).unwrap());
+            sorted.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert!(sorted.next().await.is_some());
+
+            let mut randomized = generator.crossword_stream_randomized_with_spawner(0, spawner.clone(), |w| String::from_utf8(w.to_owned()).unwrap());
+            randomized.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert!(randomized.next().await.is_some());
+
+            let start = Instant::now();
+            if shutdown
+            {
+                sorted.shutdown().await;
+                randomized.shutdown().await;
+            }
+            else 
+            {
+                drop(sorted);
+                drop(randomized);
+            }
+            assert!(all_finished().await);
+            assert!(start.elapsed() < Duration::from_secs(1));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_responsive_stop()
+    {
+        use std::time::{Duration, Instant};
+
+        // far too many partial crosswords to search through all of them
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["alpha", "beta", "gamma", "delta", "epsilon", "theta", "lambda", "sigma", "omega", "kappa", "zeta", "iota"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let stream = |generator: &CrosswordGenerator<u8, String>, randomized: bool| match randomized
+        {
+            false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+            true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+        };
+        let stop_and_drain = |mut str: CrosswordStream<u8, String>| async move
+        {
+            let start = Instant::now();
+            str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+            let mut count = 0;
+            while str.next().await.is_some() { count += 1; }
+            assert!(start.elapsed() < Duration::from_secs(1));
+            count
+        };
+
+        // stopping while all the crosswords are requested
+        for randomized in [false, true]
+        {
+            let mut str = stream(&generator, randomized);
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert!(str.next().await.is_some());
+            stop_and_drain(str).await;
+        }
+
+        // the crosswords requested before the stop are generated first, even if the generation didn't take the request yet
+        for randomized in [false, true]
+        {
+            let str = stream(&generator, randomized);
+            str.request_crossword(CrosswordGenerationRequest::Count(3)).await.unwrap();
+            assert_eq!(stop_and_drain(str).await, 3);
+        }
+
+        // no crossword is ever found, the stop must be seen in the middle of the search
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::MinWordsWithTag("missing".to_owned(), 1)];
+        for randomized in [false, true]
+        {
+            let str = stream(&generator, randomized);
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            for _ in 0..10 { tokio::task::yield_now().await; }
+            assert_eq!(stop_and_drain(str).await, 0);
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_concurrency_and_buffers()
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        assert_eq!(generator.settings.validate(), Ok(()));
+        assert_eq!((generator.settings.max_concurrent_tasks, generator.settings.crossword_buffer, generator.settings.request_buffer), (10, 100, 100));
+        assert_eq!(serde_json::from_str::<CrosswordGeneratorSettings<u8>>(&serde_json::to_string(&CrosswordGeneratorSettings::<u8>::default()).unwrap().replace(",\"max_concurrent_tasks\":10", "")).unwrap(), CrosswordGeneratorSettings::default());
+
+        // with a buffer of one crossword the generator waits for every crossword to be taken
+        generator.settings.crossword_buffer = 1;
+        let converted = Arc::new(AtomicUsize::new(0));
+        let counter = converted.clone();
+        let mut str = generator.crossword_stream_sorted(move |w| { counter.fetch_add(1, Ordering::SeqCst); String::from_utf8(w.to_owned()).unwrap() });
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        for taken in 0..3
+        {
+            for _ in 0..100 { tokio::task::yield_now().await; }
+            // only the crossword in the buffer is generated, the words of the crosswords are converted once
+            assert_eq!(str.progress().crosswords, taken + 1);
+            assert_eq!(converted.load(Ordering::SeqCst), generator.words.len());
+            assert!(str.next().await.is_some());
+        }
+
+        // counts the running tasks (with the task of the stream) and the maximum of that
+        let counts = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+        let spawner = 
+        {
+            let counts = counts.clone();
+            move |future: BoxFuture<'static, ()>|
+            {
+                let counts = counts.clone();
+                counts[1].fetch_max(counts[0].fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                tokio::spawn(async move
+                {
+                    future.await;
+                    counts[0].fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        };
+        for (tasks, max_running) in [(1, 2), (3, 4)]
+        {
+            generator.settings.max_concurrent_tasks = tasks;
+            counts[1].store(0, Ordering::SeqCst);
+            let str = generator.crossword_stream_randomized_with_spawner(0, spawner.clone(), |w| String::from_utf8(w.to_owned()).unwrap());
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert_eq!(str.collect::<BTreeSet<_>>().await, generate_all_sorted(&generator).await.into_iter().collect());
+            assert_eq!(counts[1].load(Ordering::SeqCst), max_running);
+        }
+
+        generator.settings.max_concurrent_tasks = 0;
+        assert_eq!(generator.settings.validate(), Err(GeneratorSettingsError::ZeroMaxConcurrentTasks));
+        generator.settings.max_concurrent_tasks = 1;
+        generator.settings.crossword_buffer = 0;
+        assert_eq!(generator.settings.validate(), Err(GeneratorSettingsError::ZeroCrosswordBuffer));
+        generator.settings.crossword_buffer = 1;
+        generator.settings.request_buffer = 0;
+        assert_eq!(generator.settings.validate(), Err(GeneratorSettingsError::ZeroRequestBuffer));
+        generator.settings.request_buffer = 1;
+        generator.settings.emit_batch_size = 0;
+        assert_eq!(generator.settings.validate(), Err(GeneratorSettingsError::ZeroEmitBatchSize));
+
+        // zero is treated as one
+        generator.settings.max_concurrent_tasks = 0;
+        generator.settings.crossword_buffer = 0;
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, generate_all_sorted(&generator).await.into_iter().collect());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_best_crosswords()
+    {
+        use crate::scoring::CrosswordScorer;
+
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let scorer = CrosswordScorer { area: 0.1, ..Default::default() };
+        let scores = |crosswords: &[Crossword<u8, String>]| crosswords.iter().map(|cw| scorer.score(cw)).collect::<Vec<_>>();
+        let is_sorted = |crosswords: &[Crossword<u8, String>]| scores(crosswords).windows(2).all(|pair| pair[0] >= pair[1]);
+
+        let all = generator.generate_iter().collect::<Vec<_>>();
+        let mut all_scores = scores(&all);
+        all_scores.sort_by(|a, b| b.total_cmp(a));
+
+        let best = generator.best_crosswords(5, &scorer, GenerationBudget::new());
+        assert_eq!(scores(&best), all_scores[..5]);
+        assert!(best.iter().all(|cw| all.contains(cw)));
+        assert_eq!(generator.best_crosswords(all.len() + 10, &scorer, GenerationBudget::new()).len(), all.len());
+        assert!(generator.best_crosswords(0, &scorer, GenerationBudget::new()).is_empty());
+
+        // only the first candidates are examined
+        let first = generator.best_crosswords(5, &scorer, GenerationBudget::new().max_candidates(8));
+        assert!(is_sorted(&first));
+        assert_eq!(first.iter().cloned().collect::<BTreeSet<_>>(), generator.best_crosswords(8, &scorer, GenerationBudget::new().max_candidates(8)).into_iter().take(5).collect());
+        assert!(first.iter().all(|cw| all[..8].contains(cw)));
+        assert!(generator.best_crosswords(5, &scorer, GenerationBudget::new().max_duration(Duration::ZERO)).is_empty());
+
+        let best_async = |seed, budget| generator.best_crosswords_async(5, &scorer, budget, seed, |w| String::from_utf8(w.to_owned()).unwrap());
+        let randomized = best_async(42, GenerationBudget::new().max_candidates(20)).await;
+        assert_eq!(randomized.len(), 5);
+        assert!(is_sorted(&randomized));
+        assert_eq!(best_async(42, GenerationBudget::new().max_candidates(20)).await, randomized);
+        assert_eq!(scores(&best_async(7, GenerationBudget::new()).await), all_scores[..5]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_limits()
+    {
+        use std::time::{Duration, Instant};
+
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "yellow", "lord", "do", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let stream = |generator: &CrosswordGenerator<u8, String>, randomized: bool| match randomized
+        {
+            false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+            true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+        };
+        let generate_all = |mut str: CrosswordStream<u8, String>| async move
+        {
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            let mut crosswords = vec![];
+            while let Some(cw) = str.next().await { crosswords.push(cw); }
+            (crosswords, str.summary().unwrap())
+        };
+
+        generator.settings.limits = GenerationLimits { max_crosswords: Some(3), ..Default::default() };
+        for randomized in [false, true]
+        {
+            let (crosswords, summary) = generate_all(stream(&generator, randomized)).await;
+            assert_eq!(crosswords.len(), 3);
+            assert_eq!((summary.crosswords, summary.limit_reached), (3, Some(GenerationLimit::Crosswords)));
+        }
+        assert_eq!(generator.generate_iter().count(), 3);
+
+        // too few placements to find any crossword
+        generator.settings.limits = GenerationLimits { max_placements_tried: Some(5), ..Default::default() };
+        for randomized in [false, true]
+        {
+            let (crosswords, summary) = generate_all(stream(&generator, randomized)).await;
+            assert!(crosswords.is_empty());
+            assert_eq!(summary.limit_reached, Some(GenerationLimit::PlacementsTried));
+            assert!(summary.placements_tried >= 5);
+        }
+        assert_eq!(generator.generate_iter().count(), 0);
+
+        // no crossword is ever found, so only the duration ends the search
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::MinWordsWithTag("missing".to_owned(), 1)];
+        generator.settings.limits = GenerationLimits { max_duration: Some(Duration::from_millis(50)), ..Default::default() };
+        for randomized in [false, true]
+        {
+            let start = Instant::now();
+            let (crosswords, summary) = generate_all(stream(&generator, randomized)).await;
+            assert!(start.elapsed() < Duration::from_secs(1));
+            assert!(crosswords.is_empty());
+            assert_eq!(summary.limit_reached, Some(GenerationLimit::Duration));
+            assert!(summary.duration >= Duration::from_millis(50));
+        }
+
+        // without limits
+        generator = CrosswordGenerator { words: vec!["hello", "world"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(), ..Default::default() };
+        for randomized in [false, true]
+        {
+            let str = stream(&generator, randomized);
+            assert_eq!(str.summary(), None);
+            let (crosswords, summary) = generate_all(str).await;
+            assert_eq!((summary.crosswords, summary.limit_reached), (crosswords.len(), None));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_progress()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        for randomized in [false, true]
+        {
+            let mut str = match randomized
+            {
+                false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+                true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+            };
+            str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+            let first = (&mut str).take(2).collect::<Vec<_>>().await;
+            assert!(str.progress().crosswords >= first.len());
+
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            let mut received = first.len();
+            while str.next().await.is_some() { received += 1; }
+
+            let progress = str.progress();
+            assert_eq!(progress.crosswords, received);
+            assert!(progress.placements_tried >= progress.backtracks && progress.backtracks > 0);
+            assert_eq!(progress.placements_tried, str.summary().unwrap().placements_tried);
+            assert_eq!(progress.elapsed, str.summary().unwrap().duration);
+            match randomized
+            {
+                false => assert_eq!((progress.permutations_started, progress.permutations_completed), (0, 0)),
+                true => assert_eq!((progress.permutations_started, progress.permutations_completed), (24, 24)),
+            }
+        }
+
+        let str = CrosswordStream::<u8, String>::new(|_, _| async {});
+        assert_eq!(str.progress(), GenerationProgress::default());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_failure_report()
+    {
+        // xyz and qqq share no letters with the other words
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "local", "xyz", "qqq"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let stream = |generator: &CrosswordGenerator<u8, String>, randomized: bool| match randomized
+        {
+            false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+            true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+        };
+        let generate_all = |mut str: CrosswordStream<u8, String>| async move
+        {
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            let mut crosswords = vec![];
+            while let Some(cw) = str.next().await { crosswords.push(cw); }
+            (crosswords, str.failure_report())
+        };
+
+        // not collected by default
+        assert_eq!(generate_all(stream(&generator, false)).await, (vec![], None));
+
+        generator.settings.failure_report = true;
+        for randomized in [false, true]
+        {
+            let (crosswords, report) = generate_all(stream(&generator, randomized)).await;
+            assert!(crosswords.is_empty());
+            let report = report.unwrap();
+            assert_eq!(report.unplaceable_words, vec!["qqq", "xyz"]);
+            assert_eq!(report.largest_crossword.len(), 2);
+            assert!(report.largest_crossword.find_word(&"hello".to_owned()).is_some());
+            assert!(report.word_placements.iter().all(|(w, count)| (*count > 0) == (w == "hello" || w == "local")));
+            assert_eq!(report.most_rejecting_constraint(), None);
+        }
+        assert_eq!(generator.failure_report().unwrap().unplaceable_words, vec!["qqq", "xyz"]);
+
+        // every crossword with both words is too big
+        generator.words = vec!["hello", "local"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect();
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::Connected, CrosswordConstraint::MaxArea(20)];
+        for randomized in [false, true]
+        {
+            let report = generate_all(stream(&generator, randomized)).await.1.unwrap();
+            assert!(report.unplaceable_words.is_empty());
+            assert_eq!(report.most_rejecting_constraint(), Some(&CrosswordConstraint::MaxArea(20)));
+            assert_eq!(report.largest_crossword.len(), 1);
+        }
+
+        // no report if a crossword is generated
+        generator.settings.crossword_settings.constraints.clear();
+        for randomized in [false, true]
+        {
+            let (crosswords, report) = generate_all(stream(&generator, randomized)).await;
+            assert!(!crosswords.is_empty());
+            assert_eq!(report, None);
+        }
+        assert_eq!(generator.failure_report(), None);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_pause()
+    {
+        use futures::FutureExt;
+
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        generator.settings.crossword_buffer = 1;
+        let stream = |generator: &CrosswordGenerator<u8, String>, randomized: bool| match randomized
+        {
+            false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+            true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+        };
+        let all = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        assert!(all.len() > 10);
+
+        for randomized in [false, true]
+        {
+            let mut str = stream(&generator, randomized);
+            assert_eq!(str.state(), GeneratorState::Generating);
+            str.request_crossword(CrosswordGenerationRequest::Count(3)).await.unwrap();
+            let mut crosswords = str.by_ref().take(3).collect::<Vec<_>>().await;
+            for _ in 0..1000
+            {
+                if str.state() == GeneratorState::Idle { break; }
+                tokio::task::yield_now().await;
+            }
+            assert_eq!(str.state(), GeneratorState::Idle);
+
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            crosswords.push(str.next().await.unwrap());
+            str.request_crossword(CrosswordGenerationRequest::Pause).await.unwrap();
+            for _ in 0..1000
+            {
+                if str.state() == GeneratorState::Paused { break; }
+                tokio::task::yield_now().await;
+            }
+            assert_eq!(str.state(), GeneratorState::Paused);
+
+            // only the crosswords already in the buffer arrive while paused
+            while let Some(Some(cw)) = str.next().now_or_never() { crosswords.push(cw); }
+            assert!(crosswords.len() <= 5);
+            for _ in 0..100
+            {
+                tokio::task::yield_now().await;
+                assert_eq!(str.next().now_or_never(), None);
+            }
+            assert_eq!(str.state(), GeneratorState::Paused);
+
+            str.request_crossword(CrosswordGenerationRequest::Resume).await.unwrap();
+            while let Some(cw) = str.next().await { crosswords.push(cw); }
+            assert_eq!(str.state(), GeneratorState::Finished);
+
+            let count = crosswords.len();
+            assert_eq!(crosswords.into_iter().collect::<BTreeSet<_>>(), all);
+            assert_eq!(count, all.len());
+        }
+
+        // a count resumes the generation too
+        let mut str = stream(&generator, false);
+        str.request_crossword(CrosswordGenerationRequest::Pause).await.unwrap();
+        str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+        assert_eq!(str.by_ref().take(2).count().await, 2);
+        str.shutdown().await;
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_many_words()
+    {
+        // a chain of words, each crossing the next one, every word is a level of the search, so it's 20 levels deep
+        let letters = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOP";
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: (0..20).map(|i| Word::new(letters[2 * i..2 * i + 3].to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        assert!(generator.generate_iter().next().is_some_and(|cw| cw.len() == 20));
+
+        let mut str = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::Count(1)).await.unwrap();
+        assert!(str.next().await.is_some_and(|cw| cw.len() == 20));
+        str.shutdown().await;
+
+        // most orders of the words can't be a chain, so with optional words every task still searches through all the levels
+        generator.words = generator.words.into_iter().map(|w| w.with_required(false)).collect();
+        generator.settings.emit_subsets = true;
+        let mut str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::Count(5)).await.unwrap();
+        let crosswords = str.by_ref().take(5).collect::<Vec<_>>().await;
+        assert_eq!(crosswords.len(), 5);
+        assert!(crosswords.iter().all(|cw| !cw.is_empty() && cw.is_connected()));
+        str.shutdown().await;
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_checkpoint()
+    {
+        let convert = |w: &[u8]| String::from_utf8(w.to_owned()).unwrap();
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        generator.settings.crossword_buffer = 2;
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.len() > 10);
+
+        // some of the requested crosswords are still in the buffer or wait for space in it
+        for requested in [3, 5, 8]
+        {
+            let mut str = generator.crossword_stream_sorted(convert);
+            str.request_crossword(CrosswordGenerationRequest::Count(requested)).await.unwrap();
+            let mut crosswords = str.by_ref().take(3).collect::<Vec<_>>().await;
+            let checkpoint = str.checkpoint().await.unwrap();
+
+            // the checkpointed stream continues too
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert_eq!(str.collect::<Vec<_>>().await, all[3..]);
+
+            let json = serde_json::to_string(&checkpoint).unwrap();
+            let checkpoint = serde_json::from_str::<GeneratorCheckpoint<u8, String>>(&json).unwrap();
+            let mut resumed_generator = generator.clone();
+            resumed_generator.settings.crossword_buffer = 100;
+            let resumed = resumed_generator.resume_stream(checkpoint, convert).unwrap();
+            resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            crosswords.extend(resumed.collect::<Vec<_>>().await);
+            assert_eq!(crosswords, all);
+        }
+
+        // a checkpoint without the searched crosswords (written before they were kept) still continues the search, 
+        // but the crosswords reached before the checkpoint with other orders of the words are found again
+        let mut str = generator.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::Count(3)).await.unwrap();
+        let mut crosswords = str.by_ref().take(3).collect::<Vec<_>>().await;
+        let mut json = serde_json::to_string(&str.checkpoint().await.unwrap()).unwrap();
+        let start = json.find(r#""searched_crosswords":"#).unwrap();
+        let mut depth = 0;
+        let end = json[start..].find(|c| { match c { '{' | '[' => depth += 1, '}' | ']' => depth -= 1, _ => {} } c == ',' && depth == 0 }).unwrap();
+        json.replace_range(start..=start + end, "");
+        let resumed = generator.resume_stream(serde_json::from_str(&json).unwrap(), convert).unwrap();
+        resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        crosswords.extend(resumed.collect::<Vec<_>>().await);
+        assert!(crosswords.len() > all.len());
+        assert_eq!(crosswords.iter().unique().collect::<Vec<_>>(), all.iter().collect::<Vec<_>>());
+
+        // the hashes of the canonical crosswords are kept in the checkpoint
+        let mut symmetric = generator.clone();
+        symmetric.settings.dedup_symmetric = true;
+        let mut str = symmetric.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::Count(3)).await.unwrap();
+        let mut crosswords = str.by_ref().take(3).collect::<Vec<_>>().await;
+        let json = serde_json::to_string(&str.checkpoint().await.unwrap()).unwrap();
+        let resumed = symmetric.resume_stream(serde_json::from_str(&json).unwrap(), convert).unwrap();
+        resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        crosswords.extend(resumed.collect::<Vec<_>>().await);
+        assert_eq!(crosswords, generate_all_sorted(&symmetric).await);
+
+        // a checkpoint of a finished generation has nothing to continue
+        let mut str = generator.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.by_ref().collect::<Vec<_>>().await, all);
+        let checkpoint = str.checkpoint().await.unwrap();
+        let resumed = generator.resume_stream(checkpoint, convert).unwrap();
+        resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert!(resumed.collect::<Vec<_>>().await.is_empty());
+
+        // a checkpoint of a paused generation
+        let mut str = generator.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        let mut crosswords = str.by_ref().take(4).collect::<Vec<_>>().await;
+        str.request_crossword(CrosswordGenerationRequest::Pause).await.unwrap();
+        let checkpoint = str.checkpoint().await.unwrap();
+        assert_eq!(str.state(), GeneratorState::Paused);
+        str.shutdown().await;
+        let resumed = generator.resume_stream(checkpoint.clone(), convert).unwrap();
+        resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        crosswords.extend(resumed.collect::<Vec<_>>().await);
+        assert_eq!(crosswords, all);
+
+        // the words and the settings of the search must be the same
+        let mut other = generator.clone();
+        other.words.insert(Word::new("cow".to_owned(), None));
+        assert!(matches!(other.resume_stream(checkpoint.clone(), convert), Err(ResumeError::WordsMismatch)));
+        let mut other = generator.clone();
+        other.settings.dedup_symmetric = true;
+        assert!(matches!(other.resume_stream(checkpoint.clone(), convert), Err(ResumeError::SettingsMismatch)));
+        let mut invalid = checkpoint;
+        invalid.state.current_crossword.push(PlacementState { word: 100, position: Position { x: 0, y: 0 }, direction: Direction::Right });
+        assert!(matches!(generator.resume_stream(invalid, convert), Err(ResumeError::InvalidCheckpoint)));
+
+        // the randomized stream can't be checkpointed
+        let mut str = generator.crossword_stream_randomized_seeded(0, convert);
+        assert!(str.checkpoint().await.is_none());
+    }
+
+    #[test]
+    fn test_generator_checkpoint_positions()
+    {
+        let generator = CrosswordGenerator::<u8, &str>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord", "low"].into_iter().map(|s| Word::new(s, None)).collect(),
+            ..Default::default()
+        };
+        let words = generator.checkpoint_words();
+
+        // searches until the current crossword is shifted from its normalized position
+        let mut generation = SortedGeneration::new(&generator);
+        while generation.stack.len() < 3 || generation.current_crossword.min_corner() == (0, 0)
+        {
+            assert!(!matches!(generation.step(), Poll::Ready(None)));
+        }
+        let state = generation.state(&words);
+
+        // the positions are the ones with the crosswords normalized after every step
+        let placement = |p: &PlacedWord<u8, &[u8]>| PlacementState { word: words.iter().position(|w| w.value == p.value).unwrap(), position: p.position.clone(), direction: p.direction.clone() };
+        let mut crossword = Crossword::<u8, &[u8]>::default();
+        for frame in state.stack.iter()
+        {
+            let word = &words[frame.word.unwrap()];
+            assert_eq!(frame.placements, crossword.calculate_possible_ways_to_add_word(word).iter().map(placement).collect::<Vec<_>>());
+            let Some(placed) = &frame.placed else { break; };
+            crossword.add_word(PlacedWord::new(word.value, placed.position.clone(), placed.direction.clone())).unwrap();
+        }
+        assert_eq!(state.current_crossword, crossword.iter().map(placement).collect::<Vec<_>>());
+
+        // the resumed search has the same state, and generates the same crosswords
+        let mut resumed = SortedGeneration::from_state(&generator, &state).unwrap();
+        assert_eq!(resumed.state(&words), state);
+        assert_eq!(resumed.by_ref().collect::<Vec<_>>(), generation.by_ref().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_allow_unplaced_words()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "local", "cat", "xyz"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        // xyz has no common characters with the other words
+        assert!(generate_all_sorted(&generator).await.is_empty());
+
+        generator.settings.allow_unplaced_words = true;
+        let all = generate_all_sorted(&generator).await;
+        // the other words can't be added to xyz either
+        let (alone, connected): (Vec<_>, Vec<_>) = all.iter().cloned().partition(|cw| cw.len() == 1);
+        assert_eq!(alone.len(), 1);
+        assert_eq!(generator.with_unused_words(alone[0].clone()).unused_words.len(), 3);
+        assert!(!connected.is_empty());
+        for cw in connected.iter().cloned()
+        {
+            assert_eq!(cw.len(), 3);
+            assert_eq!(generator.with_unused_words(cw).unused_words, vec![Word::new("xyz".to_owned(), None)]);
+        }
+        let all = all.into_iter().collect::<BTreeSet<_>>();
+
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        let randomized = str.collect::<Vec<_>>().await;
+        assert_eq!(randomized.len(), all.len());
+        assert_eq!(randomized.into_iter().collect::<BTreeSet<_>>(), all);
+
+        // a word that can be placed is still required
+        generator.words = vec!["hello", "local", "cat"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect();
+        let without_xyz = generate_all_sorted(&generator).await.into_iter().collect::<BTreeSet<_>>();
+        assert_eq!(without_xyz, connected.into_iter().collect());
+    }
+
+    #[test]
+    fn test_generator_unused_words_with_same_values()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec![Word::new("hello".to_owned(), None), Word::new("hello".to_owned(), Some(Direction::Down)), Word::new("local".to_owned(), None), Word::new("xyz".to_owned(), None)].into_iter().collect(),
+            ..Default::default()
+        };
+        generator.settings.allow_unplaced_words = true;
+
+        let generated = generator.generate_iter_with_unused_words().collect::<Vec<_>>();
+        assert_eq!(generated.iter().map(|g| g.crossword.clone()).collect::<Vec<_>>(), generator.generate_iter().collect::<Vec<_>>());
+        let connected = generated.iter().filter(|g| g.crossword.len() == 2).collect::<Vec<_>>();
+        assert!(!connected.is_empty());
+        for g in connected
+        {
+            // only one of the words with the same value is placed
+            let hello = g.crossword.find_word(&"hello".to_owned()).unwrap();
+            let unused_hello = g.unused_words.iter().filter(|w| w.value == "hello").collect::<Vec<_>>();
+            assert_eq!(unused_hello.len(), 1);
+            assert!(g.unused_words.contains(&Word::new("xyz".to_owned(), None)));
+            assert_eq!(g.unused_words.len(), 2);
+            if hello.direction == Direction::Right { assert_eq!(unused_hello[0].dir, Some(Direction::Down)); }
+
+            // the crossword alone matches the placed word with the word of its direction
+            let unused = generator.with_unused_words(g.crossword.clone()).unused_words;
+            assert_eq!(unused.len(), 2);
+            let expected_dir = if hello.direction == Direction::Down { None } else { Some(Direction::Down) };
+            assert!(unused.contains(&Word::new("hello".to_owned(), expected_dir)));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_min_difference()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+        let difference = |a: &Crossword<u8, String>, b: &Crossword<u8, String>| a.len().max(b.len()) - a.shared_placements(b);
+        assert!(all.windows(2).any(|pair| difference(&pair[0], &pair[1]) < 2));
+
+        generator.settings.min_difference = 2;
+        let sorted = generate_all_sorted(&generator).await;
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::Count(10)).await.unwrap();
+        let randomized = str.take(10).collect::<Vec<_>>().await;
+        for crosswords in [sorted.clone(), randomized]
+        {
+            assert!(!crosswords.is_empty() && crosswords.len() < all.len());
+            assert!(crosswords.iter().all(|cw| all.contains(cw)));
+            assert!(crosswords.iter().tuple_combinations().all(|(a, b)| difference(a, b) >= 2));
+        }
+
+        // the filtered out crosswords don't count for the requests
+        let str = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::Count(3)).await.unwrap();
+        assert_eq!(str.take(3).collect::<Vec<_>>().await, sorted[..3]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_sorted_no_duplicates()
+    {
+        // the same crosswords can be reached with many orders of the words sharing the same letters
+        for words in [vec!["ab", "ba", "abc", "bca", "cab"], vec!["ann", "nan", "naan", "anna"], vec!["abab", "baba", "abba", "baab"]]
+        {
+            for emit_subsets in [false, true]
+            {
+                let mut generator = CrosswordGenerator::<u8, String>
+                {
+                    words: words.iter().map(|s| Word::new(s.to_string(), None).with_required(!emit_subsets)).collect(),
+                    ..Default::default()
+                };
+                generator.settings.emit_subsets = emit_subsets;
+
+                let sorted = generate_all_sorted(&generator).await;
+                let distinct = sorted.iter().cloned().collect::<BTreeSet<_>>();
+                assert!(sorted.len() > 5);
+                assert_eq!(sorted.len(), distinct.len());
+
+                // the randomized stream keeps the generated crosswords to skip the duplicates
+                let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+                str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+                assert_eq!(str.collect::<BTreeSet<_>>().await, distinct);
+            }
+        }
+    }
+
+    #[test]
+    fn test_created_crosswords()
+    {
+        let crossword = |words: &[(&'static str, i16, i16, Direction)]| Crossword::<u8, &str>::from_placed_words(words.iter().map(|(value, x, y, dir)| PlacedWord::new(*value, Position { x: *x, y: *y }, dir.clone())), Default::default()).unwrap();
+        let a = crossword(&[("hello", 0, 0, Direction::Right), ("local", 2, 0, Direction::Down)]);
+        let b = crossword(&[("hello", 0, 0, Direction::Right), ("local", 3, 0, Direction::Down)]);
+        let c = crossword(&[("hello", 0, 1, Direction::Right), ("local", 4, 0, Direction::Down)]);
+        let ids = WordIds::new(["hello", "local"]);
+        let (a, b, c) = (ids.fingerprint(&a), ids.fingerprint(&b), ids.fingerprint(&c));
+
+        // only the hashes are kept
+        let mut created = CreatedCrosswords::new(DedupMemoryLimit::HashOnly);
+        assert!(created.insert(a.clone()) && created.insert(b.clone()));
+        assert!(!created.insert(a.clone()));
+        assert!(created.contains(&b) && !created.contains(&c));
+        assert!(matches!(&created, CreatedCrosswords::Hashes(hashes) if *hashes == HashSet::from([CreatedCrosswords::hash(&a), CreatedCrosswords::hash(&b)])));
+
+        let mut created = CreatedCrosswords::new(DedupMemoryLimit::KeepAll);
+        assert!(created.insert(a.clone()) && created.insert(b.clone()) && created.insert(c.clone()));
+        assert!(!created.insert(a.clone()) && !created.insert(b.clone()) && !created.insert(c.clone()));
+
+        // the least recently inserted crossword is dropped
+        let mut created = CreatedCrosswords::new(DedupMemoryLimit::Bounded(2));
+        assert!(created.insert(a.clone()) && created.insert(b.clone()));
+        assert!(!created.insert(a.clone()));
+        assert!(created.insert(c.clone()));
+        assert!(!created.insert(a.clone()));
+        assert!(created.contains(&c) && !created.contains(&b));
+        assert!(created.insert(b.clone()));
+    }
+
+    #[test]
+    fn test_layout_fingerprints()
+    {
+        fn crossword<'a>(words: &[(&'a str, &WordMetadata, i16, i16, Direction)]) -> Crossword<u8, &'a str>
+        {
+            let words = words.iter().map(|(value, meta, x, y, dir)| PlacedWord::new(*value, Position { x: *x, y: *y }, dir.clone()).with_meta((*meta).clone()));
+            Crossword::from_placed_words(words, Default::default()).unwrap()
+        }
+        let hello = String::from("hello");
+        let (plain, clued) = (WordMetadata::default(), WordMetadata { clue: Some("greeting".to_owned()), ..Default::default() });
+        let ids = WordIds::<u8, &str>::new([hello.as_str(), "local"]);
+
+        // the layouts of shifted crosswords are the same
+        let a = ids.fingerprint(&crossword(&[(&hello, &plain, 0, 0, Direction::Right), ("local", &plain, 2, 0, Direction::Down)]));
+        assert_eq!(a, LayoutFingerprint(Box::new([(0, 0, Direction::Right, 0), (2, 0, Direction::Down, 1)])));
+        assert_eq!(ids.fingerprint(&crossword(&[(&hello, &plain, -3, 2, Direction::Right), ("local", &plain, -1, 2, Direction::Down)])), a);
+        assert_ne!(ids.fingerprint(&crossword(&[(&hello, &plain, 0, 0, Direction::Right), ("local", &plain, 3, 0, Direction::Down)])), a);
+
+        // the values in other places are found by comparing them, the metadata doesn't change the layout
+        assert_eq!(ids.fingerprint(&crossword(&[("hello", &plain, 0, 0, Direction::Right), ("local", &plain, 2, 0, Direction::Down)])), a);
+        assert_eq!(ids.fingerprint(&crossword(&[("hello", &clued, 0, 0, Direction::Right), ("local", &plain, 2, 0, Direction::Down)])), a);
+
+        // the same values have the id of the last of them
+        let other = String::from("local");
+        let ids = WordIds::<u8, &str>::new(["local", other.as_str()]);
+        assert_eq!(ids.fingerprint(&crossword(&[("local", &plain, 0, 0, Direction::Down)])), LayoutFingerprint(Box::new([(0, 0, Direction::Down, 1)])));
+        assert_eq!(ids.fingerprint(&crossword(&[(&other, &clued, 0, 0, Direction::Down)])), LayoutFingerprint(Box::new([(0, 0, Direction::Down, 1)])));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_dedup_memory_limit()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        generator.settings.dedup_symmetric = true;
+        generator.settings.dedup_memory_limit = DedupMemoryLimit::KeepAll;
+        let sorted = generate_all_sorted(&generator).await;
+        let randomized = |generator: &CrosswordGenerator<u8, String>| 
+        {
+            let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+            async move
+            {
+                str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+                str.collect::<Vec<_>>().await
+            }
+        };
+        let all = randomized(&generator).await;
+        // the streams can generate other ones of the symmetric crosswords
+        let canonical = |crosswords: &[Crossword<u8, String>]| crosswords.iter().map(Crossword::canonicalize).collect::<BTreeSet<_>>();
+        assert_eq!(all.len(), sorted.len());
+        assert_eq!(canonical(&all), canonical(&sorted));
+
+        // the hashes skip the same duplicates
+        generator.settings.dedup_memory_limit = DedupMemoryLimit::HashOnly;
+        assert_eq!(generate_all_sorted(&generator).await, sorted);
+        assert_eq!(randomized(&generator).await, all);
+
+        // without memory the duplicates are generated again
+        generator.settings.dedup_memory_limit = DedupMemoryLimit::Bounded(0);
+        let bounded = randomized(&generator).await;
+        assert!(bounded.len() > all.len());
+        assert_eq!(canonical(&bounded), canonical(&all));
+        let bounded = generate_all_sorted(&generator).await;
+        assert!(bounded.len() > sorted.len());
+        assert_eq!(canonical(&bounded), canonical(&sorted));
+    }
+
+    #[tokio::test]
+    async fn test_generator_prune_containing_crosswords()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "low", "dose"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let searched = generate_all_sorted(&generator).await;
+        assert!(searched.len() > 10);
+
+        // the crosswords containing the fully searched ones are found in their searches
+        generator.settings.prune_containing_crosswords = true;
+        assert_eq!(generate_all_sorted(&generator).await, searched);
+        generator.settings.dedup_symmetric = true;
+        let pruned = generate_all_sorted(&generator).await;
+        generator.settings.prune_containing_crosswords = false;
+        assert_eq!(generate_all_sorted(&generator).await, pruned);
+
+        // the placement constraints depend on the words placed before, so the crosswords that contain a fully searched one can be skipped wrongly
+        let constrained = |word: &str, constraint: PlacementConstraint| CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "local", "ice", "toy"].into_iter().map(|s| if s == word { Word::new(s.to_owned(), None).with_placement_constraint(constraint.clone()) } else { Word::new(s.to_owned(), None) }).collect(),
+            ..Default::default()
+        };
+        for (mut generator, searched, pruned) in [(constrained("toy", PlacementConstraint::Column(0)), 3, 0), (constrained("local", PlacementConstraint::Column(2)), 5, 4)]
+        {
+            let crosswords = generate_all_sorted(&generator).await;
+            assert_eq!(crosswords.len(), searched);
+            assert!(crosswords.iter().all(|cw| cw.len() == 4));
+            generator.settings.prune_containing_crosswords = true;
+            let containing = generate_all_sorted(&generator).await;
+            assert_eq!(containing.len(), pruned);
+            assert!(containing.iter().all(|cw| crosswords.contains(cw)));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_converts_words_once()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let conversions = Arc::new(AtomicUsize::new(0));
+        let counted = conversions.clone();
+        let convert_f = move |w: &[u8]| 
+        {
+            counted.fetch_add(1, Ordering::Relaxed);
+            String::from_utf8(w.to_owned()).unwrap()
+        };
+
+        // the words are in many crosswords, but every value is converted at most once
+        let str = generator.crossword_stream_sorted(convert_f.clone());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        let sorted = str.collect::<Vec<_>>().await;
+        assert!(sorted.iter().map(Crossword::len).sum::<usize>() > 2 * generator.words.len());
+        assert_eq!(sorted, generate_all_sorted(&generator).await);
+        assert!(conversions.load(Ordering::Relaxed) <= generator.words.len());
+
+        conversions.store(0, Ordering::Relaxed);
+        let str = generator.crossword_stream_randomized_seeded(0, convert_f);
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, sorted.into_iter().collect());
+        assert!(conversions.load(Ordering::Relaxed) <= generator.words.len());
+    }
+
+    #[test]
+    fn test_permutation_sampler()
+    {
+        let orders = |sampler: &mut PermutationSampler<u8>| std::iter::from_fn(|| sampler.next().map(|(order, _)| order)).collect::<Vec<_>>();
+
+        // every order once, words with bigger weights first
+        let weights = [3, 2, 2, 2, 1];
+        let all = orders(&mut PermutationSampler::new(vec![0, 1, 2, 3, 4], |w| Reverse(weights[*w as usize]), None, GeneratorRng::new(0), None));
+        assert_eq!(all.len(), 6);
+        assert_eq!(all.iter().collect::<BTreeSet<_>>().len(), 6);
+        assert!(all.iter().all(|order| order[0] == 0 && order[4] == 4));
+        assert_eq!(orders(&mut PermutationSampler::new(vec![0, 1, 2, 3, 4], |w| Reverse(weights[*w as usize]), None, GeneratorRng::new(0), None)), all);
+
+        let all = orders(&mut PermutationSampler::new((0..6).collect(), |_| 0, None, GeneratorRng::new(1), None));
+        assert_eq!(all.iter().collect::<BTreeSet<_>>().len(), 720);
+        assert_ne!(orders(&mut PermutationSampler::new((0..6).collect(), |_| 0, None, GeneratorRng::new(2), None)), all);
+        assert_eq!(orders(&mut PermutationSampler::new((0..6).collect(), |_| 0, Some(10), GeneratorRng::new(1), None)), all[..10]);
+
+        // too many orders to count, they are just shuffled
+        let mut sampler = PermutationSampler::new((0..40).collect(), |_| 0, Some(3), GeneratorRng::new(0), None);
+        assert_eq!(sampler.count, None);
+        assert!(orders(&mut sampler).iter().all(|order| order.iter().sorted().copied().eq(0..40)));
+        assert_eq!(orders(&mut PermutationSampler::new(vec![], |_: &u8| 0, None, GeneratorRng::new(0), None)), vec![Vec::<u8>::new()]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_randomized_many_words()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt", "lemon", "dose"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+
+        // the tasks are spawned once, not for each of the 9! orders
+        let spawned = Arc::new(AtomicUsize::new(0));
+        let spawner = 
+        {
+            let spawned = spawned.clone();
+            move |future: BoxFuture<'static, ()>|
+            {
+                spawned.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(future);
+            }
+        };
+        for tasks in [1, 4]
+        {
+            generator.settings.max_concurrent_tasks = tasks;
+            spawned.store(0, Ordering::SeqCst);
+            let mut str = generator.crossword_stream_randomized_with_spawner(0, spawner.clone(), |w| String::from_utf8(w.to_owned()).unwrap());
+            str.request_crossword(CrosswordGenerationRequest::Count(10)).await.unwrap();
+            assert_eq!((&mut str).take(10).collect::<BTreeSet<_>>().await.len(), 10);
+            assert_eq!(spawned.load(Ordering::SeqCst), tasks + 1);
+            assert!(str.progress().permutations_started < 1000);
+        }
+
+        // the limit of the orders ends the stream
+        generator.settings.max_permutations = Some(3);
+        let mut str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        while str.next().await.is_some() {}
+        assert_eq!((str.progress().permutations_started, str.progress().permutations_completed), (3, 3));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_generate_first_n()
+    {
+        let convert = |w: &[u8]| String::from_utf8(w.to_owned()).unwrap();
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.len() > 3);
+
+        assert_eq!(generator.generate_one(), Some(all[0].clone()));
+        assert_eq!(generator.generate_one_async(convert).await, Some(all[0].clone()));
+        assert_eq!(generator.generate_first_n(3, convert).await, generator.generate_n(3));
+        assert!(generator.generate_first_n(0, convert).await.is_empty());
+
+        // asking for more crosswords than there are ends with all of them
+        assert_eq!(generator.generate_first_n(all.len() + 10, convert).await, all);
+
+        generator.words = vec!["hello", "cat"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect();
+        assert_eq!(generator.generate_one(), None);
+        assert_eq!(generator.generate_one_async(convert).await, None);
+        assert!(generator.generate_first_n(5, convert).await.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_crossword_stream()
+    {
+        let generator = CrosswordGenerator::<u8, Vec<u8>>
+        {
+            words: vec!["hello", "world", "low", "lord"].into_iter().map(|s| Word::new(s.as_bytes().to_vec(), None)).collect(),
+            ..Default::default()
+        };
+        let collect = |str: CrosswordStream<u8, Vec<u8>>| async move
+        {
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            str.collect::<Vec<_>>().await
+        };
+
+        let sorted = collect(generator.crossword_stream(GenerationStrategy::Sorted)).await;
+        assert_eq!(sorted, generator.generate_iter().collect::<Vec<_>>());
+        assert_eq!(collect(generator.crossword_stream(GenerationStrategy::default())).await, sorted);
+
+        let randomized = collect(generator.crossword_stream(GenerationStrategy::Randomized { seed: 3 })).await;
+        assert_eq!(randomized, collect(generator.crossword_stream_randomized_seeded(3, |w| w.to_vec())).await);
+        assert_eq!(randomized.iter().collect::<BTreeSet<_>>(), sorted.iter().collect());
+
+        // other string types with a conversion
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let str = generator.crossword_stream_with(GenerationStrategy::Randomized { seed: 3 }, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, generate_all_sorted(&generator).await.into_iter().collect());
+    }
+
+    #[tokio::test]
+    async fn test_generator_count_crosswords()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "asdf", "myname", "sesame", "yeeee", "nouyt"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let count = generate_all_sorted(&generator).await.len() as u64;
+        assert!(count > 10);
+        assert_eq!(generator.count_crosswords(), count);
+        assert_eq!(generator.count_crosswords_async().await, count);
+        assert_eq!(generator.count_crosswords_up_to(10), 10);
+        assert_eq!(generator.count_crosswords_up_to(count + 10), count);
+        assert_eq!(generator.count_crosswords_up_to(0), 0);
+
+        // the dedup and the limits of the settings apply too
+        generator.settings.dedup_symmetric = true;
+        assert_eq!(generator.count_crosswords(), generate_all_sorted(&generator).await.len() as u64);
+        generator.settings.limits.max_crosswords = Some(5);
+        assert_eq!(generator.count_crosswords(), 5);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_emit_filter()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "low", "lord", "do"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let narrow = |cw: &Crossword<u8, String>| cw.get_size().0 <= 6;
+        let all = generate_all_sorted(&generator).await;
+        let expected = all.iter().filter(|cw| narrow(cw)).cloned().collect::<Vec<_>>();
+        assert!(expected.len() > 5 && expected.len() < all.len());
+
+        generator.settings.emit_filter = Some(EmitFilter(Arc::new(|cw| cw.get_size().0 <= 6)));
+        assert_eq!(generate_all_sorted(&generator).await, expected);
+        assert_eq!(generator.generate_n(5), expected[..5]);
+
+        // the rejected crosswords don't count for the requests
+        for randomized in [false, true]
+        {
+            let mut str = match randomized
+            {
+                false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+                true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+            };
+            str.request_crossword(CrosswordGenerationRequest::Count(5)).await.unwrap();
+            let crosswords = (&mut str).take(5).collect::<Vec<_>>().await;
+            assert_eq!(crosswords.len(), 5);
+            assert!(crosswords.iter().all(narrow));
+        }
+
+        // only the presence of the filter is compared
+        let mut settings = generator.settings.clone();
+        settings.emit_filter = Some(EmitFilter(Arc::new(|_| false)));
+        assert_eq!(settings, generator.settings);
+        settings.emit_filter = None;
+        assert_ne!(settings, generator.settings);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_rotational_symmetry()
+    {
+        // all the words make a ring, that is symmetric, most of the crosswords with some of them are not
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["cat", "toy", "any", "cia"].into_iter().map(|s| Word::new(s.to_owned(), None).with_required(false)).collect(),
+            ..Default::default()
+        };
+        generator.settings.emit_subsets = true;
+        let ring = Crossword::from_placed_words([("cat", 0, 0, Direction::Right), ("toy", 2, 0, Direction::Down), ("any", 0, 2, Direction::Right), ("cia", 0, 0, Direction::Down)]
+            .map(|(value, x, y, dir)| PlacedWord::new(value.to_owned(), Position { x, y }, dir)), Default::default()).unwrap();
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.contains(&ring));
+        let symmetric = all.iter().filter(|cw| cw.is_rotationally_symmetric()).cloned().collect::<Vec<_>>();
+        assert!(symmetric.contains(&ring) && symmetric.len() < all.len());
+
+        generator.settings.require_rotational_symmetry = true;
+        assert_eq!(generate_all_sorted(&generator).await, symmetric);
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, symmetric.iter().cloned().collect());
+
+        // the same as the constraint
+        generator.settings.require_rotational_symmetry = false;
+        generator.settings.crossword_settings.constraints = vec![CrosswordConstraint::RotationalSymmetry];
+        assert_eq!(generate_all_sorted(&generator).await, symmetric);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_seed_crossword()
+    {
+        let convert = |w: &[u8]| String::from_utf8(w.to_owned()).unwrap();
+        let seed = Crossword::from_placed_words([("hello", 0, 0, Direction::Right), ("local", 2, 0, Direction::Down)]
+            .map(|(value, x, y, dir)| PlacedWord::new(value.to_owned(), Position { x, y }, dir)), Default::default()).unwrap();
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "cat", "toy", "lord", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let unseeded = generate_all_sorted(&generator).await;
+        generator = generator.with_seed_crossword(seed.clone());
+
+        // the crosswords with the seed words at the same positions relative to each other, with "local" from the seed
+        let all = generate_all_sorted(&generator).await;
+        assert!(!all.is_empty());
+        assert!(all.iter().all(|cw| cw.contains_crossword(&seed) && cw.len() == 7));
+        assert!(unseeded.iter().all(|cw| cw.find_word(&"local".to_owned()).is_none()));
+        assert_eq!(generator.generate_iter().collect::<Vec<_>>(), all);
+
+        let str = generator.crossword_stream_randomized_seeded(3, convert);
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, all.iter().cloned().collect());
+
+        // the checkpoint refers to the seed words too
+        let mut str = generator.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+        let mut crosswords = str.by_ref().take(2).collect::<Vec<_>>().await;
+        let json = serde_json::to_string(&str.checkpoint().await.unwrap()).unwrap();
+        let resumed = generator.resume_stream(serde_json::from_str(&json).unwrap(), convert).unwrap();
+        resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        crosswords.extend(resumed.collect::<Vec<_>>().await);
+        assert_eq!(crosswords, all);
+
+        // the best crosswords and the failure report have the seed words too
+        let best = generator.best_crosswords(3, &crate::scoring::CrosswordScorer::default(), GenerationBudget::new());
+        assert_eq!(best.len(), all.len().min(3));
+        assert!(best.iter().all(|cw| all.contains(cw)));
+        assert_eq!(generator.failure_report(), None);
+        let mut unplaceable = generator.clone();
+        unplaceable.words.insert(Word::new("xyz".to_owned(), None));
+        let report = unplaceable.failure_report().unwrap();
+        assert!(report.largest_crossword.contains_crossword(&seed));
+        assert_eq!(report.unplaceable_words, vec!["xyz".to_owned()]);
+
+        // a seed with words that are not compatible by the settings
+        generator.settings.word_compatibility_settings.allowed_directions = [Direction::Right].into();
+        assert!(generate_all_sorted(&generator).await.is_empty());
+        let str = generator.crossword_stream_randomized_seeded(3, convert);
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert!(str.collect::<Vec<_>>().await.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_direction_skew()
+    {
+        let skew = |cw: &Crossword<u8, String>| cw.iter().filter(|w| w.direction.is_horizontal()).count().abs_diff(cw.iter().filter(|w| !w.direction.is_horizontal()).count());
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.iter().any(|cw| skew(cw) > 0));
+
+        generator.settings.max_direction_skew = Some(0);
+        let balanced = all.iter().filter(|cw| skew(cw) == 0).cloned().collect::<Vec<_>>();
+        assert!(!balanced.is_empty());
+        assert_eq!(generate_all_sorted(&generator).await, balanced);
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, balanced.iter().cloned().collect());
+
+        // the words with fixed directions
+        generator.words = generator.words.into_iter().map(|w| { let dir = (w.value == "hello" || w.value == "cat").then_some(Direction::Right); Word::new(w.value, dir) }).collect();
+        generator.settings.max_direction_skew = None;
+        let all = generate_all_sorted(&generator).await;
+        generator.settings.max_direction_skew = Some(0);
+        let balanced = all.iter().filter(|cw| skew(cw) == 0).cloned().collect::<Vec<_>>();
+        assert!(!balanced.is_empty() && balanced.len() < all.len());
+        assert_eq!(generate_all_sorted(&generator).await, balanced);
+
+        // the optional words can be left out
+        generator.words = generator.words.into_iter().map(|w| w.with_required(false)).collect();
+        generator.settings.max_direction_skew = Some(1);
+        generator.settings.emit_subsets = true;
+        let crosswords = generate_all_sorted(&generator).await;
+        assert!(!crosswords.is_empty() && crosswords.iter().all(|cw| skew(cw) <= 1));
+    }
+
+    // the possible placements without the vertical ones
+    #[cfg(feature = "tokio")]
+    struct HorizontalOnly;
+
+    #[cfg(feature = "tokio")]
+    impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacementStrategy<CharT, StrT> for HorizontalOnly
+    {
+        fn candidate_placements(&self, crossword: &Crossword<CharT, StrT>, word: &Word<CharT, StrT>) -> Vec<PlacedWord<CharT, StrT>>
+        {
+            PossiblePlacements.candidate_placements(crossword, word).into_iter().filter(|w| w.direction.is_horizontal()).collect()
+        }
+    }
+
+    #[test]
+    fn test_center_biased_placements()
+    {
+        let mut cw = Crossword::<u8, &str>::default();
+        cw.add_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let word = Word::new("local", None);
+        let placements = CenterBiased.candidate_placements(&cw, &word);
+        assert_eq!(placements.iter().sorted().collect::<Vec<_>>(), cw.calculate_possible_ways_to_add_word(&word).iter().collect::<Vec<_>>());
+
+        // the doubled distances of the centers from the center of "hello"
+        let distances = placements.iter().map(|w| (2 * w.position.x - 4).abs() + (2 * w.position.y + 4).abs()).collect::<Vec<_>>();
+        assert_eq!(distances, vec![4, 4, 6, 6, 6]);
+        assert_eq!(placements[0].position, Position { x: 2, y: -4 });
+        assert_eq!(CenterBiased.candidate_placements(&Crossword::default(), &word), vec![PlacedWord::new("local", Position::default(), Direction::Right)]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_placement_strategy()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy"].into_iter().map(|s| Word::new(s.to_owned(), None).with_required(false)).collect(),
+            ..Default::default()
+        };
+        generator.settings.emit_subsets = true;
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.iter().any(|cw| cw.len() > 1));
+
+        // only the crosswords with one word are left, the horizontal words can't cross
+        generator.settings.placement_strategy = Some(SharedPlacementStrategy(Arc::new(HorizontalOnly)));
+        let horizontal = generate_all_sorted(&generator).await;
+        assert!(horizontal.iter().all(|cw| cw.iter().all(|w| w.direction.is_horizontal())));
+        assert_eq!(horizontal, all.iter().filter(|cw| cw.len() == 1).cloned().collect::<Vec<_>>());
+        let str = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap());
+        str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+        assert_eq!(str.collect::<BTreeSet<_>>().await, horizontal.iter().cloned().collect());
+
+        // the required words can't be added
+        generator.words = generator.words.into_iter().map(|w| w.with_required(true)).collect();
+        assert!(generate_all_sorted(&generator).await.is_empty());
+
+        // the same crosswords in another order
+        generator.words = generator.words.into_iter().map(|w| w.with_required(true)).filter(|w| w.value != "toy").collect();
+        generator.settings.emit_subsets = false;
+        generator.settings.placement_strategy = None;
+        let all = generate_all_sorted(&generator).await;
+        generator.settings.placement_strategy = Some(SharedPlacementStrategy(Arc::new(CenterBiased)));
+        generator.settings.placement_order = PlacementOrder::Sorted;
+        let center_biased = generate_all_sorted(&generator).await;
+        assert!(!all.is_empty());
+        assert_eq!(center_biased.iter().sorted().collect::<Vec<_>>(), all.iter().sorted().collect::<Vec<_>>());
+
+        // the settings with clones of a strategy are equal, but not with other strategies
+        let settings = generator.settings.clone();
+        assert_eq!(settings, generator.settings);
+        generator.settings.placement_strategy = Some(SharedPlacementStrategy(Arc::new(CenterBiased)));
+        assert_ne!(settings, generator.settings);
+        generator.settings.placement_strategy = None;
+        assert_ne!(settings, generator.settings);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_finished_reason()
+    {
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "local"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let stream = |generator: &CrosswordGenerator<u8, String>, randomized: bool| match randomized
+        {
+            false => generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()),
+            true => generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()),
+        };
+
+        // the search runs out of crosswords before the requested count
+        for randomized in [false, true]
+        {
+            let mut str = stream(&generator, randomized);
+            assert_eq!((str.pending_requested(), str.finished_reason()), (0, None));
+            str.request_crossword(CrosswordGenerationRequest::Count(1000)).await.unwrap();
+            let crosswords = str.by_ref().collect::<Vec<_>>().await;
+            assert!(!crosswords.is_empty());
+            assert_eq!(str.finished_reason(), Some(FinishReason::Exhausted));
+            assert_eq!(str.pending_requested(), 1000 - crosswords.len());
+        }
+
+        generator.words = vec!["hello", "world", "local", "cat", "toy", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect();
+        for randomized in [false, true]
+        {
+            // the requested crosswords are generated, the generation waits for more
+            let mut str = stream(&generator, randomized);
+            str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+            str.request_crossword(CrosswordGenerationRequest::Count(1)).await.unwrap();
+            assert_eq!(str.by_ref().take(3).count().await, 3);
+            while str.state() != GeneratorState::Idle { tokio::task::yield_now().await; }
+            assert_eq!((str.pending_requested(), str.finished_reason()), (0, None));
+
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert_eq!(str.pending_requested(), usize::MAX);
+            str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+            while str.next().await.is_some() {}
+            assert_eq!(str.finished_reason(), Some(FinishReason::Stopped));
+
+            // the stream is dropped or shut down, while the generation waits for requests
+            for shutdown in [false, true]
+            {
+                let mut str = stream(&generator, randomized);
+                str.request_crossword(CrosswordGenerationRequest::Count(1)).await.unwrap();
+                assert!(str.next().await.is_some());
+                let control = str.control.clone();
+                if shutdown { str.shutdown().await; } else { drop(str); }
+                while control.finish_reason.get().is_none() { tokio::task::yield_now().await; }
+                assert_eq!(control.finish_reason.get(), Some(&if shutdown { FinishReason::Stopped } else { FinishReason::Dropped }));
+            }
+        }
+
+        generator.settings.limits = GenerationLimits { max_crosswords: Some(2), ..Default::default() };
+        for randomized in [false, true]
+        {
+            let mut str = stream(&generator, randomized);
+            str.request_crossword(CrosswordGenerationRequest::Count(5)).await.unwrap();
+            assert_eq!(str.by_ref().count().await, 2);
+            assert_eq!(str.finished_reason(), Some(FinishReason::LimitReached(GenerationLimit::Crosswords)));
+            assert_eq!(str.pending_requested(), 3);
+        }
+
+        // the custom generation functions have no reason
+        let mut str = CrosswordStream::<u8, String>::new(|_requests, _crosswords| async {});
+        let _ = str.request_crossword(CrosswordGenerationRequest::Count(3)).await;
+        assert!(str.next().await.is_none());
+        assert_eq!(str.finished_reason(), None);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_split_stream()
+    {
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.len() > 6);
+
+        // the requests come from another task, the receiver ends after the requester is dropped
+        let (requester, mut receiver) = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()).split();
+        assert_eq!(receiver.size_hint(), (0, None));
+        let requests = tokio::spawn(async move
+        {
+            for _ in 0..3
+            {
+                requester.request(CrosswordGenerationRequest::Count(2)).await.unwrap();
+                while requester.pending_requested() > 0 || requester.state() != GeneratorState::Idle { tokio::task::yield_now().await; }
+            }
+        });
+        let crosswords = receiver.by_ref().collect::<Vec<_>>().await;
+        requests.await.unwrap();
+        assert_eq!(crosswords, all[..6]);
+        assert!(receiver.is_terminated());
+        assert_eq!(receiver.size_hint(), (0, Some(0)));
+        assert_eq!(receiver.finished_reason(), Some(FinishReason::Stopped));
+        assert!(receiver.next().await.is_none());
+
+        // the requesters can be cloned, the requests of all of them are handled
+        let (mut requester, receiver) = generator.crossword_stream_randomized_seeded(0, |w| String::from_utf8(w.to_owned()).unwrap()).split();
+        let other = requester.clone();
+        requester.try_request(CrosswordGenerationRequest::Count(1)).unwrap();
+        tokio::spawn(async move { other.request(CrosswordGenerationRequest::All).await.unwrap(); }).await.unwrap();
+        drop(requester);
+        assert_eq!(receiver.collect::<BTreeSet<_>>().await, all.iter().cloned().collect());
+
+        // the buffer of the requests is full while the generation waits for space in the buffer of the crosswords
+        let mut generator = generator.clone();
+        (generator.settings.request_buffer, generator.settings.crossword_buffer) = (1, 1);
+        let (mut requester, receiver) = generator.crossword_stream_sorted(|w| String::from_utf8(w.to_owned()).unwrap()).split();
+        requester.request(CrosswordGenerationRequest::All).await.unwrap();
+        while receiver.progress().crosswords == 0 { tokio::task::yield_now().await; }
+        requester.try_request(CrosswordGenerationRequest::Count(1)).unwrap();
+        assert_eq!(requester.try_request(CrosswordGenerationRequest::Count(1)), Err(RequestError::RequestBufferFull));
+
+        // the generation is stopped when the receiver is dropped
+        drop(receiver);
+        while requester.state() != GeneratorState::Finished { tokio::task::yield_now().await; }
+        assert_eq!(requester.try_request(CrosswordGenerationRequest::Count(1)), Err(RequestError::GenerationFinished));
+        assert_eq!(requester.try_request(CrosswordGenerationRequest::Stop), Ok(()));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_auto_stream()
+    {
+        let convert = |w: &[u8]| String::from_utf8(w.to_owned()).unwrap();
+        let generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+
+        // no requests, and only the taken crosswords are requested
+        let mut str = generator.crossword_stream_sorted(convert).into_auto();
+        assert_eq!(str.by_ref().take(3).collect::<Vec<_>>().await, all[..3]);
+        while str.get_ref().state() != GeneratorState::Idle { tokio::task::yield_now().await; }
+        assert_eq!(str.get_ref().pending_requested(), 0);
+        assert_eq!(str.collect::<Vec<_>>().await, all[3..]);
+
+        let str = generator.crossword_stream_randomized_seeded(0, convert).into_auto();
+        assert_eq!(str.take(3).collect::<Vec<_>>().await.len(), 3);
+
+        // the buffer of the requests wakes the stream
+        let mut small_buffers = generator.clone();
+        (small_buffers.settings.request_buffer, small_buffers.settings.crossword_buffer) = (1, 1);
+        let str = small_buffers.crossword_stream_sorted(convert).into_auto();
+        assert_eq!(str.collect::<Vec<_>>().await, all);
+
+        // the earlier requests are still handled
+        let str = generator.crossword_stream_sorted(convert);
+        str.request_crossword(CrosswordGenerationRequest::Count(2)).await.unwrap();
+        let mut str = str.into_auto();
+        assert_eq!(str.by_ref().take(4).collect::<Vec<_>>().await, all[..4]);
+        str.into_inner().shutdown().await;
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_generator_emit_batch_size()
+    {
+        let convert = |w: &[u8]| String::from_utf8(w.to_owned()).unwrap();
+        let mut generator = CrosswordGenerator::<u8, String>
+        {
+            words: vec!["hello", "world", "local", "cat", "toy", "lord", "low"].into_iter().map(|s| Word::new(s.to_owned(), None)).collect(),
+            ..Default::default()
+        };
+        let all = generate_all_sorted(&generator).await;
+        assert!(all.len() > 10);
+
+        for batch_size in [3, 100]
+        {
+            generator.settings.emit_batch_size = batch_size;
+            assert_eq!(generate_all_sorted(&generator).await, all);
+
+            let str = generator.crossword_stream_randomized_seeded(0, convert);
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            assert_eq!(str.collect::<BTreeSet<_>>().await, all.iter().cloned().collect());
+
+            // the unfinished batches are sent when the requested crosswords are found
+            let mut str = generator.crossword_stream_sorted(convert);
+            let mut crosswords = vec![];
+            for requested in [1, 5, 2]
+            {
+                str.request_crossword(CrosswordGenerationRequest::Count(requested)).await.unwrap();
+                crosswords.extend(str.by_ref().take(requested).collect::<Vec<_>>().await);
+                assert_eq!(crosswords, all[..crosswords.len()]);
+            }
+
+            let mut str = generator.crossword_stream_randomized_seeded(0, convert);
+            str.request_crossword(CrosswordGenerationRequest::Count(4)).await.unwrap();
+            assert_eq!(str.by_ref().take(4).count().await, 4);
+            str.request_crossword(CrosswordGenerationRequest::Stop).await.unwrap();
+            assert!(str.next().await.is_none());
+
+            // the checkpoint gets the collected crosswords
+            let mut str = generator.crossword_stream_sorted(convert);
+            str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            let mut crosswords = str.by_ref().take(4).collect::<Vec<_>>().await;
+            let checkpoint = str.checkpoint().await.unwrap();
+            let resumed = generator.resume_stream(checkpoint, convert).unwrap();
+            resumed.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+            crosswords.extend(resumed.collect::<Vec<_>>().await);
+            assert_eq!(crosswords, all);
+        }
+    }
+}
@@ -1,58 +1,51 @@
 //! # Crossword_Generator
 //! Crossword_generator is a library for creating crosswords from provided words. It determines the positions and directions of the words, but does not generate a finished blank crossword puzzle to solve. 
-//! Works in an async runtime.
+//! Works synchronously with [generate_iter](generator::CrosswordGenerator::generate_iter), or in an async runtime with the "async" feature (enabled by the default "tokio" feature).
+//! For just the first crosswords check [generate_one](generator::CrosswordGenerator::generate_one) and [generate_first_n](generator::CrosswordGenerator::generate_first_n).
 //! 
 //! ```
-//! use crossword_generator::{crossword::Crossword, generator::{CrosswordGenerationRequest, CrosswordGenerator, CrosswordGeneratorSettings}, word::Word};
+//! use crossword_generator::{char_string::CharString, generator::{CrosswordGenerationRequest, CrosswordGenerator, CrosswordGeneratorSettings, GenerationStrategy}, word::Word};
 //! use tokio_stream::StreamExt;
 //! 
-//! // A quick function to print the crossword to the console
-//! fn print_crossword(cw: &Crossword<u8, String>)
-//! {
-//!     let table = cw.generate_char_table();
-//!     println!(" {} ", vec!['-'; table[0].len() * 2 - 1].into_iter().collect::<String>());
-//!     for i in 0..table.len()
-//!     {
-//!         print!("|");
-//!         for j in 0..table[0].len()
-//!         {
-//!             print!("{}", (if table[i][j] != 0 {table[i][j]} else {32}) as char);
-//!             if j != table[0].len() - 1 { print!(" "); } 
-//!         }
-//!         println!("|");
-//!     }
-//!     println!(" {} ", vec!['-'; table[0].len() * 2 - 1].into_iter().collect::<String>());
-//! }
-//! 
-//! #[tokio::main]
+//! #[tokio::main(flavor = "current_thread")]
 //! async fn main()
 //! {
 //!     // Create a generator.
-//!     let mut generator = CrosswordGenerator::<u8, String>::default();
+//!     // Characters are unicode characters, so words in any language can be used (u8 characters are faster for ascii only words).
+//!     let mut generator = CrosswordGenerator::<char, CharString>::default();
 //! 
 //!     // Set some settings.
 //!     generator.settings = CrosswordGeneratorSettings::default();
 //! 
 //!     // Specify the words crosswords will be consisted from.
-//!     generator.words = vec!["hello", "world", "foo", "raw"].into_iter().map(|s| Word::new(s.to_lowercase(), None)).collect();
+//!     generator.words = vec!["hello", "world", "foo", "raw"].into_iter().map(|s| Word::from_str_chars(&s.to_lowercase())).collect();
 //!     
 //!     // Create the crossword stream, this will generate crosswords and return them to you. If you wait long enough, you will get every possible crossword that satisfies the settings.
-//!     let mut str = generator.crossword_stream(|s| String::from_utf8(s.to_owned()).expect("The word is not in proper utf8 format"));
+//!     // The sorted stream is fast, the randomized one gives crosswords in a random order.
+//!     let mut str = generator.crossword_stream(GenerationStrategy::Sorted);
 //! 
-//!     // You can request a concrete number of crosswords, or all of them.
-//!     str.request_crossword(CrosswordGenerationRequest::All).await;
+//!     // You can request a concrete number of crosswords, or all of them (or get a stream that requests them itself with into_auto).
+//!     str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
 //!     while let Some(cw) = str.next().await 
 //!     {
-//!         print_crossword(&cw);
-//!         println!("");
+//!         // Crosswords can be printed to the console (check crossword_generator::render for more options).
+//!         println!("{cw}\n");
 //!     }
 //! }
 //! ```
 
 
 pub mod traits;
+pub mod char_string;
+pub mod folded_char;
 pub mod word;
 pub mod placed_word;
+pub mod template;
 pub mod crossword;
 pub mod generator;
+pub mod render;
+pub mod scoring;
+pub mod wordlist;
+#[cfg(feature = "formats")]
+pub mod formats;
 
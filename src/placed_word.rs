@@ -1,89 +1,324 @@
-use std::{collections::BTreeSet, marker::PhantomData};
+use std::{cmp::Ordering, collections::BTreeSet, fmt::Display, hash::{Hash, Hasher}, marker::PhantomData};
 
-use itertools::Itertools;
+use itertools::iproduct;
 use serde::{Deserialize, Serialize};
-use crate::{traits::{CrosswordChar, CrosswordString}, word::{Direction, Position, Word}};
 
+use crate::{traits::{CrosswordChar, CrosswordString, RenderChar}, word::{render_value, validate_value, CoordinateOverflowError, Direction, Position, Word, WordError, WordMetadata}};
 
+// the coordinates of the character with the index of a word with the position and the direction, without overflowing
+fn char_coordinates(pos: &Position, dir: &Direction, index: usize) -> (i32, i32)
+{
+    let (dx, dy) = dir.step();
+    (pos.x as i32 + dx as i32 * index as i32, pos.y as i32 + dy as i32 * index as i32)
+}
+
+fn validate_placement<CharT>(value: &[CharT], pos: &Position, dir: &Direction) -> Result<(), WordError>
+{
+    validate_value(value)?;
+    let (x, y) = char_coordinates(pos, dir, value.len() - 1);
+    if i16::try_from(x).is_err() || i16::try_from(y).is_err() { return Err(WordError::OutOfRange); }
+    Ok(())
+}
 
+
+
+// the coordinates of a bounding box are wider than the coordinates of positions, so the cell after the end of a word or the length of a long word don't overflow
 #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
-struct WordBoundingBox
+pub(crate) struct WordBoundingBox
 {
-    x: i16,
-    y: i16,
-    w: u16, 
-    h: u16
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) w: i32, 
+    pub(crate) h: i32
 }
 
 impl WordBoundingBox
 {
     fn intersects(&self, other: &WordBoundingBox) -> bool 
     {
-        (self.x < other.x + other.w as i16 && self.x + self.w as i16 > other.x) &&
-        (self.y < other.y + other.h as i16 && self.y + self.h as i16 > other.y)
+        (self.x < other.x + other.w && self.x + self.w > other.x) &&
+        (self.y < other.y + other.h && self.y + self.h > other.y)
     }
 
     fn sides_touch(&self, other: &WordBoundingBox) -> bool
     {
-        ((self.x + self.w as i16 > other.x && self.x < other.x + other.w as i16) && (self.y + self.h as i16 == other.y || other.y + other.h as i16 == self.y)) || 
-        ((self.y + self.h as i16 > other.y && self.y < other.y + other.h as i16) && (self.x + self.w as i16 == other.x || other.x + other.w as i16 == self.x))
+        ((self.x + self.w > other.x && self.x < other.x + other.w) && (self.y + self.h == other.y || other.y + other.h == self.y)) || 
+        ((self.y + self.h > other.y && self.y < other.y + other.h) && (self.x + self.w == other.x || other.x + other.w == self.x))
     }
 
     fn corners_touch(&self, other: &WordBoundingBox) -> bool
     {
-        (self.y + self.h as i16 == other.y || self.y == other.y + other.h as i16) && 
-        (self.x + self.w as i16 == other.x || self.x == other.x + other.w as i16)
+        (self.y + self.h == other.y || self.y == other.y + other.h) && 
+        (self.x + self.w == other.x || self.x == other.x + other.w)
     }
 
 }
 
 
+/// Represents how two [words](PlacedWord) are positioned relative to each other (check [PlacedWord::relation_to]).
+/// 
+/// The touching relations are the same as in [crate::crossword::WordCompatibilitySettings].
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum WordRelation
+{
+    /// The words neither intersect nor touch.
+    Disjoint,
+    /// The words touch only with corners.
+    CornerTouch,
+    /// The words have the same orientation and are on neighboring lines.
+    SideBySide,
+    /// The words are on the same line one right after another.
+    HeadByHead,
+    /// The words have different orientations and the end of one of them touches the side of the other.
+    SideByHead,
+    /// The words have different orientations and cross each other, matching is true if the characters on the crossing are the same.
+    Intersecting { self_index: u16, other_index: u16, matching: bool },
+    /// The words have the same orientation and share some cells.
+    Overlapping,
+}
+
 /// Represents a word inside of a [crossword](crate::crossword::Crossword), has [position](Position) and [direction](Direction).
 /// 
 /// Accepts two template parameters, that specify the type of individual characters in the word and the type of the word itself (for example u8 and &str, or if you want your crossword to consist of numbers, Digit and Vec\<Digit\> (where Digit is a type that accepts only numbers from 0 to 9)).  
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+/// 
+/// Placed words are compared, ordered and hashed only by their positions, directions and values, the metadata is ignored, so crosswords with different clues are still the same crossword.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlacedWord<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
     pub position: Position,
     pub direction: Direction,
     pub value: StrT,
+    /// The metadata of the [word](Word) that was placed.
+    #[serde(flatten)]
+    pub meta: WordMetadata,
     #[serde(skip)]
     character_type: PhantomData<CharT>
 }
 
+// an empty word at the origin, implemented manually so the characters don't need Default
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT> + Default> Default for PlacedWord<CharT, StrT>
+{
+    fn default() -> Self 
+    {
+        PlacedWord { position: Position::default(), direction: Direction::default(), value: StrT::default(), meta: WordMetadata::default(), character_type: PhantomData }
+    }
+}
+
 impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
 {
+    // the fields that are compared
+    fn key(&self) -> (&Position, &Direction, &StrT)
+    {
+        (&self.position, &self.direction, &self.value)
+    }
+
+    // the value and the position aren't checked, use try_new to reject the empty words and the words outside of the range of the coordinates.
     pub fn new(val: StrT, pos: Position, dir: Direction) -> PlacedWord<CharT, StrT>
     {
-        PlacedWord { value: val, position: pos, direction: dir, character_type: PhantomData }
+        PlacedWord { value: val, position: pos, direction: dir, meta: WordMetadata::default(), character_type: PhantomData }
     } 
 
-    fn get_bounding_box(&self) -> WordBoundingBox
+    /// Returns the word without the position, with the direction of the word as the direction constraint and the same metadata.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction, Word};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let placed = PlacedWord::<u8, &str>::from(("hello", Position { x: 2, y: 0 }, Direction::Down));
+    /// assert_eq!(placed.as_word(), Word::new("hello", Some(Direction::Down)));
+    /// assert_eq!(placed.as_word().place(placed.position.clone(), Direction::Down), Ok(placed));
+    /// ```
+    pub fn as_word(&self) -> Word<CharT, StrT>
+    {
+        Word::new(self.value.clone(), Some(self.direction.clone())).with_meta(self.meta.clone())
+    }
+
+    /// Creates a word, checking that all its characters are in the range of the coordinates.
+    /// 
+    /// # Errors
+    /// 
+    /// [WordError::Empty] - The word is empty.
+    /// 
+    /// [WordError::TooLong] - The word is longer than [MAX_WORD_LENGTH](crate::word::MAX_WORD_LENGTH).
+    /// 
+    /// [WordError::OutOfRange] - The last character of the word is outside of the range of the coordinates.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction, WordError};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// assert!(PlacedWord::<u8, &str>::try_new("hello", Position{x: i16::MAX - 4, y: 0}, Direction::Right).is_ok());
+    /// assert_eq!(PlacedWord::<u8, &str>::try_new("hello", Position{x: i16::MAX - 3, y: 0}, Direction::Right), Err(WordError::OutOfRange));
+    /// ```
+    pub fn try_new(val: StrT, pos: Position, dir: Direction) -> Result<PlacedWord<CharT, StrT>, WordError>
+    {
+        validate_placement(val.as_ref(), &pos, &dir)?;
+        Ok(PlacedWord { value: val, position: pos, direction: dir, meta: WordMetadata::default(), character_type: PhantomData })
+    }
+
+    /// Returns the word with the metadata.
+    pub fn with_meta(mut self, meta: WordMetadata) -> PlacedWord<CharT, StrT>
+    {
+        self.meta = meta;
+        self
+    }
+
+    pub(crate) fn get_bounding_box(&self) -> WordBoundingBox
     {
+        let len = self.value.as_ref().len() as i32;
+        let (x, y) = (self.position.x as i32, self.position.y as i32);
         match self.direction 
         {
-            Direction::Right => WordBoundingBox { x: self.position.x, y: self.position.y, w: self.value.as_ref().len() as u16, h: 1 },
-            Direction::Down => WordBoundingBox { x: self.position.x, y: self.position.y, w: 1, h: self.value.as_ref().len() as u16 },
+            Direction::Right => WordBoundingBox { x, y, w: len, h: 1 },
+            Direction::Down => WordBoundingBox { x, y, w: 1, h: len },
+            Direction::Left => WordBoundingBox { x: x - len + 1, y, w: len, h: 1 },
+            Direction::Up => WordBoundingBox { x, y: y - len + 1, w: 1, h: len },
         }
     }
 
     fn get_parallel_coordinate(&self) -> i16
     {
-        match self.direction
-        {
-            Direction::Right => self.position.y,
-            Direction::Down => self.position.x,
-        }
+        if self.direction.is_horizontal() { self.position.y } else { self.position.x }
     }
 
     #[allow(dead_code)]
     fn get_perpendicular_coordinate(&self) -> i16
     {
-        match self.direction
+        if self.direction.is_horizontal() { self.position.x } else { self.position.y }
+    }
+
+    /// Returns the position of the character of the word with the index (the position can be outside of the word for too big indices).
+    pub(crate) fn char_position(&self, index: usize) -> Position
+    {
+        let (x, y) = char_coordinates(&self.position, &self.direction, index);
+        Position { x: x as i16, y: y as i16 }
+    }
+
+    /// Returns the index of the character of the word that is on the position, if the word covers the position.
+    pub(crate) fn index_at_position(&self, pos: &Position) -> Option<usize>
+    {
+        let (dx, dy) = self.direction.step();
+        let (offset_x, offset_y) = (pos.x as i32 - self.position.x as i32, pos.y as i32 - self.position.y as i32);
+        let along = offset_x * dx as i32 + offset_y * dy as i32;
+        let across = if self.direction.is_horizontal() { offset_y } else { offset_x };
+
+        (across == 0 && along >= 0 && (along as usize) < self.value.as_ref().len()).then_some(along as usize)
+    }
+
+    /// Returns the occupied cells of the word with their characters, from the first character.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let word = PlacedWord::<u8, &str>::new("cat", Position{x: 2, y: 1}, Direction::Left);
+    /// 
+    /// let cells = word.cells().map(|(pos, c)| (pos.x, pos.y, *c)).collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![(2, 1, b'c'), (1, 1, b'a'), (0, 1, b't')]);
+    /// assert_eq!(word.cells().len(), 3);
+    /// ```
+    pub fn cells(&self) -> impl ExactSizeIterator<Item = (Position, &CharT)>
+    {
+        self.value.as_ref().iter().enumerate().map(|(ind, c)| (self.char_position(ind), c))
+    }
+
+    /// Returns true if the word occupies the cell on the position.
+    pub fn contains_position(&self, pos: Position) -> bool
+    {
+        self.index_at_position(&pos).is_some()
+    }
+
+    /// Returns the position of the last character of the word (the position of the word for an empty word).
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let word = PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down);
+    /// assert_eq!(word.end_position(), Position{x: 2, y: 4});
+    /// assert!(word.contains_position(Position{x: 2, y: 4}));
+    /// assert!(!word.contains_position(Position{x: 2, y: 5}));
+    /// ```
+    pub fn end_position(&self) -> Position
+    {
+        self.char_position(self.value.as_ref().len().saturating_sub(1))
+    }
+
+    /// Returns the word moved by the offset.
+    /// 
+    /// # Errors
+    /// 
+    /// [CoordinateOverflowError] - A character of the moved word would be outside of the range of the coordinates.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let word = PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right);
+    /// 
+    /// assert_eq!(word.shifted(2, -1), Ok(PlacedWord::new("cat", Position{x: 2, y: -1}, Direction::Right)));
+    /// // the last character doesn't fit
+    /// assert!(word.shifted(i16::MAX - 1, 0).is_err());
+    /// ```
+    pub fn shifted(&self, dx: i16, dy: i16) -> Result<PlacedWord<CharT, StrT>, CoordinateOverflowError>
+    {
+        let mut res = self.clone();
+        res.translate(dx, dy)?;
+        Ok(res)
+    }
+
+    /// Moves the word by the offset (check [PlacedWord::shifted]), the word is not changed if it can't be moved.
+    /// 
+    /// # Errors
+    /// 
+    /// [CoordinateOverflowError] - A character of the moved word would be outside of the range of the coordinates.
+    pub fn translate(&mut self, dx: i16, dy: i16) -> Result<(), CoordinateOverflowError>
+    {
+        self.end_position().offset(dx, dy).ok_or(CoordinateOverflowError)?;
+        self.position = self.position.offset(dx, dy).ok_or(CoordinateOverflowError)?;
+        Ok(())
+    }
+
+    /// Returns the [relation](WordRelation) of the word to another one.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::{PlacedWord, WordRelation};
+    /// let w1 = PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 1}, Direction::Right);
+    /// let w2 = PlacedWord::<u8, &str>::new("world", Position{x: 4, y: 0}, Direction::Down);
+    /// let w3 = PlacedWord::<u8, &str>::new("cat", Position{x: 5, y: 0}, Direction::Right);
+    /// 
+    /// //         w c a t
+    /// // h e l l o
+    /// //         r
+    /// //         l
+    /// //         d
+    /// 
+    /// assert_eq!(w1.relation_to(&w2), WordRelation::Intersecting { self_index: 4, other_index: 1, matching: true });
+    /// assert_eq!(w2.relation_to(&w3), WordRelation::SideByHead);
+    /// assert_eq!(w1.relation_to(&w3), WordRelation::CornerTouch);
+    /// ```
+    pub fn relation_to(&self, other: &PlacedWord<CharT, StrT>) -> WordRelation
+    {
+        if self.intersects(other)
         {
-            Direction::Right => self.position.x,
-            Direction::Down => self.position.y,
+            match self.get_intersection_indices(other)
+            {
+                Some((self_index, other_index)) => WordRelation::Intersecting 
+                { 
+                    self_index, 
+                    other_index, 
+                    matching: self.value.as_ref()[self_index as usize] == other.value.as_ref()[other_index as usize] 
+                },
+                None => WordRelation::Overlapping,
+            }
         }
+        else if self.corners_touch(other) { WordRelation::CornerTouch }
+        else if self.side_touches_side(other) { WordRelation::SideBySide }
+        else if self.head_touches_head(other) { WordRelation::HeadByHead }
+        else if self.side_touches_head(other) { WordRelation::SideByHead }
+        else { WordRelation::Disjoint }
     }
 
     /// Returns true if two [words](PlacedWord) are intersecting.
@@ -106,7 +341,7 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
     /// Returns true if two [words](PlacedWord) are side by side (check [crate::crossword::WordCompatibilitySettings::side_by_side]).
     pub fn side_touches_side(&self, other: &PlacedWord<CharT, StrT>) -> bool
     {
-        self.direction == other.direction &&
+        self.direction.is_horizontal() == other.direction.is_horizontal() &&
         self.sides_touch(other) && 
         self.get_parallel_coordinate() != other.get_parallel_coordinate()
     }
@@ -114,19 +349,19 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
     /// Returns true if two [words](PlacedWord) are side by head (check [crate::crossword::WordCompatibilitySettings::side_by_head]).
     pub fn side_touches_head(&self, other: &PlacedWord<CharT, StrT>) -> bool
     {
-        self.direction != other.direction &&
+        self.direction.is_horizontal() != other.direction.is_horizontal() &&
         self.sides_touch(other)
     }
 
     /// Returns true if two [words](PlacedWord) are head by head (check [crate::crossword::WordCompatibilitySettings::head_by_head]).
     pub fn head_touches_head(&self, other: &PlacedWord<CharT, StrT>) -> bool
     {
-        self.direction == other.direction &&
+        self.direction.is_horizontal() == other.direction.is_horizontal() &&
         self.sides_touch(other) && 
         self.get_parallel_coordinate() == other.get_parallel_coordinate()
     }
 
-    /// Returns the indices of the characters in the intersection of the [words](Word) if they are intersecting.
+    /// Returns the indices of the characters in the intersection of the [words](PlacedWord) if they are intersecting.
     /// 
     /// Returns None otherwise.
     /// 
@@ -149,14 +384,64 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
     /// Note that this function does not care if the characters on the intersection are not the same, so if the words are dog and cat, 
     /// function can return non None result even though the words dog and cat don't have a common letter.
     pub fn get_intersection_indices(&self, other: &PlacedWord<CharT, StrT>) -> Option<(u16, u16)>
+    {
+        let cell = self.intersection_position(other)?;
+        Some((self.index_at_position(&cell)? as u16, other.index_at_position(&cell)? as u16))
+    }
+
+    /// Returns the position of the cell in the intersection of the [words](PlacedWord) if they are intersecting.
+    /// 
+    /// Returns None otherwise, and for parallel words (like [PlacedWord::get_intersection_indices]).
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let w1 = PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 1}, Direction::Right);
+    /// let w2 = PlacedWord::<u8, &str>::new("world", Position{x: 4, y: 0}, Direction::Down);
+    /// let w3 = PlacedWord::<u8, &str>::new("lo", Position{x: 2, y: 1}, Direction::Right);
+    /// 
+    /// //         w
+    /// // h e l l o
+    /// //         r
+    /// //         l
+    /// //         d
+    /// 
+    /// assert_eq!(w1.intersection_position(&w2), Some(Position{x: 4, y: 1}));
+    /// assert_eq!(w2.intersection_position(&w1), Some(Position{x: 4, y: 1}));
+    /// assert_eq!(w1.intersection_position(&w3), None);
+    /// ```
+    pub fn intersection_position(&self, other: &PlacedWord<CharT, StrT>) -> Option<Position>
     {
         if !self.intersects(other) { return None; }
-        if self.direction == other.direction { return None; }
+        if self.direction.is_horizontal() == other.direction.is_horizontal() { return None; }
 
-        match self.direction
+        Some(if self.direction.is_horizontal() { Position { x: other.position.x, y: self.position.y } } else { Position { x: self.position.x, y: other.position.y } })
+    }
+
+    /// Returns the character of the word on the position, or [None] if the word doesn't occupy the cell.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let word = PlacedWord::<u8, &str>::new("world", Position{x: 4, y: 0}, Direction::Down);
+    /// 
+    /// assert_eq!(word.char_at_position(Position{x: 4, y: 1}), Some(&b'o'));
+    /// assert_eq!(word.char_at_position(Position{x: 3, y: 1}), None);
+    /// ```
+    pub fn char_at_position(&self, pos: Position) -> Option<&CharT>
+    {
+        self.index_at_position(&pos).map(|ind| &self.value.as_ref()[ind])
+    }
+
+    /// Returns true if two [words](PlacedWord) are intersecting and have the same character at the intersection.
+    pub fn connects_with(&self, other: &PlacedWord<CharT, StrT>) -> bool
+    {
+        match self.intersection_position(other)
         {
-            Direction::Right => Some(((other.position.x - self.position.x) as u16, (self.position.y - other.position.y) as u16)),
-            Direction::Down => Some(((other.position.y - self.position.y) as u16, (self.position.x - other.position.x) as u16))
+            Some(pos) => self.char_at_position(pos.clone()) == other.char_at_position(pos),
+            None => false
         }
     }
 
@@ -187,28 +472,48 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
     /// ```
     pub fn calculate_possible_ways_to_add_word(&self, word: &Word<CharT, StrT>) -> BTreeSet<PlacedWord<CharT, StrT>>
     {
-        if let Some(dir) = &word.dir
-        {
-            if *dir == self.direction { return BTreeSet::default(); }
-        }
+        self.calculate_possible_ways_to_add_word_in_directions(word, &BTreeSet::from([Direction::Right, Direction::Down]))
+    }
+
+    /// Returns all possible ways to add another [word](Word) on top of this, with the directions from the set (check [PlacedWord::calculate_possible_ways_to_add_word]).
+    /// 
+    /// Only the directions that are perpendicular to the direction of this word (and equal to the direction of the word if it is specified) are used.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Word, Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use std::collections::BTreeSet;
+    /// let w1 = PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right);
+    /// 
+    /// //     d d
+    /// //     i i
+    /// // h e l l o ---> 2 ways up and 2 ways down (from the same cells)
+    /// //     i i
+    /// //     d d
+    /// 
+    /// assert_eq!(w1.calculate_possible_ways_to_add_word_in_directions(&Word::<u8, &str>::new("lid", None), &Direction::iter().collect()), BTreeSet::from([
+    ///     PlacedWord::<u8, &str>::new("lid", Position{x: 2, y: 0}, Direction::Down),
+    ///     PlacedWord::<u8, &str>::new("lid", Position{x: 3, y: 0}, Direction::Down),
+    ///     PlacedWord::<u8, &str>::new("lid", Position{x: 2, y: 0}, Direction::Up),
+    ///     PlacedWord::<u8, &str>::new("lid", Position{x: 3, y: 0}, Direction::Up),
+    /// ]));
+    /// ```
+    pub fn calculate_possible_ways_to_add_word_in_directions(&self, word: &Word<CharT, StrT>, directions: &BTreeSet<Direction>) -> BTreeSet<PlacedWord<CharT, StrT>>
+    {
         let w = word.value.as_ref();
         let mut pos_ways: BTreeSet<PlacedWord<CharT, StrT>> = BTreeSet::new();
-        let common_chars = w.iter().filter(|c| self.value.as_ref().contains(*c)).collect::<Vec<&CharT>>();
 
-        for char in common_chars
+        for dir in directions.iter().filter(|dir| dir.is_horizontal() != self.direction.is_horizontal() && word.dir.as_ref().is_none_or(|d| d == *dir))
         {
-            for (word_ind, self_ind) in w.iter().enumerate().filter_map(|c| if c.1 == char { Some(c.0) } else { None } ).cartesian_product(self.value.as_ref().iter().enumerate().filter_map(|c| if c.1 == char { Some(c.0) } else { None } ))
+            let (dx, dy) = dir.step();
+            for (word_ind, self_ind) in iproduct!(0..w.len(), 0..self.value.as_ref().len()).filter(|&(word_ind, self_ind)| w[word_ind] == self.value.as_ref()[self_ind])
             {
-                pos_ways.insert(PlacedWord::<CharT, StrT>::new(
-                    
-                    word.value.clone(),
-                    match self.direction
-                    {
-                        Direction::Right => Position{ x: self.position.x + self_ind as i16, y: self.position.y - word_ind as i16},
-                        Direction::Down  => Position{ x: self.position.x - word_ind as i16, y: self.position.y + self_ind as i16},
-                    },
-                    self.direction.opposite(),
-                ));
+                // the ways that don't fit in the range of the coordinates are skipped
+                let cell = self.char_position(self_ind);
+                let position = (cell.x as i32 - dx as i32 * word_ind as i32, cell.y as i32 - dy as i32 * word_ind as i32);
+                let Ok(position) = i16::try_from(position.0).and_then(|x| Ok(Position { x, y: i16::try_from(position.1)? })) else { continue; };
+                if let Ok(way) = word.clone().place(position, dir.clone()) { pos_ways.insert(way); }
             }
         }
 
@@ -220,12 +525,70 @@ impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PlacedWord<CharT, StrT>
 
 
 
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Display for PlacedWord<CharT, StrT>
+{
+    /// Formats the word with its position and direction, like "hello @(2,0) Down".
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// assert_eq!(PlacedWord::<u8, &str>::new("hello", Position { x: 2, y: 0 }, Direction::Down).to_string(), "hello @(2,0) Down");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        write!(f, "{} @({},{}) {:?}", render_value(self.value.as_ref()), self.position.x, self.position.y, self.direction)
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialEq for PlacedWord<CharT, StrT>
+{
+    fn eq(&self, other: &Self) -> bool 
+    {
+        self.key() == other.key()
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Eq for PlacedWord<CharT, StrT> {}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialOrd for PlacedWord<CharT, StrT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Ord for PlacedWord<CharT, StrT>
+{
+    fn cmp(&self, other: &Self) -> Ordering 
+    {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Hash for PlacedWord<CharT, StrT>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) 
+    {
+        self.key().hash(state)
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> From<(StrT, Position, Direction)> for PlacedWord<CharT, StrT>
+{
+    fn from((value, position, direction): (StrT, Position, Direction)) -> Self
+    {
+        PlacedWord::new(value, position, direction)
+    }
+}
+
 #[cfg(test)]
 mod tests
 {
     use itertools::iproduct;
     
-    use crate::crossword::WordCompatibilitySettings;
+    use crate::{crossword::WordCompatibilitySettings, word::DirectionFlags};
 
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
@@ -611,6 +974,205 @@ mod tests
 
         second.position.x = 2;
         assert_eq!(first.get_intersection_indices(&second), None);
+
+        first.direction = Direction::Up;
+        second.direction = Direction::Left;
+        second.position = Position {x: 2, y: -2};
+        assert_eq!(first.get_intersection_indices(&second), Some((2, 2)));
+
+        second.position.y = 1;
+        assert_eq!(first.get_intersection_indices(&second), None);
+    }
+
+    #[test]
+    fn test_placed_word_intersection_position()
+    {
+        let mut first = PlacedWord::new("hayastan".to_string(), Position{ x: 0, y: 0 }, Direction::Right);
+        let mut second = PlacedWord::new("arcax".to_string(), Position{ x: 0, y: 0 }, Direction::Right);
+
+        for (first_dir, second_dir) in iproduct!(Direction::ALL, Direction::ALL)
+        {
+            first.direction = first_dir.clone();
+            second.direction = second_dir.clone();
+            for (x, y) in iproduct!(-9i16..=9, -9i16..=9)
+            {
+                second.position = Position {x, y};
+                let message = format!("{:?} {:?} at {:?}", first_dir, second_dir, second.position);
+
+                let pos = first.intersection_position(&second);
+                assert_eq!(pos.is_some(), first.get_intersection_indices(&second).is_some(), "{}", message);
+                assert_eq!(pos, second.intersection_position(&first), "{}", message);
+                if let Some(pos) = pos
+                {
+                    let (i, j) = first.get_intersection_indices(&second).unwrap();
+                    assert_eq!(first.char_at_position(pos.clone()), first.value.as_bytes().get(i as usize), "{}", message);
+                    assert_eq!(second.char_at_position(pos.clone()), second.value.as_bytes().get(j as usize), "{}", message);
+                    assert_eq!(first.connects_with(&second), first.char_at_position(pos.clone()) == second.char_at_position(pos), "{}", message);
+                }
+                else { assert!(!first.connects_with(&second), "{}", message); }
+            }
+        }
+
+        // parallel overlapping words have no intersection position
+        first.direction = Direction::Right;
+        second.direction = Direction::Left;
+        second.position = Position { x: 4, y: 0 };
+        assert!(first.intersects(&second));
+        assert_eq!(first.intersection_position(&second), None);
+
+        assert_eq!(first.char_at_position(Position { x: 7, y: 0 }), Some(&b'n'));
+        assert_eq!(first.char_at_position(Position { x: 8, y: 0 }), None);
+        assert_eq!(first.char_at_position(Position { x: 0, y: 1 }), None);
+        assert_eq!(second.char_at_position(Position { x: 0, y: 0 }), Some(&b'x'));
+    }
+
+    fn relation_char(relation: WordRelation) -> char
+    {
+        match relation
+        {
+            WordRelation::Disjoint => '.',
+            WordRelation::CornerTouch => 'c',
+            WordRelation::SideBySide => 's',
+            WordRelation::HeadByHead => 'h',
+            WordRelation::SideByHead => 't',
+            WordRelation::Intersecting { matching: true, .. } => 'x',
+            WordRelation::Intersecting { matching: false, .. } => 'i',
+            WordRelation::Overlapping => 'o',
+        }
+    }
+
+    #[test]
+    fn test_placed_word_relation_to()
+    {
+        let mut first = PlacedWord::new("hayastan", Position{ x: 0, y: 0 }, Direction::Right);
+        let mut second = PlacedWord::new("arcax", Position{ x: 0, y: 0 }, Direction::Right);
+
+        // the relations of the second word to the first one, when the second word is on the positions of the neighborhood
+        fn relations(first: &PlacedWord<u8, &str>, second: &PlacedWord<u8, &str>, xs: std::ops::RangeInclusive<i16>, ys: std::ops::RangeInclusive<i16>) -> Vec<String>
+        {
+            let mut second = second.clone();
+            ys.map(|y| xs.clone().map(|x| { second.position = Position {x, y}; relation_char(second.relation_to(first)) }).collect()).collect()
+        }
+
+        assert_eq!(relations(&first, &second, -6..=9, -2..=2), vec!["................",
+                                                                    ".cssssssssssssc.",
+                                                                    ".hooooooooooooh.",
+                                                                    ".cssssssssssssc.",
+                                                                    "................"], "hor_hor");
+
+        first.direction = Direction::Down;
+        second.direction = Direction::Down;
+        assert_eq!(relations(&first, &second, -2..=2, -6..=9), vec![".....",
+                                                                    ".chc.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".sos.",
+                                                                    ".chc.",
+                                                                    "....."], "ver_ver");
+
+        first.direction = Direction::Right;
+        assert_eq!(relations(&first, &second, -2..=9, -6..=2), vec!["............",
+                                                                    ".cttttttttc.",
+                                                                    ".tiiiiiiiit.",
+                                                                    ".tixixiixit.",
+                                                                    ".tiiiiiiiit.",
+                                                                    ".tiiiiiiiit.",
+                                                                    ".tixixiixit.",
+                                                                    ".cttttttttc.",
+                                                                    "............"], "hor_ver");
+    }
+
+    #[test]
+    fn test_placed_word_relation_to_predicates()
+    {
+        let mut first = PlacedWord::new("hayastan", Position{ x: 0, y: 0 }, Direction::Right);
+        let mut second = PlacedWord::new("arcax", Position{ x: 0, y: 0 }, Direction::Right);
+
+        for (first_dir, second_dir) in iproduct!(Direction::ALL, Direction::ALL)
+        {
+            first.direction = first_dir.clone();
+            second.direction = second_dir.clone();
+            for (x, y) in iproduct!(-9i16..=9, -9i16..=9)
+            {
+                second.position = Position {x, y};
+                let message = format!("{:?} {:?} at {:?}", first_dir, second_dir, second.position);
+                let relation = first.relation_to(&second);
+
+                assert_eq!(first.intersects(&second), matches!(relation, WordRelation::Intersecting { .. } | WordRelation::Overlapping), "{}", message);
+                assert_eq!(first.corners_touch(&second), relation == WordRelation::CornerTouch, "{}", message);
+                assert_eq!(first.side_touches_side(&second), relation == WordRelation::SideBySide, "{}", message);
+                assert_eq!(first.head_touches_head(&second), relation == WordRelation::HeadByHead, "{}", message);
+                assert_eq!(first.side_touches_head(&second), relation == WordRelation::SideByHead, "{}", message);
+                assert_eq!(first.connects_with(&second), matches!(relation, WordRelation::Intersecting { matching: true, .. }), "{}", message);
+
+                let mirrored = match relation.clone()
+                {
+                    WordRelation::Intersecting { self_index, other_index, matching } => WordRelation::Intersecting { self_index: other_index, other_index: self_index, matching },
+                    relation => relation,
+                };
+                assert_eq!(second.relation_to(&first), mirrored, "{}", message);
+            }
+        }
+    }
+
+    // the same word written in a Right or Down direction, occupying the same cells
+    fn forward(word: &PlacedWord<u8, String>) -> PlacedWord<u8, String>
+    {
+        if word.direction == Direction::Left || word.direction == Direction::Up
+        {
+            PlacedWord::new(word.value.chars().rev().collect(), word.char_position(word.value.len() - 1), word.direction.reversed())
+        }
+        else { word.clone() }
+    }
+
+    #[test]
+    fn test_placed_word_left_and_up()
+    {
+        let mut first = PlacedWord::new("hayastan".to_string(), Position{ x: 0, y: 0 }, Direction::Right);
+        let mut second = PlacedWord::new("arcax".to_string(), Position{ x: 0, y: 0 }, Direction::Right);
+
+        for (first_dir, second_dir) in iproduct!(Direction::ALL, Direction::ALL)
+        {
+            first.direction = first_dir.clone();
+            second.direction = second_dir.clone();
+            for (x, y) in iproduct!(-9i16..=9, -9i16..=9)
+            {
+                second.position = Position {x, y};
+                let (first_forward, second_forward) = (forward(&first), forward(&second));
+                let message = format!("{:?} {:?} at {:?}", first_dir, second_dir, second.position);
+
+                assert_eq!(first.intersects(&second), first_forward.intersects(&second_forward), "intersects {}", message);
+                assert_eq!(first.corners_touch(&second), first_forward.corners_touch(&second_forward), "corners {}", message);
+                assert_eq!(first.side_touches_side(&second), first_forward.side_touches_side(&second_forward), "side_side {}", message);
+                assert_eq!(first.side_touches_head(&second), first_forward.side_touches_head(&second_forward), "side_head {}", message);
+                assert_eq!(first.head_touches_head(&second), first_forward.head_touches_head(&second_forward), "head_head {}", message);
+
+                let flip = |index: u16, word: &PlacedWord<u8, String>| if word.direction == Direction::Left || word.direction == Direction::Up { word.value.len() as u16 - 1 - index } else { index };
+                assert_eq!(first.get_intersection_indices(&second), first_forward.get_intersection_indices(&second_forward).map(|(i, j)| (flip(i, &first), flip(j, &second))), "intersection {}", message);
+
+                for (a, av, b, bv, c, d) in iproduct!([false, true], [false, true], [false, true], [false, true], [false, true], [false, true])
+                {
+                    let settings = WordCompatibilitySettings
+                    {
+                        side_by_side: DirectionFlags { horizontal: a, vertical: av },
+                        head_by_head: DirectionFlags { horizontal: b, vertical: bv },
+                        side_by_head: c,
+                        corner_by_corner: d,
+                        ..Default::default()
+                    };
+                    assert_eq!(settings.word_compatibility_issue(&first, &second).is_none(), settings.word_compatibility_issue(&first_forward, &second_forward).is_none(), "compatibility {} with settings {:?}", message, settings);
+                }
+            }
+        }
     }
 
     
@@ -618,9 +1180,17 @@ mod tests
     #[test]
     fn test_word_compatibility_settings_are_words_compatible() {
 
-        for (a, b, c, d) in iproduct!((0isize..2), (0isize..2), (0isize..2), (0isize..2))
+        // a and b are the side_by_side and head_by_head settings for horizontal words, av and bv are for vertical words
+        for (a, av, b, bv, c, d) in iproduct!((0isize..2), (0isize..2), (0isize..2), (0isize..2), (0isize..2), (0isize..2))
         {
-            let settings = WordCompatibilitySettings { side_by_side: a != 0, head_by_head: b != 0, side_by_head: c != 0, corner_by_corner: d != 0 };
+            let settings = WordCompatibilitySettings 
+            { 
+                side_by_side: DirectionFlags { horizontal: a != 0, vertical: av != 0 }, 
+                head_by_head: DirectionFlags { horizontal: b != 0, vertical: bv != 0 }, 
+                side_by_head: c != 0, 
+                corner_by_corner: d != 0,
+                ..Default::default()
+            };
 
             let mut first = PlacedWord::new("hayastan", Position{ x: 0, y: 0 }, Direction::Right);
             let mut second = PlacedWord::new("arcax", Position{ x: 0, y: 0 }, Direction::Right);
@@ -654,20 +1224,20 @@ mod tests
             }
             
             assert_eq!(comp, vec![  1, 1, 1, 1, 1,
-                                    1, d, b, d, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, a, 0, a, 1,
-                                    1, d, b, d, 1,
+                                    1, d, bv, d, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, av, 0, av, 1,
+                                    1, d, bv, d, 1,
                                     1, 1, 1, 1, 1], "ver_ver with settings {:?}", settings);
 
             first.direction = Direction::Right;
@@ -694,4 +1264,112 @@ mod tests
         
     }
                             
+
+    #[test]
+    fn test_placed_word_cells()
+    {
+        for dir in Direction::iter()
+        {
+            let word = PlacedWord::<u8, &str>::new("cat", Position{ x: 1, y: -1 }, dir.clone());
+            let (dx, dy) = dir.step();
+
+            let cells = word.cells();
+            assert_eq!(cells.len(), 3);
+            assert_eq!(cells.collect::<Vec<_>>(), vec![
+                (Position { x: 1, y: -1 }, &b'c'),
+                (Position { x: 1 + dx, y: -1 + dy }, &b'a'),
+                (Position { x: 1 + 2 * dx, y: -1 + 2 * dy }, &b't'),
+            ], "{:?}", dir);
+            assert_eq!(word.end_position(), Position { x: 1 + 2 * dx, y: -1 + 2 * dy }, "{:?}", dir);
+
+            for (x, y) in iproduct!(-3i16..=5, -5i16..=3)
+            {
+                let pos = Position { x, y };
+                assert_eq!(word.contains_position(pos.clone()), word.cells().any(|(p, _)| p == pos), "{:?} at {:?}", dir, pos);
+            }
+
+            // a word with one character
+            let a = PlacedWord::<u8, &str>::new("a", Position{ x: 1, y: -1 }, dir.clone());
+            assert_eq!(a.cells().collect::<Vec<_>>(), vec![(Position { x: 1, y: -1 }, &b'a')]);
+            assert_eq!(a.end_position(), a.position);
+            assert!(a.contains_position(Position { x: 1, y: -1 }));
+            assert!(!a.contains_position(Position { x: 1 + dx, y: -1 + dy }));
+            assert!(!a.contains_position(Position { x: 1 - dx, y: -1 - dy }));
+        }
+    }
+
+    #[test]
+    fn test_placed_word_shifted()
+    {
+        let mut word = PlacedWord::<u8, &str>::new("cat", Position{ x: 0, y: 0 }, Direction::Right);
+        assert_eq!(word.shifted(-3, 5).unwrap().position, Position { x: -3, y: 5 });
+
+        // the last character is on the edge of the range
+        assert_eq!(word.shifted(i16::MAX - 2, 0).unwrap().end_position(), Position { x: i16::MAX, y: 0 });
+        assert_eq!(word.shifted(i16::MAX - 1, 0), Err(CoordinateOverflowError));
+        assert_eq!(word.shifted(0, i16::MAX).unwrap().position, Position { x: 0, y: i16::MAX });
+        assert_eq!(word.shifted(i16::MIN, i16::MIN).unwrap().position, Position { x: i16::MIN, y: i16::MIN });
+
+        word.direction = Direction::Left;
+        assert_eq!(word.shifted(i16::MIN + 2, 0).unwrap().end_position(), Position { x: i16::MIN, y: 0 });
+        assert_eq!(word.shifted(i16::MIN + 1, 0), Err(CoordinateOverflowError));
+        assert!(word.shifted(i16::MAX, 0).is_ok());
+
+        // the word is not changed when it can't be moved
+        word.position = Position { x: 0, y: i16::MAX - 5 };
+        word.direction = Direction::Down;
+        assert_eq!(word.translate(1, 4), Err(CoordinateOverflowError));
+        assert_eq!(word.position, Position { x: 0, y: i16::MAX - 5 });
+        assert_eq!(word.translate(1, 3), Ok(()));
+        assert_eq!(word.position, Position { x: 1, y: i16::MAX - 2 });
+
+        assert_eq!(Position { x: i16::MIN, y: 0 }.offset(-1, 0), None);
+        assert_eq!(Position { x: 0, y: i16::MAX }.offset(0, 1), None);
+        assert_eq!(Position { x: i16::MAX, y: i16::MIN }.offset(-1, 1), Some(Position { x: i16::MAX - 1, y: i16::MIN + 1 }));
+    }
+
+    #[test]
+    fn test_placed_word_coordinate_range()
+    {
+        // words on the opposite edges of the range don't touch
+        let hello = PlacedWord::<u8, &str>::new("hello", Position { x: i16::MAX - 4, y: 0 }, Direction::Right);
+        let world = PlacedWord::<u8, &str>::new("world", Position { x: i16::MIN, y: 0 }, Direction::Right);
+        assert_eq!(hello.relation_to(&world), WordRelation::Disjoint);
+        assert_eq!(world.relation_to(&hello), WordRelation::Disjoint);
+        assert!(!hello.head_touches_head(&world));
+
+        // a word longer than the range of i16 can't be created with try_new, but the checks don't wrap around for it
+        let long = "a".repeat(39_999) + "b";
+        assert_eq!(Word::<u8, &str>::try_new(&long, None), Err(WordError::TooLong(40_000)));
+        assert_eq!(PlacedWord::<u8, &str>::try_new(&long, Position { x: -20_000, y: 0 }, Direction::Right), Err(WordError::TooLong(40_000)));
+        let long = PlacedWord::<u8, &str>::new(&long, Position { x: -20_000, y: 0 }, Direction::Right);
+        assert_eq!(long.end_position(), Position { x: 19_999, y: 0 });
+        let short = PlacedWord::<u8, &str>::new("cbc", Position { x: 19_999, y: -1 }, Direction::Down);
+        assert_eq!(long.relation_to(&short), WordRelation::Intersecting { self_index: 39_999, other_index: 1, matching: true });
+        assert_eq!(long.get_intersection_indices(&short), Some((39_999, 1)));
+        assert!(long.side_touches_head(&PlacedWord::new("ab", Position { x: 19_000, y: 1 }, Direction::Down)));
+        assert!(long.head_touches_head(&PlacedWord::new("ab", Position { x: 20_000, y: 0 }, Direction::Right)));
+
+        let max = "a".repeat(crate::word::MAX_WORD_LENGTH);
+        assert!(PlacedWord::<u8, &str>::try_new(&max, Position { x: 0, y: 0 }, Direction::Right).is_ok());
+        assert_eq!(PlacedWord::<u8, &str>::try_new(&max, Position { x: 1, y: 0 }, Direction::Right), Err(WordError::OutOfRange));
+        assert_eq!(PlacedWord::<u8, &str>::try_new(&"a".repeat(10_000), Position { x: 30_000, y: 0 }, Direction::Right), Err(WordError::OutOfRange));
+        assert_eq!(PlacedWord::<u8, &str>::try_new(&"a".repeat(10_000), Position { x: 0, y: -30_000 }, Direction::Up), Err(WordError::OutOfRange));
+        assert_eq!(PlacedWord::<u8, &str>::try_new("", Position { x: i16::MAX, y: i16::MAX }, Direction::Right), Err(WordError::Empty));
+
+        // the ways to add a word that don't fit in the range are skipped
+        let edge = PlacedWord::<u8, &str>::new("hello", Position { x: 0, y: i16::MAX - 2 }, Direction::Right);
+        let ways = edge.calculate_possible_ways_to_add_word_in_directions(&Word::new("local", None), &BTreeSet::from(Direction::ALL));
+        assert!(ways.iter().all(|way| PlacedWord::<u8, &str>::try_new(way.value, way.position.clone(), way.direction.clone()).is_ok()));
+        assert!(ways.contains(&PlacedWord::new("local", Position { x: 2, y: i16::MAX - 2 }, Direction::Up)));
+        assert!(!ways.contains(&PlacedWord::new("local", Position { x: 2, y: i16::MAX - 2 }, Direction::Down)));
+        assert_eq!(PlacedWord::<u8, &str>::try_new("local", Position { x: 2, y: i16::MAX - 2 }, Direction::Down), Err(WordError::OutOfRange));
+    }
+
+    #[test]
+    fn test_placed_word_display()
+    {
+        assert_eq!(PlacedWord::<u8, String>::new("hello".to_owned(), Position { x: 2, y: 0 }, Direction::Down).to_string(), "hello @(2,0) Down");
+        assert_eq!(PlacedWord::<char, Vec<char>>::new(vec!['ա', 'բ'], Position { x: -3, y: 12 }, Direction::Left).to_string(), "աբ @(-3,12) Left");
+    }
 }
@@ -0,0 +1,650 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{crossword::Crossword, traits::{CrosswordChar, CrosswordString, RenderChar}};
+
+/// Represents options for rendering a [crossword](Crossword) as text (check [Crossword::to_ascii]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct AsciiRenderOptions
+{
+    /// The character for the empty cells.
+    pub filler: char,
+    /// Draw a border around the crossword.
+    pub border: bool,
+    /// Show the x coordinates above the crossword and the y coordinates on the left (only the last digit of the x coordinates is shown).
+    pub coordinates: bool,
+    /// Convert all characters to uppercase.
+    pub uppercase: bool,
+    /// Show the characters (the solution), otherwise the filled cells are shown with the blank character.
+    pub show_solution: bool,
+    /// The character for the filled cells when the solution is not shown.
+    pub blank: char,
+    /// Show the clue numbers (check [Crossword::numbered_entries]) before the characters of the starting cells, the cells are widened to fit the numbers.
+    pub show_numbers: bool,
+}
+
+impl Default for AsciiRenderOptions
+{
+    fn default() -> Self 
+    {
+        AsciiRenderOptions { filler: ' ', border: true, coordinates: false, uppercase: false, show_solution: true, blank: '_', show_numbers: false }
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Renders the crossword as text, with spaces between the cells.
+    /// 
+    /// The [Display] implementation uses the default [options](AsciiRenderOptions).
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::render::AsciiRenderOptions;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.to_string(), 
+    /// " --------- 
+    /// |h e l l o|
+    /// |    o    |
+    /// |    c    |
+    /// |    a    |
+    /// |    l    |
+    ///  --------- ");
+    /// 
+    /// assert_eq!(cw.to_ascii(AsciiRenderOptions { filler: '.', border: false, coordinates: true, uppercase: true, ..Default::default() }), 
+    /// "  0 1 2 3 4
+    /// 0 H E L L O
+    /// 1 . . O . .
+    /// 2 . . C . .
+    /// 3 . . A . .
+    /// 4 . . L . .");
+    /// 
+    /// assert_eq!(cw.to_ascii(AsciiRenderOptions { filler: '.', show_solution: false, show_numbers: true, ..Default::default() }), 
+    /// " -------------- 
+    /// |1_  _ 2_  _  _|
+    /// | .  .  _  .  .|
+    /// | .  .  _  .  .|
+    /// | .  .  _  .  .|
+    /// | .  .  _  .  .|
+    ///  -------------- ");
+    /// ```
+    pub fn to_ascii(&self, options: AsciiRenderOptions) -> String
+    {
+        let table = self.generate_char_table_opt();
+        let numbers = if options.show_numbers { self.cell_numbers() } else { BTreeMap::new() };
+        let number_width = numbers.values().max().map_or(0, |n| n.to_string().len());
+        let cell_width = number_width + 1;
+        
+        let width = table.first().map_or(0, |row| row.len());
+        let label_width = if options.coordinates { table.len().saturating_sub(1).to_string().len() + 1 } else { 0 };
+        let border_width = if options.border { 1 } else { 0 };
+
+        let mut lines = vec![];
+        if options.coordinates
+        {
+            let header = (0..width).map(|x| format!("{:>cell_width$}", x % 10)).join(" ");
+            lines.push(format!("{}{}{}", " ".repeat(label_width + border_width), header, " ".repeat(border_width)));
+        }
+
+        let border_line = format!("{}{}{}", " ".repeat(label_width + border_width), "-".repeat((width * (cell_width + 1)).saturating_sub(1)), " ".repeat(border_width));
+        if options.border { lines.push(border_line.clone()); }
+
+        for (y, row) in table.iter().enumerate()
+        {
+            let cells = row.iter().enumerate()
+                .map(|(x, c)| 
+                {
+                    let number = numbers.get(&(x, y)).map_or(String::new(), |n| n.to_string());
+                    let cell = match c
+                    {
+                        Some(c) if options.show_solution => if options.uppercase { c.render().to_uppercase() } else { c.render() },
+                        Some(_) => options.blank.to_string(),
+                        None => options.filler.to_string(),
+                    };
+                    format!("{number:<number_width$}{cell}")
+                })
+                .join(" ");
+
+            let label = if options.coordinates { format!("{:>w$} ", y, w = label_width - 1) } else { String::new() };
+            let border = if options.border { "|" } else { "" };
+            lines.push(format!("{label}{border}{cells}{border}"));
+        }
+        
+        if options.border { lines.push(border_line); }
+
+        lines.join("\n")
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Renders the characters of the cells as strings, [None] for the empty cells.
+    fn rendered_table(&self, uppercase: bool) -> Vec<Vec<Option<String>>>
+    {
+        self.generate_char_table_opt().into_iter()
+            .map(|row| row.into_iter().map(|c| c.map(|c| if uppercase { c.render().to_uppercase() } else { c.render() })).collect())
+            .collect()
+    }
+
+    /// Renders the crossword as a GitHub-flavored markdown table, with the x coordinates as the header and filler for the empty cells.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.to_markdown_table(" ", true), 
+    /// "| 0 | 1 | 2 |
+    /// |---|---|---|
+    /// | C | A | T |
+    /// |   |   | O |
+    /// |   |   | Y |");
+    /// ```
+    pub fn to_markdown_table(&self, filler: &str, uppercase: bool) -> String
+    {
+        let table = self.rendered_table(uppercase);
+        let width = table.first().map_or(0, |row| row.len());
+        if width == 0 { return String::new(); }
+
+        let mut lines = vec![format!("| {} |", (0..width).join(" | ")), format!("|{}|", vec!["---"; width].join("|"))];
+        for row in table
+        {
+            let cells = row.iter().map(|c| c.as_deref().map_or(filler.to_owned(), |c| c.replace('|', "\\|"))).join(" | ");
+            lines.push(format!("| {cells} |"));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Renders the crossword as CSV, with one record for every row of the crossword and empty fields for the empty cells.
+    /// 
+    /// Fields with commas, quotes or line breaks are quoted.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// assert_eq!(cw.to_csv(false), "c,a,t\n,,o\n,,y\n");
+    /// ```
+    pub fn to_csv(&self, uppercase: bool) -> String
+    {
+        self.rendered_table(uppercase).into_iter()
+            .map(|row| row.into_iter().map(|c| match c
+            {
+                Some(c) if c.contains([',', '"', '\n', '\r']) => format!("\"{}\"", c.replace('"', "\"\"")),
+                Some(c) => c,
+                None => String::new(),
+            }).join(",") + "\n")
+            .collect()
+    }
+}
+
+/// Represents the style of an SVG image of a [crossword](Crossword) (check [Crossword::to_svg]).
+#[cfg(feature = "svg")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct SvgStyle
+{
+    /// The width and height of a cell.
+    pub cell_size: u32,
+    /// The font size of the characters.
+    pub font_size: u32,
+    /// The font size of the clue numbers.
+    pub number_font_size: u32,
+    /// Draw the empty cells as shaded cells (otherwise they are omitted).
+    pub shade_empty_cells: bool,
+    /// Draw the characters (the solution), otherwise draws the empty puzzle.
+    pub show_solution: bool,
+    /// Draw the clue numbers (check [Crossword::numbered_entries]) in the corners of the starting cells.
+    pub show_numbers: bool,
+}
+
+#[cfg(feature = "svg")]
+impl Default for SvgStyle
+{
+    fn default() -> Self 
+    {
+        SvgStyle { cell_size: 32, font_size: 20, number_font_size: 9, shade_empty_cells: false, show_solution: true, show_numbers: true }
+    }
+}
+
+/// Escapes the characters that can't be in the text of an XML element.
+#[cfg(any(feature = "svg", feature = "html"))]
+fn escape_xml(text: &str) -> String
+{
+    text.chars().map(|c| match c
+    {
+        '&' => "&amp;".to_owned(),
+        '<' => "&lt;".to_owned(),
+        '>' => "&gt;".to_owned(),
+        '"' => "&quot;".to_owned(),
+        c => c.to_string(),
+    }).collect()
+}
+
+#[cfg(feature = "svg")]
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Renders the crossword as an SVG image with provided [style](SvgStyle).
+    /// 
+    /// Elements have classes for styling: "cell" and "block" for filled and empty cells, "letter" and "number" for characters and clue numbers.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::render::SvgStyle;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("hello", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("local", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let svg = cw.to_svg(SvgStyle::default());
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches(r#"class="cell""#).count(), 9);
+    /// ```
+    pub fn to_svg(&self, style: SvgStyle) -> String
+    {
+        let table = self.generate_char_table_opt();
+        let size = self.get_size();
+        let cell = style.cell_size;
+        let (width, height) = (size.0 as u32 * cell, size.1 as u32 * cell);
+
+        let mut svg = vec![format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#)];
+        
+        for (y, row) in table.iter().enumerate()
+        {
+            for (x, c) in row.iter().enumerate()
+            {
+                let (px, py) = (x as u32 * cell, y as u32 * cell);
+                match c
+                {
+                    Some(c) => 
+                    {
+                        svg.push(format!(r#"<rect class="cell" x="{px}" y="{py}" width="{cell}" height="{cell}" fill="white" stroke="black"/>"#));
+                        if style.show_solution
+                        {
+                            svg.push(format!(r#"<text class="letter" x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#, 
+                                px + cell / 2, py + cell / 2, style.font_size, escape_xml(&c.render())));
+                        }
+                    },
+                    None if style.shade_empty_cells => svg.push(format!(r#"<rect class="block" x="{px}" y="{py}" width="{cell}" height="{cell}" fill="black"/>"#)),
+                    None => {},
+                }
+            }
+        }
+
+        if style.show_numbers
+        {
+            for ((x, y), number) in self.cell_numbers()
+            {
+                let (px, py) = (x as u32 * cell, y as u32 * cell);
+                svg.push(format!(r#"<text class="number" x="{}" y="{}" font-size="{}">{}</text>"#, px + 2, py + style.number_font_size + 1, style.number_font_size, number));
+            }
+        }
+
+        svg.push("</svg>".to_owned());
+        svg.join("\n")
+    }
+}
+
+/// Represents options for rendering a [crossword](Crossword) as an HTML table (check [Crossword::to_html]).
+#[cfg(feature = "html")]
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub struct HtmlRenderOptions
+{
+    /// The prefix of the id of the table and of all classes, so multiple crosswords can be on the same page.
+    pub prefix: String,
+    /// Show the characters (the solution), otherwise shows the empty puzzle.
+    pub show_solution: bool,
+    /// Show the clue numbers (check [Crossword::numbered_entries]) as superscripts in the starting cells.
+    pub show_numbers: bool,
+}
+
+#[cfg(feature = "html")]
+impl Default for HtmlRenderOptions
+{
+    fn default() -> Self 
+    {
+        HtmlRenderOptions { prefix: "crossword-".to_owned(), show_solution: true, show_numbers: true }
+    }
+}
+
+#[cfg(feature = "html")]
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Renders the crossword as an HTML table with provided [options](HtmlRenderOptions), with one cell for every cell of the crossword.
+    /// 
+    /// The table has the id and the class "{prefix}grid", cells have classes "{prefix}cell" and "{prefix}block" for filled and empty cells, 
+    /// and "{prefix}start" for cells where a word starts, characters and clue numbers have classes "{prefix}letter" and "{prefix}number".
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::render::HtmlRenderOptions;
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let html = cw.to_html(HtmlRenderOptions { prefix: "cw-".to_owned(), ..Default::default() });
+    /// assert!(html.starts_with(r#"<table id="cw-grid" class="cw-grid">"#));
+    /// assert!(html.contains(r#"<td class="cw-cell cw-start"><sup class="cw-number">2</sup><span class="cw-letter">t</span></td>"#));
+    /// assert_eq!(html.matches("<td").count(), 9);
+    /// ```
+    pub fn to_html(&self, options: HtmlRenderOptions) -> String
+    {
+        let table = self.generate_char_table_opt();
+        let numbers = self.cell_numbers();
+        let prefix = escape_xml(&options.prefix);
+
+        let mut html = vec![format!(r#"<table id="{prefix}grid" class="{prefix}grid">"#)];
+        for (y, row) in table.iter().enumerate()
+        {
+            html.push("<tr>".to_owned());
+            for (x, c) in row.iter().enumerate()
+            {
+                let c = match c
+                {
+                    Some(c) => c,
+                    None => 
+                    {
+                        html.push(format!(r#"<td class="{prefix}block"></td>"#));
+                        continue;
+                    }
+                };
+
+                let number = numbers.get(&(x, y));
+                let mut cell = format!(r#"<td class="{prefix}cell{}">"#, if number.is_some() { format!(" {prefix}start") } else { String::new() });
+                if let Some(number) = number.filter(|_| options.show_numbers) { cell += &format!(r#"<sup class="{prefix}number">{number}</sup>"#); }
+                if options.show_solution { cell += &format!(r#"<span class="{prefix}letter">{}</span>"#, escape_xml(&c.render())); }
+                cell += "</td>";
+                html.push(cell);
+            }
+            html.push("</tr>".to_owned());
+        }
+        html.push("</table>".to_owned());
+
+        html.join("\n")
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Returns the clue numbers of the starting cells by their coordinates in the grid (check [Crossword::numbered_entries]).
+    pub(crate) fn cell_numbers(&self) -> BTreeMap<(usize, usize), u32>
+    {
+        let min_corner = self.min_corner();
+        self.numbered_entries().into_iter()
+            .map(|e| (((e.position.x - min_corner.0) as usize, (e.position.y - min_corner.1) as usize), e.number))
+            .collect()
+    }
+}
+
+/// Represents a renderer of [crosswords](Crossword) with its options (check [Crossword::render_pair]).
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum RenderFormat
+{
+    /// Check [Crossword::to_ascii].
+    Ascii(AsciiRenderOptions),
+    /// Check [Crossword::to_html].
+    #[cfg(feature = "html")]
+    Html(HtmlRenderOptions),
+    /// Check [Crossword::to_svg].
+    #[cfg(feature = "svg")]
+    Svg(SvgStyle),
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Crossword<CharT, StrT>
+{
+    /// Renders the empty puzzle and the solution of the crossword with the specified [format](RenderFormat), both with the same clue numbers.
+    /// 
+    /// The empty puzzle hides the characters but keeps the cells and the clue numbers, the solution shows the characters. 
+    /// The options of the format for showing the solution and the clue numbers are ignored.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// # use crossword_generator::crossword::Crossword;
+    /// # use crossword_generator::render::{AsciiRenderOptions, RenderFormat};
+    /// let mut cw = Crossword::default();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("cat", Position{x: 0, y: 0}, Direction::Right)).unwrap();
+    /// cw.add_word(PlacedWord::<u8, &str>::new("toy", Position{x: 2, y: 0}, Direction::Down)).unwrap();
+    /// 
+    /// let (puzzle, solution) = cw.render_pair(RenderFormat::Ascii(AsciiRenderOptions { border: false, ..Default::default() }));
+    /// assert_eq!(puzzle, 
+    /// "1_  _ 2_
+    ///        _
+    ///        _");
+    /// assert_eq!(solution, 
+    /// "1c  a 2t
+    ///        o
+    ///        y");
+    /// ```
+    pub fn render_pair(&self, format: RenderFormat) -> (String, String)
+    {
+        match format
+        {
+            RenderFormat::Ascii(options) => 
+            (
+                self.to_ascii(AsciiRenderOptions { show_solution: false, show_numbers: true, ..options.clone() }),
+                self.to_ascii(AsciiRenderOptions { show_solution: true, show_numbers: true, ..options }),
+            ),
+            #[cfg(feature = "html")]
+            RenderFormat::Html(options) => 
+            (
+                self.to_html(HtmlRenderOptions { show_solution: false, show_numbers: true, ..options.clone() }),
+                self.to_html(HtmlRenderOptions { show_solution: true, show_numbers: true, ..options }),
+            ),
+            #[cfg(feature = "svg")]
+            RenderFormat::Svg(style) => 
+            (
+                self.to_svg(SvgStyle { show_solution: false, show_numbers: true, ..style.clone() }),
+                self.to_svg(SvgStyle { show_solution: true, show_numbers: true, ..style }),
+            ),
+        }
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Display for Crossword<CharT, StrT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        write!(f, "{}", self.to_ascii(AsciiRenderOptions::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{placed_word::PlacedWord, word::{Direction, Position}};
+
+    use super::*;
+
+    fn test_crossword() -> Crossword<char, Vec<char>>
+    {
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("hello".chars().collect(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("local".chars().collect(), Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("cat".chars().collect(), Position { x: 2, y: 2 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("toy".chars().collect(), Position { x: 4, y: 2 }, Direction::Down)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("yellow".chars().collect(), Position { x: 4, y: 4 }, Direction::Right)).unwrap();
+        cw
+    }
+
+    #[test]
+    fn test_render_to_ascii()
+    {
+        let cw = test_crossword();
+
+        assert_eq!(cw.to_ascii(AsciiRenderOptions { coordinates: true, ..Default::default() }), [
+            "   0 1 2 3 4 5 6 7 8 9 ",
+            "   ------------------- ",
+            "0 |h e l l o          |",
+            "1 |    o              |",
+            "2 |    c a t          |",
+            "3 |    a   o          |",
+            "4 |    l   y e l l o w|",
+            "   ------------------- ",
+        ].join("\n"));
+
+        assert_eq!(format!("{cw}"), cw.to_ascii(AsciiRenderOptions::default()));
+        assert_eq!(Crossword::<u8, &str>::default().to_ascii(AsciiRenderOptions { border: false, ..Default::default() }), "");
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn test_render_to_svg()
+    {
+        let cw = test_crossword();
+        let filled = cw.filled_cell_count();
+        let (w, h) = cw.get_size();
+
+        let svg = cw.to_svg(SvgStyle::default());
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert!(svg.contains(r#"width="320" height="160""#));
+        assert_eq!(svg.matches(r#"class="cell""#).count(), filled);
+        assert_eq!(svg.matches(r#"class="letter""#).count(), filled);
+        assert_eq!(svg.matches(r#"class="block""#).count(), 0);
+        // hello and local start on different cells, cat and toy too, yellow starts on the last cell of toy
+        let numbers = cw.numbered_entries().into_iter().map(|e| e.number).max().unwrap();
+        assert_eq!(svg.matches(r#"class="number""#).count(), numbers as usize);
+        assert!(svg.contains(">5</text>"));
+
+        let svg = cw.to_svg(SvgStyle { shade_empty_cells: true, show_solution: false, show_numbers: false, ..Default::default() });
+        assert_eq!(svg.matches(r#"class="block""#).count(), (w * h) as usize - filled);
+        assert_eq!(svg.matches(r#"class="letter""#).count(), 0);
+        assert_eq!(svg.matches(r#"class="number""#).count(), 0);
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("a<b".chars().collect(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        assert!(cw.to_svg(SvgStyle::default()).contains("&lt;"));
+    }
+
+    #[cfg(feature = "html")]
+    #[test]
+    fn test_render_to_html()
+    {
+        let cw = test_crossword();
+        let (w, h) = cw.get_size();
+
+        let html = cw.to_html(HtmlRenderOptions::default());
+        assert_eq!(html.matches("<td").count(), (w * h) as usize);
+        assert_eq!(html.matches("<tr>").count(), h as usize);
+        assert_eq!(html.matches(r#"class="crossword-block""#).count(), (w * h) as usize - cw.filled_cell_count());
+        assert_eq!(html.matches(r#"<sup class="crossword-number">"#).count(), cw.cell_numbers().len());
+
+        // the intersections of the words, toy starts at the end of cat
+        let cells = html.lines().filter(|line| line.starts_with("<td")).collect::<Vec<_>>();
+        for (x, y) in [(2, 0), (2, 2), (4, 2), (4, 4)]
+        {
+            assert_eq!(cells[y * w as usize + x].matches(r#"class="crossword-letter""#).count(), 1);
+        }
+        assert_eq!(cells[2 * w as usize + 4], r#"<td class="crossword-cell crossword-start"><sup class="crossword-number">4</sup><span class="crossword-letter">t</span></td>"#);
+
+        let html = cw.to_html(HtmlRenderOptions { prefix: "a\"b-".to_owned(), show_solution: false, show_numbers: false });
+        assert!(html.starts_with(r#"<table id="a&quot;b-grid""#));
+        assert!(!html.contains("letter") && !html.contains("<sup"));
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new("a<&".chars().collect(), Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        let html = cw.to_html(HtmlRenderOptions::default());
+        assert!(html.contains("&lt;") && html.contains("&amp;") && !html.contains("<&"));
+    }
+
+    #[test]
+    fn test_render_to_markdown_table_and_csv()
+    {
+        let cw = test_crossword();
+
+        assert_eq!(cw.to_markdown_table(".", true), [
+            "| 0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 |",
+            "|---|---|---|---|---|---|---|---|---|---|",
+            "| H | E | L | L | O | . | . | . | . | . |",
+            "| . | . | O | . | . | . | . | . | . | . |",
+            "| . | . | C | A | T | . | . | . | . | . |",
+            "| . | . | A | . | O | . | . | . | . | . |",
+            "| . | . | L | . | Y | E | L | L | O | W |",
+        ].join("\n"));
+
+        assert_eq!(cw.to_csv(false), [
+            "h,e,l,l,o,,,,,",
+            ",,o,,,,,,,",
+            ",,c,a,t,,,,,",
+            ",,a,,o,,,,,",
+            ",,l,,y,e,l,l,o,w",
+            "",
+        ].join("\n"));
+
+        assert_eq!(Crossword::<u8, &str>::default().to_markdown_table(" ", false), "");
+        assert_eq!(Crossword::<u8, &str>::default().to_csv(false), "");
+
+        let mut cw = Crossword::default();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(vec![',', '"', '|'], Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+        cw.add_word(PlacedWord::<char, Vec<char>>::new(vec!['"', 'a'], Position { x: 1, y: 0 }, Direction::Down)).unwrap();
+        assert_eq!(cw.to_csv(false), "\",\",\"\"\"\",|\n,a,\n");
+        assert_eq!(cw.to_markdown_table(",", false), "| 0 | 1 | 2 |\n|---|---|---|\n| , | \" | \\| |\n| , | a | , |");
+    }
+
+    #[test]
+    fn test_render_pair()
+    {
+        let cw = test_crossword();
+
+        let (puzzle, solution) = cw.render_pair(RenderFormat::Ascii(AsciiRenderOptions { filler: '.', coordinates: true, ..Default::default() }));
+        assert_eq!(solution, [
+            "    0  1  2  3  4  5  6  7  8  9 ",
+            "   ----------------------------- ",
+            "0 |1h  e 2l  l  o  .  .  .  .  .|",
+            "1 | .  .  o  .  .  .  .  .  .  .|",
+            "2 | .  . 3c  a 4t  .  .  .  .  .|",
+            "3 | .  .  a  .  o  .  .  .  .  .|",
+            "4 | .  .  l  . 5y  e  l  l  o  w|",
+            "   ----------------------------- ",
+        ].join("\n"));
+        assert_eq!(solution.chars().map(|c| if c.is_alphabetic() { '_' } else { c }).collect::<String>(), puzzle);
+
+        #[cfg(feature = "html")]
+        {
+            let strip_letters = |text: &str, letter: &str| text.lines().map(|line| match line.find(letter)
+            {
+                Some(start) => { let end = start + line[start..].find("</").unwrap(); format!("{}{}", &line[..start], &line[end..]) },
+                None => line.to_owned(),
+            }).join("\n");
+
+            let (puzzle, solution) = cw.render_pair(RenderFormat::Html(HtmlRenderOptions::default()));
+            assert_eq!(strip_letters(&solution, r#"<span class="crossword-letter">"#).replace("</span>", ""), puzzle);
+            assert_eq!(puzzle.matches(r#"<sup class="crossword-number">"#).count(), 5);
+        }
+
+        #[cfg(feature = "svg")]
+        {
+            let (puzzle, solution) = cw.render_pair(RenderFormat::Svg(SvgStyle { show_numbers: false, ..Default::default() }));
+            assert_eq!(solution.lines().filter(|line| !line.contains(r#"class="letter""#)).join("\n"), puzzle);
+            assert_eq!(puzzle.matches(r#"class="number""#).count(), 5);
+        }
+    }
+}
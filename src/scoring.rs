@@ -0,0 +1,172 @@
+use std::{cmp::{Ordering, Reverse}, collections::BinaryHeap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{crossword::Crossword, traits::{CrosswordChar, CrosswordString}};
+
+/// Represents a score function for [crosswords](Crossword), a weighted sum of their properties, crosswords with bigger scores are better.
+///
+/// By default crosswords with more intersections are better, and of those the more square ones.
+///
+/// # Example
+///
+/// ```
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::crossword::Crossword;
+/// # use crossword_generator::scoring::CrosswordScorer;
+/// let mut cw = Crossword::<u8, &str>::default();
+/// cw.add_word(PlacedWord::new("hello", Position { x: 0, y: 0 }, Direction::Right)).unwrap();
+/// cw.add_word(PlacedWord::new("local", Position { x: 2, y: 0 }, Direction::Down)).unwrap();
+///
+/// // one intersection, and the 5x5 crossword is a square
+/// assert_eq!(CrosswordScorer::default().score(&cw), 2.0);
+/// assert_eq!(CrosswordScorer { area: 0.1, ..Default::default() }.score(&cw), -0.5);
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct CrosswordScorer
+{
+    /// The weight of the area of the crossword (check [Crossword::get_size]), it is subtracted, so smaller crosswords are better, 0 by default.
+    pub area: f64,
+    /// The weight of the count of the intersections (check [Crossword::total_intersections]), 1 by default.
+    pub intersections: f64,
+    /// The weight of the ratio of the smaller and the bigger side of the crossword (from 0 to 1), 1 by default.
+    pub squareness: f64,
+}
+
+impl Default for CrosswordScorer
+{
+    fn default() -> Self
+    {
+        CrosswordScorer { area: 0.0, intersections: 1.0, squareness: 1.0 }
+    }
+}
+
+impl CrosswordScorer
+{
+    /// Returns the score of the crossword.
+    pub fn score<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, crossword: &Crossword<CharT, StrT>) -> f64
+    {
+        let (width, height) = crossword.get_size();
+        let squareness = if width == 0 || height == 0 { 0.0 } else { width.min(height) as f64 / width.max(height) as f64 };
+
+        self.intersections * crossword.total_intersections() as f64 +
+        self.squareness * squareness -
+        self.area * (width as f64 * height as f64)
+    }
+}
+
+// a crossword ordered by its score (and then by itself, so the order is total)
+struct Scored<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    score: f64,
+    crossword: Crossword<CharT, StrT>,
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialEq for Scored<CharT, StrT>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Eq for Scored<CharT, StrT> {}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialOrd for Scored<CharT, StrT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Ord for Scored<CharT, StrT>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.score.total_cmp(&other.score).then_with(|| other.crossword.cmp(&self.crossword))
+    }
+}
+
+// keeps the n best crosswords offered to it, without crosswords with the same layout
+pub(crate) struct BestCrosswords<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    n: usize,
+    // the worst kept crossword is on the top
+    heap: BinaryHeap<Reverse<Scored<CharT, StrT>>>,
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> BestCrosswords<CharT, StrT>
+{
+    pub(crate) fn new(n: usize) -> BestCrosswords<CharT, StrT>
+    {
+        BestCrosswords { n, heap: BinaryHeap::with_capacity(n + 1) }
+    }
+
+    pub(crate) fn offer(&mut self, scorer: &CrosswordScorer, crossword: Crossword<CharT, StrT>)
+    {
+        if self.heap.iter().any(|Reverse(kept)| kept.crossword.same_layout(&crossword)) { return; }
+
+        self.heap.push(Reverse(Scored { score: scorer.score(&crossword), crossword }));
+        if self.heap.len() > self.n { self.heap.pop(); }
+    }
+
+    // the best crossword first
+    pub(crate) fn into_sorted_vec(self) -> Vec<Crossword<CharT, StrT>>
+    {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(scored)| scored.crossword).collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{placed_word::PlacedWord, word::{Direction, Position}};
+
+    use super::*;
+
+    fn crossword(words: &[(&'static str, i16, i16, Direction)]) -> Crossword<u8, &'static str>
+    {
+        Crossword::from_placed_words(words.iter().map(|(value, x, y, dir)| PlacedWord::new(*value, Position { x: *x, y: *y }, dir.clone())), Default::default()).unwrap()
+    }
+
+    #[test]
+    fn test_scorer()
+    {
+        let scorer = CrosswordScorer::default();
+        assert_eq!(scorer.score(&Crossword::<u8, &str>::default()), 0.0);
+        assert_eq!(scorer.score(&crossword(&[("hello", 0, 0, Direction::Right)])), 0.2);
+
+        let cw = crossword(&[("hello", 0, 0, Direction::Right), ("local", 2, 0, Direction::Down), ("cat", 2, 2, Direction::Right)]);
+        assert_eq!(cw.get_size(), (5, 5));
+        assert_eq!(scorer.score(&cw), 3.0);
+        assert_eq!(CrosswordScorer { area: 1.0, intersections: 0.0, squareness: 0.0 }.score(&cw), -25.0);
+    }
+
+    #[test]
+    fn test_best_crosswords()
+    {
+        let small = crossword(&[("hello", 0, 0, Direction::Right), ("local", 2, 0, Direction::Down)]);
+        let big = crossword(&[("hello", 0, 0, Direction::Right), ("local", 2, 0, Direction::Down), ("cat", 2, 2, Direction::Right)]);
+        let line = crossword(&[("hello", 0, 0, Direction::Right)]);
+
+        let mut best = BestCrosswords::new(2);
+        for cw in [line.clone(), small.clone(), big.clone(), small.clone()]
+        {
+            best.offer(&CrosswordScorer::default(), cw);
+        }
+        assert_eq!(best.into_sorted_vec(), vec![big, small.clone()]);
+
+        // the same layout with other settings is a duplicate
+        let mut best = BestCrosswords::new(3);
+        let mut settings = small.word_compatibility_settings.clone();
+        settings.corner_by_corner = false;
+        best.offer(&CrosswordScorer::default(), small.clone());
+        best.offer(&CrosswordScorer::default(), Crossword::from_placed_words(small.iter().cloned(), settings).unwrap());
+        assert_eq!(best.into_sorted_vec(), vec![small]);
+
+        assert!(BestCrosswords::<u8, &str>::new(0).into_sorted_vec().is_empty());
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::BTreeSet;
+
+use itertools::iproduct;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString}, word::{CoordinateOverflowError, Direction, Position}};
+
+/// Represents a fixed rectangle of cells for a [crossword](crate::crossword::Crossword), where some of the cells are blocked (the black squares of a puzzle).
+///
+/// Words of a crossword with a template can occupy only the open cells of the rectangle, and their positions are absolute (the crossword is not normalized).
+///
+/// # Example
+///
+/// ```
+/// # use crossword_generator::word::{Direction, Position};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// # use crossword_generator::template::GridTemplate;
+/// let template = GridTemplate::from_strings(&["..#",
+///                                              "...",
+///                                              "#.."]).unwrap();
+///
+/// assert_eq!((template.width, template.height), (3, 3));
+/// assert!(template.is_blocked(&Position { x: 2, y: 0 }));
+/// assert!(template.can_contain(&PlacedWord::<u8, &str>::new("cat", Position { x: 0, y: 1 }, Direction::Right)));
+/// assert!(!template.can_contain(&PlacedWord::<u8, &str>::new("cat", Position { x: 0, y: 0 }, Direction::Right)));
+/// assert!(!template.can_contain(&PlacedWord::<u8, &str>::new("cat", Position { x: 1, y: 1 }, Direction::Right)));
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct GridTemplate
+{
+    pub width: u16,
+    pub height: u16,
+    pub blocked: BTreeSet<Position>,
+}
+
+/// Represents a slot of a [template](GridTemplate), a maximal sequence of open cells with length at least 2 (check [GridTemplate::slots]).
+/// 
+/// The direction of a slot is always [Direction::Right] or [Direction::Down].
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct Slot
+{
+    pub position: Position,
+    pub direction: Direction,
+    pub length: u16,
+}
+
+impl Slot
+{
+    /// Returns the positions of the cells of the slot, from the first one, the cells outside of the range of the coordinates are skipped.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_
+    {
+        let (dx, dy) = self.direction.step();
+        coordinates(self.length).map_while(move |ind| self.position.offset(dx * ind, dy * ind))
+    }
+}
+
+/// Error type for possible errors when creating a [template](GridTemplate) with [GridTemplate::from_strings].
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum GridTemplateError
+{
+    #[error("The character {0:?} is neither an open cell ('.') nor a blocked cell ('#')")]
+    InvalidCharacter(char),
+    #[error("The row with index {0} has a different length than the first row")]
+    UnevenRow(usize),
+    #[error(transparent)]
+    CoordinateOverflow(#[from] CoordinateOverflowError),
+}
+
+impl GridTemplate
+{
+    /// Creates a template with the size and without blocked cells.
+    pub fn new(width: u16, height: u16) -> GridTemplate
+    {
+        GridTemplate { width, height, blocked: BTreeSet::new() }
+    }
+
+    /// Creates a template from its rows, where '.' is an open cell and '#' is a blocked cell.
+    ///
+    /// # Errors
+    ///
+    /// [GridTemplateError::InvalidCharacter] - A row contains another character.
+    ///
+    /// [GridTemplateError::UnevenRow] - The rows have different lengths.
+    ///
+    /// [GridTemplateError::CoordinateOverflow] - The cells don't fit in the range of the coordinates (there are more than [i16::MAX] + 1 rows or columns).
+    pub fn from_strings(rows: &[&str]) -> Result<GridTemplate, GridTemplateError>
+    {
+        let width = rows.first().map(|row| row.chars().count()).unwrap_or(0);
+        // the coordinate of the last cell of a row or a column must fit in i16
+        let size = |len: usize| i16::try_from(len.saturating_sub(1)).map(|_| len as u16).map_err(|_| CoordinateOverflowError);
+        let mut template = GridTemplate::new(size(width)?, size(rows.len())?);
+
+        for (y, row) in (0..=i16::MAX).zip(rows)
+        {
+            if row.chars().count() != width { return Err(GridTemplateError::UnevenRow(y as usize)); }
+
+            for (x, c) in (0..=i16::MAX).zip(row.chars())
+            {
+                match c
+                {
+                    '.' => {},
+                    '#' => { template.blocked.insert(Position { x, y }); },
+                    c => return Err(GridTemplateError::InvalidCharacter(c)),
+                }
+            }
+        }
+
+        Ok(template)
+    }
+
+    /// Returns true if the position is inside the rectangle of the template.
+    pub fn is_inside(&self, pos: &Position) -> bool
+    {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as u16) < self.width && (pos.y as u16) < self.height
+    }
+
+    /// Returns true if the cell on the position is blocked.
+    pub fn is_blocked(&self, pos: &Position) -> bool
+    {
+        self.blocked.contains(pos)
+    }
+
+    /// Returns true if the position is inside the rectangle of the template, and the cell is not blocked.
+    pub fn is_open(&self, pos: &Position) -> bool
+    {
+        self.is_inside(pos) && !self.is_blocked(pos)
+    }
+
+    /// Returns true if all the characters of the [word](PlacedWord) are on open cells.
+    pub fn can_contain<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, word: &PlacedWord<CharT, StrT>) -> bool
+    {
+        (0..word.value.as_ref().len()).all(|ind| self.is_open(&word.char_position(ind)))
+    }
+
+    /// Returns the slots of the template, the entries a filled crossword must have.
+    /// 
+    /// The slots are sorted by the position of the first cell (row by row), and a horizontal slot comes before the vertical one with the same position.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::{Direction, Position};
+    /// # use crossword_generator::template::{GridTemplate, Slot};
+    /// let template = GridTemplate::from_strings(&["..#",
+    ///                                              "...",
+    ///                                              "#.."]).unwrap();
+    /// 
+    /// assert_eq!(template.slots(), vec![
+    ///     Slot { position: Position { x: 0, y: 0 }, direction: Direction::Right, length: 2 },
+    ///     Slot { position: Position { x: 0, y: 0 }, direction: Direction::Down, length: 2 },
+    ///     Slot { position: Position { x: 1, y: 0 }, direction: Direction::Down, length: 3 },
+    ///     Slot { position: Position { x: 0, y: 1 }, direction: Direction::Right, length: 3 },
+    ///     Slot { position: Position { x: 2, y: 1 }, direction: Direction::Down, length: 2 },
+    ///     Slot { position: Position { x: 1, y: 2 }, direction: Direction::Right, length: 2 },
+    /// ]);
+    /// ```
+    pub fn slots(&self) -> Vec<Slot>
+    {
+        let mut slots = vec![];
+        for direction in [Direction::Right, Direction::Down]
+        {
+            let (dx, dy) = direction.step();
+            for Position { x, y } in self.cells()
+            {
+                // a slot starts at an open cell after a closed one
+                if !self.is_open(&Position { x, y }) || self.is_open(&Position { x: x - dx, y: y - dy }) { continue; }
+
+                // the slot ends at the last open cell, or at the end of the range of the coordinates
+                let length = (0..=i16::MAX).map_while(|ind| Position { x, y }.offset(dx * ind, dy * ind)).take_while(|pos| self.is_open(pos)).count() as u16;
+                if length >= 2 { slots.push(Slot { position: Position { x, y }, direction: direction.clone(), length }); }
+            }
+        }
+
+        slots.sort_by_key(|slot| (slot.position.y, slot.position.x, slot.direction.clone()));
+        slots
+    }
+
+    /// Returns the positions of the cells of the template by rows, the cells outside of the range of the coordinates are skipped.
+    pub(crate) fn cells(&self) -> impl Iterator<Item = Position>
+    {
+        iproduct!(coordinates(self.height), coordinates(self.width)).map(|(y, x)| Position { x, y })
+    }
+
+    /// Returns the cell that the cell is moved to by mirroring the template, or None if it's outside of the range of the coordinates.
+    pub(crate) fn mirrored(&self, pos: &Position, horizontal: bool, vertical: bool) -> Option<Position>
+    {
+        let mirror = |size: u16, coord: i16, mirror: bool| if mirror { i16::try_from(i32::from(size) - 1 - i32::from(coord)).ok() } else { Some(coord) };
+        Some(Position { x: mirror(self.width, pos.x, horizontal)?, y: mirror(self.height, pos.y, vertical)? })
+    }
+
+    /// Returns the template with the cells transformed like the words of a [crossword](crate::crossword::Crossword) (transposing first, then mirroring).
+    pub(crate) fn transformed(&self, transpose: bool, mirror_horizontal: bool, mirror_vertical: bool) -> GridTemplate
+    {
+        let (width, height) = if transpose { (self.height, self.width) } else { (self.width, self.height) };
+        let mut res = GridTemplate::new(width, height);
+        // the blocked cells that are mirrored outside of the range of the coordinates can't be reached by the words anyway
+        res.blocked = self.blocked.iter()
+            .map(|pos| if transpose { Position { x: pos.y, y: pos.x } } else { pos.clone() })
+            .filter_map(|pos| res.mirrored(&pos, mirror_horizontal, mirror_vertical))
+            .collect();
+
+        res
+    }
+}
+
+
+// the coordinates of the cells of a row or a column of the size that fit in i16
+fn coordinates(size: u16) -> impl Iterator<Item = i16> + Clone
+{
+    (0..size).map_while(|ind| i16::try_from(ind).ok())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::word::Direction;
+
+    use super::*;
+
+    #[test]
+    fn test_grid_template_from_strings()
+    {
+        let template = GridTemplate::from_strings(&["..#..",
+                                                    ".....",
+                                                    "#...#"]).unwrap();
+
+        assert_eq!((template.width, template.height), (5, 3));
+        assert_eq!(template.blocked, BTreeSet::from([Position { x: 2, y: 0 }, Position { x: 0, y: 2 }, Position { x: 4, y: 2 }]));
+        assert!(template.is_open(&Position { x: 1, y: 2 }));
+        assert!(!template.is_open(&Position { x: 5, y: 0 }));
+        assert!(!template.is_open(&Position { x: 0, y: -1 }));
+
+        assert_eq!(GridTemplate::from_strings(&["..", ".x"]), Err(GridTemplateError::InvalidCharacter('x')));
+        assert_eq!(GridTemplate::from_strings(&["..", "...", ".."]), Err(GridTemplateError::UnevenRow(1)));
+        assert_eq!(GridTemplate::from_strings(&[]), Ok(GridTemplate::default()));
+
+        let row = ".".repeat(i16::MAX as usize + 1);
+        assert_eq!(GridTemplate::from_strings(&[&row]).map(|template| template.width), Ok(i16::MAX as u16 + 1));
+        let row = ".".repeat(i16::MAX as usize + 2);
+        assert_eq!(GridTemplate::from_strings(&[&row]), Err(GridTemplateError::CoordinateOverflow(CoordinateOverflowError)));
+    }
+
+    #[test]
+    fn test_grid_template_can_contain()
+    {
+        let template = GridTemplate::from_strings(&["..#..",
+                                                    ".....",
+                                                    "#...#"]).unwrap();
+
+        let word = |x, y, dir| PlacedWord::<u8, &str>::new("ab", Position { x, y }, dir);
+        assert!(template.can_contain(&word(0, 0, Direction::Right)));
+        assert!(template.can_contain(&word(3, 0, Direction::Right)));
+        assert!(template.can_contain(&word(1, 0, Direction::Down)));
+        assert!(template.can_contain(&word(4, 1, Direction::Left)));
+        assert!(template.can_contain(&word(2, 2, Direction::Up)));
+        assert!(!template.can_contain(&word(1, 0, Direction::Right)));
+        assert!(!template.can_contain(&word(4, 0, Direction::Right)));
+        assert!(!template.can_contain(&word(0, 1, Direction::Down)));
+        assert!(!template.can_contain(&word(0, 0, Direction::Up)));
+    }
+
+    #[test]
+    fn test_grid_template_transformed()
+    {
+        let template = GridTemplate::from_strings(&["..#",
+                                                    "...",]).unwrap();
+
+        assert_eq!(template.transformed(true, false, false), GridTemplate::from_strings(&["..", "..", "#."]).unwrap());
+        assert_eq!(template.transformed(false, true, false), GridTemplate::from_strings(&["#..", "..."]).unwrap());
+        assert_eq!(template.transformed(false, false, true), GridTemplate::from_strings(&["...", "..#"]).unwrap());
+        assert_eq!(template.transformed(true, true, true).transformed(true, true, true), template);
+
+        // the cells outside of the range of the coordinates are skipped
+        let mut template = GridTemplate::new(u16::MAX, 1);
+        template.blocked.insert(Position { x: 0, y: 0 });
+        template.blocked.insert(Position { x: i16::MAX, y: 0 });
+        assert_eq!(template.cells().count(), i16::MAX as usize + 1);
+        assert_eq!(template.transformed(false, true, false).blocked, BTreeSet::from([Position { x: i16::MAX, y: 0 }]));
+    }
+
+    #[test]
+    fn test_grid_template_slots()
+    {
+        //  ---------
+        // |. . # . .|
+        // |. . . . .|
+        // |# . # . #|
+        // |. . . . .|
+        // |. . # . .|
+        //  ---------
+        let template = GridTemplate::from_strings(&["..#..",
+                                                    ".....",
+                                                    "#.#.#",
+                                                    ".....",
+                                                    "..#.."]).unwrap();
+
+        let slot = |x, y, direction, length| Slot { position: Position { x, y }, direction, length };
+        assert_eq!(template.slots(), vec![
+            slot(0, 0, Direction::Right, 2),
+            slot(0, 0, Direction::Down, 2),
+            slot(1, 0, Direction::Down, 5),
+            slot(3, 0, Direction::Right, 2),
+            slot(3, 0, Direction::Down, 5),
+            slot(4, 0, Direction::Down, 2),
+            slot(0, 1, Direction::Right, 5),
+            slot(0, 3, Direction::Right, 5),
+            slot(0, 3, Direction::Down, 2),
+            slot(4, 3, Direction::Down, 2),
+            slot(0, 4, Direction::Right, 2),
+            slot(3, 4, Direction::Right, 2),
+        ]);
+
+        assert_eq!(slot(1, 0, Direction::Down, 3).positions().collect::<Vec<_>>(), vec![Position { x: 1, y: 0 }, Position { x: 1, y: 1 }, Position { x: 1, y: 2 }]);
+        assert!(template.slots().iter().all(|slot| slot.positions().all(|pos| template.is_open(&pos))));
+
+        // single open cells are not slots
+        assert_eq!(GridTemplate::from_strings(&[".#", "#."]).unwrap().slots(), vec![]);
+        assert_eq!(GridTemplate::new(2, 1).slots(), vec![slot(0, 0, Direction::Right, 2)]);
+
+        // the widest template, the slot ends at the end of the range of the coordinates
+        let row = ".".repeat(i16::MAX as usize + 1);
+        let template = GridTemplate::from_strings(&[&row]).unwrap();
+        assert_eq!(template.slots(), vec![slot(0, 0, Direction::Right, i16::MAX as u16 + 1)]);
+        assert_eq!(template.slots()[0].positions().last(), Some(Position { x: i16::MAX, y: 0 }));
+        assert_eq!(template.slots()[0].positions().count(), i16::MAX as usize + 1);
+        assert_eq!(slot(1, 0, Direction::Right, u16::MAX).positions().count(), i16::MAX as usize);
+    }
+}
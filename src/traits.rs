@@ -6,8 +6,75 @@ use trait_set::trait_set;
 trait_set! 
 {
     /// Trait for any type that can represent individual character in a [crossword](crate::crossword::Crossword).
-    pub trait CrosswordChar = Eq + PartialEq + Ord + PartialOrd + Clone + Default + Debug + Send + Sync + Hash;
+    /// 
+    /// This is the only definition of the trait, every module of the crate uses it. 
+    /// Ord is needed for the sorted collections of words and crosswords, Hash for the hash based indexes of characters (check [WordIndex](crate::word::WordIndex)), Send and Sync for the generation in an async runtime.
+    /// Default is not needed, only [Crossword::generate_char_table](crate::crossword::Crossword::generate_char_table) requires it, where the default value fills the empty cells.
+    /// 
+    /// # Example
+    /// 
+    /// A custom character and string type, used through the whole generation.
+    /// 
+    /// ```
+    /// # use crossword_generator::generator::{CrosswordGenerationRequest, CrosswordGenerator};
+    /// # use crossword_generator::word::Word;
+    /// # use tokio_stream::StreamExt;
+    /// #[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+    /// struct Digit(u8);
+    /// 
+    /// #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+    /// struct Number(Vec<Digit>);
+    /// 
+    /// impl AsRef<[Digit]> for Number
+    /// {
+    ///     fn as_ref(&self) -> &[Digit] { &self.0 }
+    /// }
+    /// 
+    /// let number = |n: &str| Number(n.bytes().map(|b| Digit(b - b'0')).collect());
+    /// 
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut generator = CrosswordGenerator::<Digit, Number>::default();
+    /// generator.words = ["123", "345", "51"].into_iter().map(|n| Word::new(number(n), None)).collect();
+    /// 
+    /// let mut str = generator.crossword_stream_sorted(|digits| Number(digits.to_vec()));
+    /// str.request_crossword(CrosswordGenerationRequest::All).await.unwrap();
+    /// let crosswords = str.collect::<Vec<_>>().await;
+    /// 
+    /// assert!(!crosswords.is_empty());
+    /// assert!(crosswords.iter().all(|cw| cw.len() == 3 && cw.find_word(&number("345")).is_some()));
+    /// # });
+    /// ```
+    pub trait CrosswordChar = Eq + PartialEq + Ord + PartialOrd + Clone + Debug + Send + Sync + Hash;
     
     /// Trait for any type that can represent individual word value in a [crossword](crate::crossword::Crossword).
+    /// 
+    /// Unlike the characters, the strings don't need Default, words are never created without a value.
+    /// Hash is needed for the same reason as for the characters, and so words, crosswords and generators can be used in hash based collections.
+    /// Check [CrosswordChar] for an example of a custom string type.
     pub trait CrosswordString<CharT: CrosswordChar> = AsRef<[CharT]> + Eq + PartialEq + Ord + PartialOrd + Clone + Debug + Send + Sync + Hash;
 }
+
+/// Trait for characters that can be rendered as text (check [crate::render]).
+/// 
+/// Implemented for u8 (rendered as an ASCII character) and char.
+pub trait RenderChar
+{
+    /// Returns the text representation of the character.
+    fn render(&self) -> String;
+}
+
+impl RenderChar for u8
+{
+    fn render(&self) -> String 
+    {
+        (*self as char).to_string()
+    }
+}
+
+impl RenderChar for char
+{
+    fn render(&self) -> String 
+    {
+        self.to_string()
+    }
+}
@@ -1,6 +1,7 @@
-use std::marker::PhantomData;
+use std::{cmp::Ordering, collections::{BTreeMap, BTreeSet, HashMap}, fmt::Display, hash::{Hash, Hasher}, marker::PhantomData, str::FromStr};
 use serde::{Deserialize, Serialize};
-use crate::traits::{CrosswordChar, CrosswordString};
+use thiserror::Error;
+use crate::{char_string::CharString, placed_word::PlacedWord, traits::{CrosswordChar, CrosswordString, RenderChar}};
 
 /// Represents the position of the first character of a [word](crate::placed_word::PlacedWord) placed in [crossword](crate::crossword::Crossword).
 #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
@@ -10,44 +11,793 @@ pub struct Position
     pub y: i16,
 }
 
+impl Position
+{
+    /// Returns the position moved by the offset, or [None] if a coordinate doesn't fit in i16.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::Position;
+    /// assert_eq!(Position { x: 1, y: 2 }.offset(-3, 4), Some(Position { x: -2, y: 6 }));
+    /// assert_eq!(Position { x: i16::MAX, y: 0 }.offset(1, 0), None);
+    /// ```
+    pub fn offset(&self, dx: i16, dy: i16) -> Option<Position>
+    {
+        Some(Position { x: self.x.checked_add(dx)?, y: self.y.checked_add(dy)? })
+    }
+}
+
+/// Error type for moving a [word](crate::placed_word::PlacedWord) outside of the range of the coordinates (check [Position::offset]).
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+#[error("The coordinates don't fit in i16")]
+pub struct CoordinateOverflowError;
+
+/// The maximum length of a [word](Word), longer words don't fit in the range of the coordinates of a normalized [crossword](crate::crossword::Crossword).
+pub const MAX_WORD_LENGTH: usize = i16::MAX as usize + 1;
+
+/// Error type for invalid [words](Word) and [placed words](crate::placed_word::PlacedWord).
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum WordError
+{
+    #[error("The word is empty")]
+    Empty,
+    #[error("The word has {0} characters, which is more than the maximum length")]
+    TooLong(usize),
+    #[error("A character of the word is outside of the range of the coordinates")]
+    OutOfRange,
+    #[error("The word can be placed only in the direction {0}")]
+    ConflictingDirection(Direction),
+}
+
+pub(crate) fn render_value<CharT: RenderChar>(value: &[CharT]) -> String
+{
+    value.iter().map(|c| c.render()).collect()
+}
+
+pub(crate) fn validate_value<CharT>(value: &[CharT]) -> Result<(), WordError>
+{
+    if value.is_empty() { return Err(WordError::Empty); }
+    if value.len() > MAX_WORD_LENGTH { return Err(WordError::TooLong(value.len())); }
+    Ok(())
+}
+
 /// Represents the direction of a [word](crate::placed_word::PlacedWord) placed in [crossword](crate::crossword::Crossword).
+/// 
+/// Words in directions [Direction::Left] and [Direction::Up] are read from right to left and from bottom to top, 
+/// so their [position](Position) is the rightmost or the bottom cell of the word (check [crate::crossword::WordCompatibilitySettings::allowed_directions]).
+/// 
+/// Serialized as "across", "down", "left" and "up", the old names "Right" and "Down" are also accepted when deserializing.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::word::Direction;
+/// assert_eq!(serde_json::to_string(&Direction::Right).unwrap(), r#""across""#);
+/// assert_eq!(serde_json::from_str::<Direction>(r#""Right""#).unwrap(), Direction::Right);
+/// assert_eq!("Down".parse::<Direction>(), Ok(Direction::Down));
+/// assert_eq!(Direction::Down.to_string(), "down");
+/// ```
 #[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
 pub enum Direction
 {
     #[default]
+    #[serde(rename = "across", alias = "Right")]
     Right,
+    #[serde(rename = "down", alias = "Down")]
     Down,
+    #[serde(rename = "left")]
+    Left,
+    #[serde(rename = "up")]
+    Up,
 }
 
 impl Direction
 {
+    /// All directions, in the order of [Direction::iter].
+    pub const ALL: [Direction; 4] = [Direction::Right, Direction::Down, Direction::Left, Direction::Up];
+
+    /// Returns an iterator over all directions.
+    pub fn iter() -> impl Iterator<Item = Direction>
+    {
+        Direction::ALL.into_iter()
+    }
+
+    /// Returns the perpendicular direction, that the direction becomes after transposing (swapping x and y coordinates).
     pub fn opposite(&self) -> Direction
     {
         match *self
         {
             Direction::Right => Direction::Down,
             Direction::Down => Direction::Right,
+            Direction::Left => Direction::Up,
+            Direction::Up => Direction::Left,
+        }
+    }
+
+    /// Returns the direction that goes the other way on the same line.
+    pub fn reversed(&self) -> Direction
+    {
+        match *self
+        {
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Up => Direction::Down,
+        }
+    }
+
+    /// Returns true for [Direction::Right] and [Direction::Left].
+    pub fn is_horizontal(&self) -> bool
+    {
+        matches!(*self, Direction::Right | Direction::Left)
+    }
+
+    /// Returns the change of the coordinates from a character of a word to the next character.
+    pub fn step(&self) -> (i16, i16)
+    {
+        match *self
+        {
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Up => (0, -1),
+        }
+    }
+}
+
+impl Display for Direction
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        match *self
+        {
+            Direction::Right => write!(f, "across"),
+            Direction::Down => write!(f, "down"),
+            Direction::Left => write!(f, "left"),
+            Direction::Up => write!(f, "up"),
+        }
+    }
+}
+
+/// Error type for parsing a [direction](Direction) from a string.
+#[derive(Error, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Hash)]
+#[error("Invalid direction \"{0}\", expected \"across\", \"right\", \"down\", \"left\" or \"up\".")]
+pub struct ParseDirectionError(pub String);
+
+impl FromStr for Direction
+{
+    type Err = ParseDirectionError;
+
+    /// Parses "across" or "right" as [Direction::Right], "down", "left" and "up" as other directions, ignoring the case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> 
+    {
+        match s.to_lowercase().as_str()
+        {
+            "across" | "right" => Ok(Direction::Right),
+            "down" => Ok(Direction::Down),
+            "left" => Ok(Direction::Left),
+            "up" => Ok(Direction::Up),
+            _ => Err(ParseDirectionError(s.to_owned())),
         }
     }
 }
 
+/// Represents a flag that can have different values for horizontal ([Direction::Right] and [Direction::Left]) and vertical ([Direction::Down] and [Direction::Up]) words.
+/// 
+/// Can be created from a bool, that sets the value for both directions.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct DirectionFlags
+{
+    pub horizontal: bool,
+    pub vertical: bool,
+}
+
+impl DirectionFlags
+{
+    /// Returns the value of the flag for the specified direction.
+    pub fn get(&self, dir: &Direction) -> bool
+    {
+        if dir.is_horizontal() { self.horizontal } else { self.vertical }
+    }
+}
+
+impl From<bool> for DirectionFlags
+{
+    fn from(value: bool) -> Self 
+    {
+        DirectionFlags { horizontal: value, vertical: value }
+    }
+}
+
 /// Represents a word outside of a [crossword](crate::crossword::Crossword), has no particular [position](Position), but can have a specified [direction](Direction) that when generating crosswords, the word will be only in the specified direction.
 /// 
 /// Accepts two template parameters, that specify the type of individual characters in the word and the type of the word itself (for example u8 and &str, or if you want your crossword to consist of numbers, Digit and Vec\<Digit\> (where Digit is a type that accepts only numbers from 0 to 9)) .
-#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+/// 
+/// Words are compared, ordered and hashed only by their values and directions, the metadata and the other fields are ignored, 
+/// so a set of words (like [CrosswordGenerator::words](crate::generator::CrosswordGenerator::words)) keeps only one of the words that differ only in them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Word<CharT: CrosswordChar, StrT: CrosswordString<CharT>>
 {
     pub value: StrT,
     pub dir: Option<Direction>,
+    #[serde(flatten)]
+    pub meta: WordMetadata,
+    /// Words with bigger weights are placed first by the [generator](crate::generator::CrosswordGenerator), words with the same weights are placed in their usual order.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub weight: i32,
+    /// The [generator](crate::generator::CrosswordGenerator) can leave optional words out of the generated crosswords, required words are always placed.
+    #[serde(default = "default_required", skip_serializing_if = "is_true")]
+    pub required: bool,
+    /// Restricts the positions of the word, when it is added to a [crossword](crate::crossword::Crossword) (check [PlacementConstraint]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement_constraint: Option<PlacementConstraint>,
     #[serde(skip)]
     character_type: PhantomData<CharT>
 } 
 
+/// Represents a restriction on the cells of a [word](Word) placed in a [crossword](crate::crossword::Crossword) (check [crate::crossword::Crossword::calculate_possible_ways_to_add_word]).
+/// 
+/// For crosswords without a [template](crate::template::GridTemplate) the coordinates are relative to the top left corner of the words 
+/// that are already in the crossword (the coordinates of the normalized crossword, even if it was edited without normalizing), 
+/// so words added after it can move the word to other rows and columns of the final crossword. 
+/// For crosswords with a template they are the coordinates of the template.
+/// In an empty crossword the word is placed so, that it satisfies the constraint.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::word::{Direction, Position, PlacementConstraint};
+/// # use crossword_generator::placed_word::PlacedWord;
+/// let word = PlacedWord::<u8, &str>::new("local", Position { x: 2, y: 0 }, Direction::Down);
+/// assert!(PlacementConstraint::Column(2).allows(&word));
+/// assert!(PlacementConstraint::Through(Position { x: 2, y: 4 }).allows(&word));
+/// assert!(!PlacementConstraint::Row(1).allows(&word));
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Serialize, Deserialize, Hash)]
+pub enum PlacementConstraint
+{
+    /// The word must start at the position.
+    ExactPosition(Position),
+    /// The word must start on the row.
+    Row(i16),
+    /// The word must start on the column.
+    Column(i16),
+    /// The word must have a character on the position.
+    Through(Position),
+}
+
+impl PlacementConstraint
+{
+    /// Returns true if the placed word satisfies the constraint.
+    pub fn allows<CharT: CrosswordChar, StrT: CrosswordString<CharT>>(&self, word: &PlacedWord<CharT, StrT>) -> bool
+    {
+        match self
+        {
+            PlacementConstraint::ExactPosition(pos) => word.position == *pos,
+            PlacementConstraint::Row(y) => word.position.y == *y,
+            PlacementConstraint::Column(x) => word.position.x == *x,
+            PlacementConstraint::Through(pos) => word.contains_position(pos.clone()),
+        }
+    }
+
+    // the constraint moved by the offset, None if it leaves the range of the coordinates
+    pub(crate) fn offset(&self, dx: i16, dy: i16) -> Option<PlacementConstraint>
+    {
+        Some(match self
+        {
+            PlacementConstraint::ExactPosition(pos) => PlacementConstraint::ExactPosition(pos.offset(dx, dy)?),
+            PlacementConstraint::Row(y) => PlacementConstraint::Row(y.checked_add(dy)?),
+            PlacementConstraint::Column(x) => PlacementConstraint::Column(x.checked_add(dx)?),
+            PlacementConstraint::Through(pos) => PlacementConstraint::Through(pos.offset(dx, dy)?),
+        })
+    }
+
+    /// Returns the position for the word in an empty crossword, that satisfies the constraint.
+    pub(crate) fn anchor(&self) -> Position
+    {
+        match self
+        {
+            PlacementConstraint::ExactPosition(pos) | PlacementConstraint::Through(pos) => pos.clone(),
+            PlacementConstraint::Row(y) => Position { x: 0, y: *y },
+            PlacementConstraint::Column(x) => Position { x: *x, y: 0 },
+        }
+    }
+}
+
+fn is_zero(weight: &i32) -> bool
+{
+    *weight == 0
+}
+
+fn default_required() -> bool
+{
+    true
+}
+
+fn is_true(required: &bool) -> bool
+{
+    *required
+}
+
+// an empty word, that is required like the words created with Word::new
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT> + Default> Default for Word<CharT, StrT>
+{
+    fn default() -> Self 
+    {
+        Word { value: StrT::default(), dir: None, meta: WordMetadata::default(), weight: 0, required: true, placement_constraint: None, character_type: PhantomData }
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialEq for Word<CharT, StrT>
+{
+    fn eq(&self, other: &Self) -> bool 
+    {
+        self.key() == other.key()
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Eq for Word<CharT, StrT> {}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> PartialOrd for Word<CharT, StrT>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> 
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Ord for Word<CharT, StrT>
+{
+    fn cmp(&self, other: &Self) -> Ordering 
+    {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Hash for Word<CharT, StrT>
+{
+    fn hash<H: Hasher>(&self, state: &mut H) 
+    {
+        self.key().hash(state)
+    }
+}
+
+/// Represents the metadata of a [word](Word), that the generated crosswords keep in their [placed words](PlacedWord).
+/// 
+/// Serialized with the fields of the word, the empty fields are skipped.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct WordMetadata
+{
+    /// The clue of the word, the exporters of the crosswords use it, if the clue is not specified in their map of clues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clue: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
+    /// The group of the word in the word list (for example "animals"), counts as a tag of the word.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl WordMetadata
+{
+    /// Returns true if the metadata has neither a clue nor tags nor a category.
+    pub fn is_empty(&self) -> bool
+    {
+        self.clue.is_none() && self.tags.is_empty() && self.category.is_none()
+    }
+
+    /// Returns true if the tag is one of the tags or the category.
+    pub fn has_tag(&self, tag: &str) -> bool
+    {
+        self.category.as_deref() == Some(tag) || self.tags.contains(tag)
+    }
+}
+
+impl Word<char, CharString>
+{
+    /// Creates a word with unicode characters from the string, without a direction (check [CharString]).
+    pub fn from_str_chars(value: &str) -> Word<char, CharString>
+    {
+        Word::new(CharString::from(value), None)
+    }
+}
+
 impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Word<CharT, StrT>
 {
+    // the fields that are compared
+    fn key(&self) -> (&StrT, &Option<Direction>)
+    {
+        (&self.value, &self.dir)
+    }
+
     // you can specify a constraint on direction with Some(direction).
+    // the value isn't checked, use try_new to reject the empty and the too long values.
     pub fn new(val: StrT, dir: Option<Direction>) -> Word<CharT, StrT>
     {
-        Word { value: val, dir, character_type: PhantomData }
+        Word { value: val, dir, meta: WordMetadata::default(), weight: 0, required: true, placement_constraint: None, character_type: PhantomData }
     } 
-}
\ No newline at end of file
+
+    /// Creates a word, checking that it can be placed in a [crossword](crate::crossword::Crossword).
+    /// 
+    /// # Errors
+    /// 
+    /// [WordError::Empty] - The word is empty.
+    /// 
+    /// [WordError::TooLong] - The word is longer than [MAX_WORD_LENGTH].
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Word, WordError};
+    /// assert!(Word::<u8, &str>::try_new("hello", None).is_ok());
+    /// assert_eq!(Word::<u8, &str>::try_new("", None), Err(WordError::Empty));
+    /// ```
+    pub fn try_new(val: StrT, dir: Option<Direction>) -> Result<Word<CharT, StrT>, WordError>
+    {
+        validate_value(val.as_ref())?;
+        Ok(Word { value: val, dir, meta: WordMetadata::default(), weight: 0, required: true, placement_constraint: None, character_type: PhantomData })
+    }
+
+    /// Returns the same word with another value.
+    pub(crate) fn with_value<StrT2: CrosswordString<CharT>>(&self, value: StrT2) -> Word<CharT, StrT2>
+    {
+        Word { value, dir: self.dir.clone(), meta: self.meta.clone(), weight: self.weight, required: self.required, placement_constraint: self.placement_constraint.clone(), character_type: PhantomData }
+    }
+
+    /// Returns the word with the weight.
+    pub fn with_weight(mut self, weight: i32) -> Word<CharT, StrT>
+    {
+        self.weight = weight;
+        self
+    }
+
+    /// Returns the word with the placement constraint.
+    pub fn with_placement_constraint(mut self, constraint: PlacementConstraint) -> Word<CharT, StrT>
+    {
+        self.placement_constraint = Some(constraint);
+        self
+    }
+
+    /// Returns the word, that is required or optional.
+    pub fn with_required(mut self, required: bool) -> Word<CharT, StrT>
+    {
+        self.required = required;
+        self
+    }
+
+    /// Returns the word with the metadata.
+    pub fn with_meta(mut self, meta: WordMetadata) -> Word<CharT, StrT>
+    {
+        self.meta = meta;
+        self
+    }
+
+    /// Returns the word with the clue.
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::Word;
+    /// let word = Word::<u8, &str>::from("cat").with_clue("Feline").with_tag("animals");
+    /// assert_eq!(word.meta.clue.as_deref(), Some("Feline"));
+    /// assert!(word.meta.tags.contains("animals"));
+    /// ```
+    pub fn with_clue(mut self, clue: impl Into<String>) -> Word<CharT, StrT>
+    {
+        self.meta.clue = Some(clue.into());
+        self
+    }
+
+    /// Returns the word with the tag added to its tags.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Word<CharT, StrT>
+    {
+        self.meta.tags.insert(tag.into());
+        self
+    }
+
+    /// Returns the word with the category.
+    pub fn with_category(mut self, category: impl Into<String>) -> Word<CharT, StrT>
+    {
+        self.meta.category = Some(category.into());
+        self
+    }
+
+    /// Checks that the word can be placed in a [crossword](crate::crossword::Crossword), the fields are public, so a word can become invalid after creating it.
+    /// 
+    /// # Errors
+    /// 
+    /// Same as [Word::try_new].
+    pub fn validate(&self) -> Result<(), WordError>
+    {
+        validate_value(self.value.as_ref())
+    }
+
+    /// Places the word on the position in the direction, the placed word keeps the metadata.
+    /// 
+    /// # Errors
+    /// 
+    /// [WordError::ConflictingDirection] - The word has a direction constraint, that is different from the direction.
+    /// 
+    /// Other errors are the same as for [PlacedWord::try_new].
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Word, WordError, Position, Direction};
+    /// # use crossword_generator::placed_word::PlacedWord;
+    /// let word = Word::<u8, &str>::from("hello");
+    /// assert_eq!(word.place(Position { x: 1, y: 2 }, Direction::Down), Ok(PlacedWord::from(("hello", Position { x: 1, y: 2 }, Direction::Down))));
+    /// 
+    /// let word = Word::<u8, &str>::new("hello", Some(Direction::Right));
+    /// assert_eq!(word.place(Position { x: 1, y: 2 }, Direction::Down), Err(WordError::ConflictingDirection(Direction::Right)));
+    /// ```
+    pub fn place(self, pos: Position, dir: Direction) -> Result<PlacedWord<CharT, StrT>, WordError>
+    {
+        if let Some(required) = self.dir.filter(|d| *d != dir) { return Err(WordError::ConflictingDirection(required)); }
+        Ok(PlacedWord::try_new(self.value, pos, dir)?.with_meta(self.meta))
+    }
+
+    /// Returns true if the word has the length of the pattern, and has the characters of the pattern on the positions where the pattern is not [None].
+    /// 
+    /// The patterns of the unfilled slots of a crossword are returned by [crate::crossword::Crossword::unfilled_slots].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # use crossword_generator::word::Word;
+    /// let word = Word::<u8, &str>::new("cat", None);
+    /// 
+    /// assert!(word.matches_pattern(&[Some(b'c'), None, None]));
+    /// assert!(!word.matches_pattern(&[Some(b'c'), None, Some(b'r')]));
+    /// assert!(!word.matches_pattern(&[Some(b'c'), None]));
+    /// ```
+    pub fn matches_pattern(&self, pattern: &[Option<CharT>]) -> bool
+    {
+        let value = self.value.as_ref();
+        value.len() == pattern.len() && value.iter().zip(pattern).all(|(c, p)| p.as_ref().is_none_or(|p| p == c))
+    }
+}
+
+impl<CharT: CrosswordChar + RenderChar, StrT: CrosswordString<CharT>> Display for Word<CharT, StrT>
+{
+    /// Formats the word with its direction constraint, like "hello \[any\]" or "hello \[Down only\]".
+    /// 
+    /// # Example
+    /// ```
+    /// # use crossword_generator::word::{Word, Direction};
+    /// assert_eq!(Word::<u8, &str>::new("hello", None).to_string(), "hello [any]");
+    /// assert_eq!(Word::<u8, &str>::new("hello", Some(Direction::Down)).to_string(), "hello [Down only]");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result 
+    {
+        write!(f, "{} ", render_value(self.value.as_ref()))?;
+        match &self.dir
+        {
+            Some(dir) => write!(f, "[{dir:?} only]"),
+            None => write!(f, "[any]"),
+        }
+    }
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> From<StrT> for Word<CharT, StrT>
+{
+    /// Creates a word without a direction constraint.
+    fn from(value: StrT) -> Self
+    {
+        Word::new(value, None)
+    }
+}
+
+/// Returns the [words](Word) that match the pattern (check [Word::matches_pattern]).
+/// 
+/// For a lot of patterns on the same words, check [WordIndex].
+pub fn filter_words_matching<'a, CharT: CrosswordChar + 'a, StrT: CrosswordString<CharT> + 'a>(words: impl Iterator<Item = &'a Word<CharT, StrT>>, pattern: &[Option<CharT>]) -> Vec<&'a Word<CharT, StrT>>
+{
+    words.filter(|w| w.matches_pattern(pattern)).collect()
+}
+
+/// Represents an index of [words](Word) for finding the words that match a pattern fast (check [Word::matches_pattern]).
+/// 
+/// The words are grouped by their length, and for every length, by the character on every position.
+/// 
+/// # Example
+/// 
+/// ```
+/// # use crossword_generator::word::{Word, WordIndex};
+/// let words = ["cat", "car", "cow", "dog", "cart"].map(|w| Word::<u8, &str>::new(w, None));
+/// let index = WordIndex::new(words.iter());
+/// 
+/// let candidates = index.candidates(&[Some(b'c'), None, Some(b't')]);
+/// assert_eq!(candidates, vec![&words[0]]);
+/// 
+/// assert_eq!(index.candidates(&[None, Some(b'o'), None]).len(), 2);
+/// assert_eq!(index.candidates(&[None; 4]).len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordIndex<'a, CharT: CrosswordChar, StrT: CrosswordString<CharT>>
+{
+    by_length: BTreeMap<usize, Vec<&'a Word<CharT, StrT>>>,
+    // the indices of the words in the group of their length, by the length, the position and the character 
+    by_char: HashMap<(usize, usize, CharT), Vec<usize>>,
+}
+
+impl<CharT: CrosswordChar, StrT: CrosswordString<CharT>> Default for WordIndex<'_, CharT, StrT>
+{
+    fn default() -> Self 
+    {
+        WordIndex { by_length: BTreeMap::new(), by_char: HashMap::new() }
+    }
+}
+
+impl<'a, CharT: CrosswordChar, StrT: CrosswordString<CharT>> WordIndex<'a, CharT, StrT>
+{
+    /// Creates the index of the [words](Word).
+    pub fn new(words: impl IntoIterator<Item = &'a Word<CharT, StrT>>) -> WordIndex<'a, CharT, StrT>
+    {
+        let mut index = WordIndex { by_length: BTreeMap::new(), by_char: HashMap::new() };
+        for word in words
+        {
+            let value = word.value.as_ref();
+            let group = index.by_length.entry(value.len()).or_default();
+            for (pos, c) in value.iter().enumerate()
+            {
+                index.by_char.entry((value.len(), pos, c.clone())).or_default().push(group.len());
+            }
+            group.push(word);
+        }
+
+        index
+    }
+
+    /// Returns the [words](Word) that match the pattern (check [Word::matches_pattern]), in the order they were given to the index.
+    pub fn candidates(&self, pattern: &[Option<CharT>]) -> Vec<&'a Word<CharT, StrT>>
+    {
+        let Some(group) = self.by_length.get(&pattern.len()) else { return vec![]; };
+
+        // only the words with the rarest known character of the pattern are checked
+        let known = pattern.iter().enumerate().filter_map(|(pos, c)| c.as_ref().map(|c| self.by_char.get(&(pattern.len(), pos, c.clone()))));
+        match known.min_by_key(|indices| indices.map_or(0, |indices| indices.len()))
+        {
+            None => group.clone(),
+            Some(None) => vec![],
+            Some(Some(indices)) => indices.iter().map(|&ind| group[ind]).filter(|w| w.matches_pattern(pattern)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashSet;
+
+    use crate::crossword::Crossword;
+
+    use super::*;
+
+    #[test]
+    fn test_word_try_new()
+    {
+        assert_eq!(Word::<u8, &str>::try_new("", None), Err(WordError::Empty));
+        assert_eq!(Word::<u8, String>::try_new("a".repeat(MAX_WORD_LENGTH + 1), Some(Direction::Right)), Err(WordError::TooLong(MAX_WORD_LENGTH + 1)));
+        assert_eq!(Word::<u8, String>::try_new("a".repeat(MAX_WORD_LENGTH), None).map(|w| w.value.len()), Ok(MAX_WORD_LENGTH));
+
+        let mut word = Word::<u8, &str>::try_new("cat", None).unwrap();
+        assert_eq!(word.validate(), Ok(()));
+        word.value = "";
+        assert_eq!(word.validate(), Err(WordError::Empty));
+    }
+
+    #[test]
+    fn test_word_matches_pattern()
+    {
+        let words = ["cat", "car", "cow", "dog", "cart", "", "tac"].map(|w| Word::<u8, &str>::new(w, None));
+        let index = WordIndex::new(words.iter());
+        let pattern = |p: &str| p.bytes().map(|c| (c != b'.').then_some(c)).collect::<Vec<_>>();
+
+        for (p, expected) in [
+            // empty patterns
+            ("", vec![""]),
+            ("...", vec!["cat", "car", "cow", "dog", "tac"]),
+            ("......", vec![]),
+            // full patterns
+            ("cat", vec!["cat"]),
+            ("cart", vec!["cart"]),
+            // mismatch at the last letter
+            ("cax", vec![]),
+            ("carx", vec![]),
+            ("ca.", vec!["cat", "car"]),
+            ("c.t", vec!["cat"]),
+            ("..g", vec!["dog"]),
+            ("z..", vec![]),
+        ]
+        {
+            let p = pattern(p);
+            let matching = filter_words_matching(words.iter(), &p).into_iter().map(|w| w.value).collect::<Vec<_>>();
+            assert_eq!(matching, expected, "pattern {:?}", p);
+            assert_eq!(index.candidates(&p).into_iter().map(|w| w.value).collect::<Vec<_>>(), expected, "pattern {:?}", p);
+        }
+
+        assert!(!words[0].matches_pattern(&pattern("ca")));
+        assert!(!words[0].matches_pattern(&pattern("tac")));
+        assert!(WordIndex::<u8, &str>::default().candidates(&pattern("...")).is_empty());
+    }
+
+    #[test]
+    fn test_direction_names()
+    {
+        for dir in Direction::iter()
+        {
+            assert_eq!(dir.to_string().parse::<Direction>(), Ok(dir.clone()));
+            assert_eq!(dir.to_string().to_uppercase().parse::<Direction>(), Ok(dir.clone()));
+
+            let json = serde_json::to_string(&dir).unwrap();
+            assert_eq!(json, format!("\"{dir}\""));
+            assert_eq!(serde_json::from_str::<Direction>(&json).unwrap(), dir);
+        }
+
+        assert_eq!("Right".parse::<Direction>(), Ok(Direction::Right));
+        assert_eq!("Left".parse::<Direction>(), Ok(Direction::Left));
+        assert_eq!("sideways".parse::<Direction>(), Err(ParseDirectionError("sideways".to_owned())));
+        assert_eq!(serde_json::from_str::<Direction>(r#""Right""#).unwrap(), Direction::Right);
+        assert_eq!(serde_json::from_str::<Direction>(r#""Down""#).unwrap(), Direction::Down);
+        assert!(serde_json::from_str::<Direction>(r#""right""#).is_err());
+        assert_eq!(Direction::iter().collect::<Vec<_>>(), vec![Direction::Right, Direction::Down, Direction::Left, Direction::Up]);
+
+        for dir in Direction::iter()
+        {
+            assert_eq!(dir.reversed().reversed(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_ne!(dir.opposite().is_horizontal(), dir.is_horizontal());
+            assert_eq!(dir.reversed().step(), (-dir.step().0, -dir.step().1));
+            assert_eq!(dir.opposite().step(), (dir.step().1, dir.step().0));
+        }
+    }
+
+    #[test]
+    fn test_word_display()
+    {
+        assert_eq!(Word::<u8, String>::new("hello".to_owned(), None).to_string(), "hello [any]");
+        assert_eq!(Word::<u8, String>::new("hello".to_owned(), Some(Direction::Down)).to_string(), "hello [Down only]");
+        assert_eq!(Word::<char, Vec<char>>::new(vec!['ա', 'բ'], Some(Direction::Right)).to_string(), "աբ [Right only]");
+    }
+
+    #[test]
+    fn test_word_metadata_serialization()
+    {
+        let word = Word::<u8, String>::new("cat".to_owned(), None);
+        assert_eq!(serde_json::to_value(&word).unwrap(), serde_json::json!({ "value": "cat", "dir": null }));
+        assert_eq!(serde_json::from_value::<Word<u8, String>>(serde_json::json!({ "value": "cat", "dir": null })).unwrap(), word);
+
+        let word = word.with_clue("Feline").with_tag("animals").with_tag("short");
+        let json = serde_json::json!({ "value": "cat", "dir": null, "clue": "Feline", "tags": ["animals", "short"] });
+        assert_eq!(serde_json::to_value(&word).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Word<u8, String>>(json).unwrap().meta, word.meta);
+
+        let placed = word.clone().place(Position { x: 1, y: 2 }, Direction::Down).unwrap();
+        assert_eq!(placed.meta, word.meta);
+        assert_eq!(serde_json::to_value(&placed).unwrap()["clue"], "Feline");
+        assert_eq!(placed.as_word().meta, word.meta);
+    }
+
+    #[test]
+    fn test_word_comparison_ignores_metadata()
+    {
+        let word = Word::<u8, &str>::new("cat", None);
+        let other = word.clone().with_clue("Feline").with_tag("animals").with_weight(2).with_required(false).with_placement_constraint(PlacementConstraint::Row(0));
+        assert_eq!(word, other);
+        assert_eq!(word.cmp(&other), Ordering::Equal);
+        assert_eq!(BTreeSet::from([word.clone(), other.clone()]).len(), 1);
+        assert_eq!(HashSet::from([word.clone(), other.clone()]).len(), 1);
+        assert_ne!(word, Word::new("cat", Some(Direction::Right)));
+        assert_ne!(word, Word::new("car", None));
+
+        let placed = word.place(Position { x: 1, y: 2 }, Direction::Down).unwrap();
+        let other_placed = other.place(Position { x: 1, y: 2 }, Direction::Down).unwrap();
+        assert_ne!(placed.meta, other_placed.meta);
+        assert_eq!(placed, other_placed);
+        assert_eq!(HashSet::from([placed.clone(), other_placed.clone()]).len(), 1);
+        assert_ne!(placed, PlacedWord::new("cat", Position { x: 1, y: 2 }, Direction::Right));
+
+        // so the crosswords that differ only in the metadata of the words are the same
+        let cw = Crossword::from_placed_words([placed], Default::default()).unwrap();
+        assert_eq!(cw, Crossword::from_placed_words([other_placed], Default::default()).unwrap());
+    }
+}
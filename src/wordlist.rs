@@ -0,0 +1,196 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::word::Word;
+
+/// Represents the normalization applied to the words of a word list when loading it (check [WordListNormalization::load_words_from_str]).
+///
+/// The steps are applied in the order of the fields, all of them are off by default.
+///
+/// # Example
+///
+/// ```
+/// # use crossword_generator::wordlist::WordListNormalization;
+/// let normalization = WordListNormalization::new().lowercase().strip_non_alphanumeric().deduplicate();
+///
+/// let words = normalization.load_words_from_str("Ice Cream\n# comment\n\nice-cream\nCat");
+/// assert_eq!(words.iter().map(|w| w.value.as_slice()).collect::<Vec<_>>(), vec![b"icecream".as_slice(), b"cat"]);
+/// ```
+#[derive(Clone, Eq, PartialEq, PartialOrd, Ord, Default, Debug, Serialize, Deserialize, Hash)]
+pub struct WordListNormalization
+{
+    /// Convert the words to lowercase (using the unicode rules, not only for ascii characters).
+    pub lowercase: bool,
+    /// Remove all the characters that are not alphanumeric (spaces, hyphens, apostrophes, ...), "ice cream" becomes "icecream".
+    pub strip_non_alphanumeric: bool,
+    /// Keep only the first of the words with the same (normalized) value.
+    pub deduplicate: bool,
+}
+
+impl WordListNormalization
+{
+    /// Creates a normalization that does nothing.
+    pub fn new() -> WordListNormalization
+    {
+        WordListNormalization::default()
+    }
+
+    /// Returns the normalization with lowercasing turned on.
+    pub fn lowercase(mut self) -> WordListNormalization
+    {
+        self.lowercase = true;
+        self
+    }
+
+    /// Returns the normalization with stripping of non alphanumeric characters turned on.
+    pub fn strip_non_alphanumeric(mut self) -> WordListNormalization
+    {
+        self.strip_non_alphanumeric = true;
+        self
+    }
+
+    /// Returns the normalization with deduplication turned on.
+    pub fn deduplicate(mut self) -> WordListNormalization
+    {
+        self.deduplicate = true;
+        self
+    }
+
+    /// Returns the normalized value of a single word (deduplication is not applied).
+    pub fn normalize(&self, word: &str) -> String
+    {
+        let word = word.trim();
+        let word = if self.lowercase { word.to_lowercase() } else { word.to_owned() };
+        if self.strip_non_alphanumeric { word.chars().filter(|c| c.is_alphanumeric()).collect() } else { word }
+    }
+
+    /// Loads the words from the text with one word per line.
+    ///
+    /// The lines are trimmed, empty lines and lines starting with '#' are skipped, as well as the words that are not valid after the normalization (check [Word::validate]).
+    ///
+    /// The words are in utf8, so non ascii characters take more than one character of the crossword.
+    pub fn load_words_from_str(&self, input: &str) -> Vec<Word<u8, Vec<u8>>>
+    {
+        self.collect_words(content_lines(input).map(|line| (line, None)))
+    }
+
+    /// Loads the words with clues from the text with one "answer,clue" pair per line.
+    ///
+    /// The lines are split on the first comma, the clue can be quoted with double quotes (with "" for a quote inside it), lines without clues give words without clues.
+    /// Otherwise works like [WordListNormalization::load_words_from_str], the clue of the first word is kept when deduplicating.
+    pub fn load_words_with_clues_from_csv(&self, input: &str) -> Vec<Word<u8, Vec<u8>>>
+    {
+        self.collect_words(content_lines(input).map(|line| match line.split_once(',')
+        {
+            Some((answer, clue)) => (answer, Some(unquote(clue.trim())).filter(|clue| !clue.is_empty())),
+            None => (line, None),
+        }))
+    }
+
+    fn collect_words<'a>(&self, entries: impl Iterator<Item = (&'a str, Option<String>)>) -> Vec<Word<u8, Vec<u8>>>
+    {
+        let mut seen = BTreeSet::new();
+        entries.filter_map(|(answer, clue)|
+        {
+            let value = self.normalize(answer).into_bytes();
+            if self.deduplicate && seen.contains(&value) { return None; }
+
+            let word = Word::try_new(value.clone(), None).ok()?;
+            seen.insert(value);
+            Some(match clue { Some(clue) => word.with_clue(clue), None => word })
+        }).collect()
+    }
+}
+
+/// Loads the words from the text with one word per line, without normalization (check [WordListNormalization::load_words_from_str]).
+pub fn load_words_from_str(input: &str) -> Vec<Word<u8, Vec<u8>>>
+{
+    WordListNormalization::new().load_words_from_str(input)
+}
+
+/// Loads the words with clues from the text with one "answer,clue" pair per line, without normalization (check [WordListNormalization::load_words_with_clues_from_csv]).
+pub fn load_words_with_clues_from_csv(input: &str) -> Vec<Word<u8, Vec<u8>>>
+{
+    WordListNormalization::new().load_words_with_clues_from_csv(input)
+}
+
+fn content_lines(input: &str) -> impl Iterator<Item = &str>
+{
+    input.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn unquote(field: &str) -> String
+{
+    match field.strip_prefix('"').and_then(|field| field.strip_suffix('"'))
+    {
+        Some(field) => field.replace("\"\"", "\""),
+        None => field.to_owned(),
+    }
+}
+
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn values(words: &[Word<u8, Vec<u8>>]) -> Vec<&str>
+    {
+        words.iter().map(|w| std::str::from_utf8(&w.value).unwrap()).collect()
+    }
+
+    fn clues(words: &[Word<u8, Vec<u8>>]) -> Vec<Option<&str>>
+    {
+        words.iter().map(|w| w.meta.clue.as_deref()).collect()
+    }
+
+    #[test]
+    fn test_load_words_from_str()
+    {
+        let words = load_words_from_str(include_str!("../fixtures/messy_words.txt"));
+        assert_eq!(values(&words), vec!["Cat", "DOG", "cat", "Ice Cream", "rock'n'roll", "!!!", "Straße"]);
+        assert!(words.iter().all(|w| w.dir.is_none() && w.meta.is_empty()));
+    }
+
+    #[test]
+    fn test_wordlist_lowercase()
+    {
+        let words = WordListNormalization::new().lowercase().load_words_from_str(include_str!("../fixtures/messy_words.txt"));
+        assert_eq!(values(&words), vec!["cat", "dog", "cat", "ice cream", "rock'n'roll", "!!!", "straße"]);
+
+        assert_eq!(WordListNormalization::new().lowercase().normalize("ÀÉÎ"), "àéî");
+    }
+
+    #[test]
+    fn test_wordlist_strip_non_alphanumeric()
+    {
+        let words = WordListNormalization::new().strip_non_alphanumeric().load_words_from_str(include_str!("../fixtures/messy_words.txt"));
+        // "!!!" becomes empty, so it's skipped
+        assert_eq!(values(&words), vec!["Cat", "DOG", "cat", "IceCream", "rocknroll", "Straße"]);
+    }
+
+    #[test]
+    fn test_wordlist_deduplicate()
+    {
+        let words = WordListNormalization::new().deduplicate().load_words_from_str(include_str!("../fixtures/messy_words.txt"));
+        assert_eq!(values(&words), vec!["Cat", "DOG", "cat", "Ice Cream", "rock'n'roll", "!!!", "Straße"]);
+
+        let words = WordListNormalization::new().lowercase().strip_non_alphanumeric().deduplicate().load_words_from_str(include_str!("../fixtures/messy_words.txt"));
+        assert_eq!(values(&words), vec!["cat", "dog", "icecream", "rocknroll", "straße"]);
+    }
+
+    #[test]
+    fn test_load_words_with_clues_from_csv()
+    {
+        let words = load_words_with_clues_from_csv(include_str!("../fixtures/messy_clues.csv"));
+        assert_eq!(values(&words), vec!["Cat", "dog", "CAT", "ice cream", "owl", "emu", "?!"]);
+        assert_eq!(clues(&words), vec![Some("A pet that purrs"), Some("Man's best friend, they say"), Some("Another clue for cat"), Some("Cold dessert"), None, None, Some("Not a word")]);
+
+        let words = WordListNormalization::new().lowercase().strip_non_alphanumeric().deduplicate().load_words_with_clues_from_csv(include_str!("../fixtures/messy_clues.csv"));
+        assert_eq!(values(&words), vec!["cat", "dog", "icecream", "owl", "emu"]);
+        assert_eq!(clues(&words), vec![Some("A pet that purrs"), Some("Man's best friend, they say"), Some("Cold dessert"), None, None]);
+
+        assert_eq!(clues(&load_words_with_clues_from_csv("quote,\"Say \"\"hi\"\"\"")), vec![Some("Say \"hi\"")]);
+    }
+}